@@ -1,5 +1,5 @@
 use crate::{
-    error::check_inf_operands,
+    error::{check_inf, check_inf_operands},
     fbig::FBig,
     helper_macros,
     repr::{Context, Repr, Word},
@@ -8,10 +8,16 @@ use crate::{
 };
 use core::{
     cmp::Ordering,
+    iter::Sum,
     ops::{Add, AddAssign, Sub, SubAssign},
 };
 
-use dashu_base::Sign::{self, *};
+use alloc::vec::Vec;
+use dashu_base::{
+    Approximation::Exact,
+    EstimatedLog2,
+    Sign::{self, *},
+};
 use dashu_int::{IBig, UBig};
 
 impl<R: Round, const B: Word> Add for FBig<R, B> {
@@ -214,6 +220,152 @@ fn add_ref_ref<R: Round, const B: Word>(
     FBig::new(sum, context)
 }
 
+/// An accumulator for the exact sum of a sequence of floating point numbers.
+///
+/// The sum is stored as chunks keyed by the exponent, where each chunk is an exact integer
+/// covering a range of digits. A new term is only merged with the chunks whose digits are
+/// close to its digits, so the memory doesn't grow with the spread of the exponents.
+/// The chunks are sorted by exponent and they don't overlap (with a gap of at least
+/// [MERGE_GAP][Self::MERGE_GAP] digits), so the sign of the sum of the chunks below any
+/// chunk is the sign of the highest one of them.
+pub(crate) struct ExactSum<const B: Word> {
+    chunks: Vec<Repr<B>>,
+}
+
+impl<const B: Word> ExactSum<B> {
+    /// The chunks with digits closer than this are merged
+    const MERGE_GAP: isize = Word::BITS as isize;
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self { chunks: Vec::new() }
+    }
+
+    /// Get the (over-estimated) position after the highest digit of the chunk
+    #[inline]
+    fn chunk_top(chunk: &Repr<B>) -> isize {
+        chunk.exponent + chunk.digits_ub() as isize
+    }
+
+    /// Check whether the digits of two chunks are close enough to be merged
+    #[inline]
+    fn is_near(lhs: &Repr<B>, rhs: &Repr<B>) -> bool {
+        let low = lhs.exponent.max(rhs.exponent);
+        let high = Self::chunk_top(lhs).min(Self::chunk_top(rhs));
+        low <= high + Self::MERGE_GAP
+    }
+
+    /// Add `significand * B ^ exponent` to the accumulator
+    pub fn add_parts(&mut self, significand: IBig, exponent: isize) {
+        if significand.is_zero() {
+            return;
+        }
+
+        // merge all the chunks close to the new term, since the chunks are sorted and
+        // disjoint, only the neighbors of the insertion position need to be checked
+        let mut chunk = Repr {
+            significand,
+            exponent,
+        };
+        loop {
+            let pos = self.chunks.partition_point(|c| c.exponent < chunk.exponent);
+            let near = if pos > 0 && Self::is_near(&self.chunks[pos - 1], &chunk) {
+                pos - 1
+            } else if pos < self.chunks.len() && Self::is_near(&self.chunks[pos], &chunk) {
+                pos
+            } else {
+                if !chunk.significand.is_zero() {
+                    self.chunks.insert(pos, chunk);
+                }
+                return;
+            };
+
+            let other = self.chunks.remove(near);
+            let (mut high, low) = if other.exponent > chunk.exponent {
+                (other, chunk)
+            } else {
+                (chunk, other)
+            };
+            shl_digits_in_place::<B>(
+                &mut high.significand,
+                (high.exponent - low.exponent) as usize,
+            );
+            chunk = Repr {
+                significand: high.significand + low.significand,
+                exponent: low.exponent,
+            };
+            if chunk.significand.is_zero() {
+                return;
+            }
+        }
+    }
+
+    /// Add a finite representation to the accumulator
+    #[inline]
+    pub fn add(&mut self, term: &Repr<B>) {
+        check_inf(term);
+        self.add_parts(term.significand.clone(), term.exponent);
+    }
+
+    /// Round the sum to the context.
+    ///
+    /// The chunks are added from the highest one. Once a chunk is far below the digits
+    /// required for rounding the partial sum, the remaining chunks only decide the direction
+    /// of the rounding, so they are replaced by a sticky unit with the sign of that chunk.
+    pub fn round<R: Round>(self, context: &Context<R>) -> Rounded<Repr<B>> {
+        let exact = Context::<R>::new(0);
+        let mut chunks = self.chunks.into_iter().rev();
+        let mut sum = match chunks.next() {
+            Some(chunk) => chunk,
+            None => return Exact(Repr::zero()),
+        };
+        for chunk in chunks {
+            if context.is_limited() {
+                let top = sum.exponent + sum.digits_lb() as isize;
+                let sticky_pos = sum.exponent.min(top - context.precision as isize - 2) - 1;
+                if Self::chunk_top(&chunk) <= sticky_pos {
+                    let sticky = Repr::new(chunk.significand.signum(), sticky_pos - 1);
+                    sum = exact.add(&sum, &sticky).value().repr;
+                    break;
+                }
+            }
+            sum = exact.add(&sum, &chunk).value().repr;
+        }
+        context.repr_round(Repr::new(sum.significand, sum.exponent))
+    }
+}
+
+impl<R: Round, const B: Word> Sum for FBig<R, B> {
+    /// Sum up the numbers with a single rounding at the end.
+    ///
+    /// The result has the highest precision among the terms.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut context = Context::new(0);
+        let mut acc = ExactSum::new();
+        for term in iter {
+            context = Context::max(context, term.context);
+            check_inf(&term.repr);
+            acc.add_parts(term.repr.significand, term.repr.exponent);
+        }
+        FBig::new(acc.round(&context).value(), context)
+    }
+}
+
+impl<'a, R: Round, const B: Word> Sum<&'a FBig<R, B>> for FBig<R, B> {
+    /// Sum up the numbers with a single rounding at the end.
+    ///
+    /// The result has the highest precision among the terms.
+    fn sum<I: Iterator<Item = &'a FBig<R, B>>>(iter: I) -> Self {
+        let mut context = Context::new(0);
+        let mut acc = ExactSum::new();
+        for term in iter {
+            context = Context::max(context, term.context);
+            acc.add(&term.repr);
+        }
+        FBig::new(acc.round(&context).value(), context)
+    }
+}
+
 impl<R: Round> Context<R> {
     /// Round sum = `significand * B ^ exponent` with the low part (value, precision).
     /// If the sum is actually from a subtraction and the low part is not zero, `is_sub` should be true.
@@ -254,7 +406,9 @@ impl<R: Round> Context<R> {
             }
             Ordering::Less => {
                 // Expand to low parts if the result has less digits than desired precision.
-                // It's necessary only when lhs and rhs has different sign and a cancellation might happen.
+                // It's necessary when lhs and rhs has different sign and a cancellation might happen,
+                // or when a much smaller operand was reduced to a low part, so that the rounding
+                // happens at the last digit of the precision rather than the last digit of the sum.
                 /*
                  * lhs:  |=========0000|
                  * rhs:  |=============|xxxxx|
@@ -263,7 +417,7 @@ impl<R: Round> Context<R> {
                  * shift:              |<>|
                  * expanded:     |========|xx|
                  */
-                if !low.0.is_zero() && is_sub {
+                if !low.0.is_zero() {
                     let (low_val, low_prec) = low;
                     let shift = low_prec.min(rnd_precision - digits);
                     let (pad, low_val) = split_digits::<B>(low_val, low_prec - shift);
@@ -275,15 +429,29 @@ impl<R: Round> Context<R> {
             }
         };
 
+        // move the digits below the subnormal quantum into the low part
+        if let Some(qmin) = self.min_quantum() {
+            if exponent < qmin && !significand.is_zero() {
+                let shift = (qmin - exponent) as usize;
+                let (signif_hi, mut signif_lo) = split_digits::<B>(significand, shift);
+                significand = signif_hi;
+                exponent = qmin;
+                shl_digits_in_place::<B>(&mut signif_lo, low.1);
+                low.0 += signif_lo;
+                low.1 += shift;
+            }
+        }
+
         // perform rounding
-        if low.0.is_zero() {
+        let rounded = if low.0.is_zero() {
             Rounded::Exact(Repr::new(significand, exponent))
         } else {
             // By now significand should have at least full precision. After adjustment, the digits length
             // could be one more than the precision. We don't shrink the extra digit.
             let adjust = R::round_fract::<B>(&significand, low.0, low.1);
             Rounded::Inexact(Repr::new(significand + adjust, exponent), adjust)
-        }
+        };
+        self.repr_fit_range(rounded).0
     }
 
     // lhs + rhs_sign * rhs, assuming lhs.exponent >= rhs.exponent
@@ -444,6 +612,71 @@ impl<R: Round> Context<R> {
         self.repr_round_sum(significand, exponent, low, is_sub)
     }
 
+    /// Calculate `lhs + rhs` for rounding to the precision of this context later.
+    ///
+    /// The sum is exact, except that when one operand is far smaller than the lowest digit
+    /// of the other operand and the digits required for rounding, it will be replaced by a
+    /// unit at a low position (as a sticky digit). Rounding the returned value to the precision
+    /// gives the same result as rounding the exact sum, while the returned value stays short.
+    pub(crate) fn repr_add_sticky<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Repr<B> {
+        let exact = Context::<R>::new(0);
+        if !self.is_limited() || lhs.is_zero() || rhs.is_zero() {
+            return exact.add(lhs, rhs).value().repr;
+        }
+
+        let (large, small) = if lhs.log2_est() >= rhs.log2_est() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        let large_top = large.exponent + large.digits_lb() as isize;
+        let sticky_pos = large.exponent.min(large_top - self.precision as isize - 2) - 1;
+        if small.exponent + small.digits_ub() as isize <= sticky_pos {
+            let sticky = Repr::new(small.significand.signum(), sticky_pos - 1);
+            exact.add(large, &sticky).value().repr
+        } else {
+            exact.add(lhs, rhs).value().repr
+        }
+    }
+
+    /// Calculate the sum of a sequence of floating point numbers under this context.
+    ///
+    /// The terms are accumulated exactly and the result is rounded only once, so the result
+    /// is correctly rounded regardless of the order of the terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1e10")?;
+    /// let b = DBig::from_str_native("1.23")?;
+    /// let c = DBig::from_str_native("-1e10")?;
+    /// assert_eq!(
+    ///     context.sum([a.repr(), b.repr(), c.repr()]),
+    ///     Inexact(DBig::from_str_native("1.2")?, NoOp)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the terms is infinite.
+    pub fn sum<'a, const B: Word, I>(&self, terms: I) -> Rounded<FBig<R, B>>
+    where
+        I: IntoIterator<Item = &'a Repr<B>>,
+    {
+        let mut acc = ExactSum::new();
+        for term in terms {
+            acc.add(term);
+        }
+        acc.round(self).map(|v| FBig::new(v, *self))
+    }
+
     /// Add two floating point numbers under this context.
     ///
     /// # Examples
@@ -0,0 +1,459 @@
+use crate::{
+    error::{panic_divide_by_0, panic_invalid_ball, panic_log_non_positive, panic_root_negative},
+    fbig::FBig,
+    interval::{widen_up, Interval},
+    repr::{Context, Repr, Word},
+    round::{mode, Round, Rounded},
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use dashu_base::{Abs, Approximation::*, Sign};
+use dashu_int::IBig;
+
+type Mid<const B: Word> = FBig<mode::HalfEven, B>;
+type Radius<const B: Word> = FBig<mode::Up, B>;
+
+/// Number of digits in base `base` that is enough to hold 30 bits
+const fn radius_precision(base: Word) -> usize {
+    let (mut digits, mut pow) = (0, 1u128);
+    while pow < (1 << 30) {
+        pow *= base as u128;
+        digits += 1;
+    }
+    digits
+}
+
+/// A ball of real numbers `[mid ± rad]` with an [FBig] midpoint and a low precision radius,
+/// used for verified computation (similar to the `arb` type in [Arb](https://arblib.org/)).
+///
+/// The midpoint is rounded to the nearest ([mode::HalfEven]) with the precision of the operands,
+/// and the radius is always rounded toward +∞ ([mode::Up]) with a fixed precision of
+/// [Ball::RADIUS_PRECISION] digits (about 30 bits). After each operation, the radius is
+/// enlarged to cover both the propagated error from the operands and the rounding error of the
+/// midpoint, so that the result ball always encloses all the possible exact results from the
+/// numbers in the operand balls.
+///
+/// Compared to [Interval], the overhead of a ball is much lower when the precision is high,
+/// because only the midpoint is computed with full precision.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_float::Ball;
+///
+/// let third = Ball::exact(DBig::from_str_native("1.0000")?) / Ball::exact(DBig::from_str_native("3")?);
+/// assert_eq!(third.to_string(), "[0.33333 +/- 0.00001]");
+/// assert!(third.contains(&DBig::from_str_native("0.333333333")?));
+///
+/// // the radius grows as the error accumulates
+/// let one = &third * Ball::exact(DBig::from_str_native("3")?);
+/// assert_eq!(one.to_string(), "[0.99999 +/- 0.00003]");
+/// assert!(one.contains(&DBig::ONE));
+/// # Ok::<(), ParseError>(())
+/// ```
+///
+/// # Rounding errors
+///
+/// The arithmetic operations and the square root are correctly rounded, while the exponential
+/// and logarithm functions (and the integer power) are faithfully rounded. In both cases the
+/// rounding error of the midpoint is less than one unit in the last place, which is added to
+/// the radius when the midpoint is inexact (as reported by the [Rounded] result).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ball<const BASE: Word = 2> {
+    mid: Mid<BASE>,
+    rad: Radius<BASE>,
+}
+
+impl<const B: Word> Ball<B> {
+    /// The precision of the radius, which is the number of digits needed to hold 30 bits.
+    pub const RADIUS_PRECISION: usize = radius_precision(B);
+
+    /// Create a ball from the midpoint and the radius.
+    ///
+    /// The radius is rounded up to [Ball::RADIUS_PRECISION] digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Ball;
+    ///
+    /// let a = Ball::new(DBig::from_str_native("1.5")?, DBig::from_str_native("0.5")?);
+    /// assert_eq!(a.to_string(), "[1.5 +/- 0.5]");
+    /// assert!(a.contains(&DBig::from_str_native("2")?));
+    /// assert!(!a.contains(&DBig::from_str_native("2.1")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the midpoint or the radius is infinite, or the radius is negative.
+    pub fn new<R1: Round, R2: Round>(mid: FBig<R1, B>, rad: FBig<R2, B>) -> Self {
+        if mid.repr.is_infinite() || rad.repr.is_infinite() || rad.repr.sign() == Sign::Negative {
+            panic_invalid_ball()
+        }
+        let context = Self::radius_context();
+        let rad = context.repr_round(rad.repr).value();
+        Self {
+            mid: mid.with_rounding(),
+            rad: FBig::new(rad, context),
+        }
+    }
+
+    /// Create a ball containing a single number, i.e. the radius is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    #[inline]
+    pub fn exact<R: Round>(x: FBig<R, B>) -> Self {
+        if x.repr.is_infinite() {
+            panic_invalid_ball()
+        }
+        Self {
+            mid: x.with_rounding(),
+            rad: Self::zero_radius(),
+        }
+    }
+
+    /// Get the midpoint of the ball
+    #[inline]
+    pub const fn mid(&self) -> &FBig<mode::HalfEven, B> {
+        &self.mid
+    }
+
+    /// Get the radius of the ball
+    #[inline]
+    pub const fn rad(&self) -> &FBig<mode::Up, B> {
+        &self.rad
+    }
+
+    /// Get the midpoint and the radius of the ball
+    #[inline]
+    pub fn into_parts(self) -> (FBig<mode::HalfEven, B>, FBig<mode::Up, B>) {
+        (self.mid, self.rad)
+    }
+
+    /// Get the precision of the midpoint
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.mid.precision()
+    }
+
+    /// Check whether the radius is zero
+    #[inline]
+    pub const fn is_exact(&self) -> bool {
+        self.rad.repr.is_zero()
+    }
+
+    /// Check whether the number is in the ball
+    pub fn contains<R: Round>(&self, x: &FBig<R, B>) -> bool {
+        if x.repr.is_infinite() {
+            return false;
+        }
+        // the difference is calculated exactly with unlimited precision
+        let diff = Context::<mode::Up>::new(0)
+            .sub(&x.repr, &self.mid.repr)
+            .value();
+        diff.abs() <= self.rad
+    }
+
+    /// Check whether zero is in the ball
+    #[inline]
+    pub fn contains_zero(&self) -> bool {
+        self.mid.clone().abs() <= self.rad
+    }
+
+    #[inline]
+    const fn radius_context() -> Context<mode::Up> {
+        Context::new(Self::RADIUS_PRECISION)
+    }
+
+    #[inline]
+    const fn zero_radius() -> Radius<B> {
+        FBig::new(Repr::zero(), Self::radius_context())
+    }
+
+    /// The context for the midpoint of the result with the given operands
+    #[inline]
+    fn mid_context(operands: &[&Self]) -> Context<mode::HalfEven> {
+        let precision = operands.iter().map(|x| x.precision()).max().unwrap_or(0);
+        Context::new(precision)
+    }
+
+    /// Calculate the square root of the ball.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ball contains negative numbers, or the precision is unlimited.
+    pub fn sqrt(&self) -> Self {
+        if self.mid < self.rad {
+            panic_root_negative()
+        }
+        let (mid, err) = split_rounded(Self::mid_context(&[self]).sqrt(&self.mid.repr));
+
+        // |√x - √m| = |x - m| / (√x + √m) <= r / √m
+        let context = Self::radius_context();
+        let prop = if self.is_exact() {
+            Self::zero_radius()
+        } else {
+            let root = lower_context::<B>().sqrt(&self.mid.repr).value();
+            context.div(&self.rad.repr, &root.repr).value()
+        };
+        let rad = context.add(&prop.repr, &err).value();
+        Self { mid, rad }
+    }
+
+    /// Calculate the exponential function on the ball.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn exp(&self) -> Self {
+        let (mid, err) = split_rounded(Self::mid_context(&[self]).exp(&self.mid.repr));
+
+        // |exp(x) - exp(m)| <= exp(m) (exp(r) - 1)
+        let context = Self::radius_context();
+        let prop = if self.is_exact() {
+            Self::zero_radius()
+        } else {
+            let scale = context.add(&mid.repr, &err).value();
+            let growth = widen_up(context.exp_m1(&self.rad.repr));
+            context.mul(&scale.repr, &growth.repr).value()
+        };
+        let rad = context.add(&prop.repr, &err).value();
+        Self { mid, rad }
+    }
+
+    /// Calculate the natural logarithm on the ball.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ball contains non-positive numbers, or the precision is unlimited.
+    pub fn ln(&self) -> Self {
+        if self.mid <= self.rad {
+            panic_log_non_positive()
+        }
+        let (mid, err) = split_rounded(Self::mid_context(&[self]).ln(&self.mid.repr));
+
+        // |ln(x) - ln(m)| <= r / (m - r)
+        let context = Self::radius_context();
+        let prop = if self.is_exact() {
+            Self::zero_radius()
+        } else {
+            let gap = lower_context::<B>()
+                .sub(&self.mid.repr, &self.rad.repr)
+                .value();
+            context.div(&self.rad.repr, &gap.repr).value()
+        };
+        let rad = context.add(&prop.repr, &err).value();
+        Self { mid, rad }
+    }
+
+    /// Raise the ball to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Ball;
+    ///
+    /// let a = Ball::new(DBig::from_str_native("2.000")?, DBig::from_str_native("0.001")?);
+    /// let b = a.powi(3.into());
+    /// assert_eq!(b.mid(), &DBig::from_str_native("8.000")?);
+    /// assert!(b.contains(&DBig::from_str_native("8.012006001")?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is negative and the ball contains zero, or the precision is
+    /// unlimited when the exponent is negative.
+    pub fn powi(&self, exp: IBig) -> Self {
+        if exp.is_zero() {
+            return Self::exact(Mid::<B>::ONE);
+        }
+        if exp.sign() == Sign::Negative {
+            return Self::exact(Mid::<B>::ONE) / self.powi(-exp);
+        }
+
+        let (mid, err) =
+            split_rounded(Self::mid_context(&[self]).powi(&self.mid.repr, exp.clone()));
+
+        // |xⁿ - mⁿ| <= (|m| + r)ⁿ - |m|ⁿ <= n r (|m| + r)ⁿ⁻¹
+        let context = Self::radius_context();
+        let prop = if self.is_exact() {
+            Self::zero_radius()
+        } else {
+            let bound = context
+                .add(&abs_repr(&self.mid.repr), &self.rad.repr)
+                .value();
+            let pow = widen_up(context.powi(&bound.repr, &exp - IBig::ONE));
+            let scale = context.mul(&Repr::new(exp, 0), &self.rad.repr).value();
+            context.mul(&scale.repr, &pow.repr).value()
+        };
+        let rad = context.add(&prop.repr, &err).value();
+        Self { mid, rad }
+    }
+}
+
+/// The context used for the lower bounds in the error propagation
+#[inline]
+const fn lower_context<const B: Word>() -> Context<mode::Down> {
+    Context::new(Ball::<B>::RADIUS_PRECISION)
+}
+
+/// Split a rounded midpoint into the value and an upper bound of its rounding error
+#[inline]
+fn split_rounded<const B: Word>(x: Rounded<Mid<B>>) -> (Mid<B>, Repr<B>) {
+    match x {
+        Exact(v) => (v, Repr::zero()),
+        Inexact(v, _) => {
+            let err = v.ulp().repr;
+            (v, err)
+        }
+    }
+}
+
+#[inline]
+fn abs_repr<const B: Word>(x: &Repr<B>) -> Repr<B> {
+    Repr {
+        significand: x.significand.clone().abs(),
+        exponent: x.exponent,
+    }
+}
+
+impl<const B: Word> Display for Ball<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} +/- {}]", self.mid, self.rad)
+    }
+}
+
+impl<const B: Word> From<Ball<B>> for Interval<B> {
+    /// Convert the ball to the interval `[mid - rad, mid + rad]`, with the endpoints rounded outward.
+    fn from(ball: Ball<B>) -> Self {
+        let precision = ball.precision();
+        let lo = Context::<mode::Down>::new(precision).sub(&ball.mid.repr, &ball.rad.repr);
+        let hi = Context::<mode::Up>::new(precision).add(&ball.mid.repr, &ball.rad.repr);
+        Interval::new(lo.value(), hi.value())
+    }
+}
+
+impl<const B: Word> Neg for Ball<B> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            mid: -self.mid,
+            rad: self.rad,
+        }
+    }
+}
+
+impl<const B: Word> Neg for &Ball<B> {
+    type Output = Ball<B>;
+    #[inline]
+    fn neg(self) -> Ball<B> {
+        self.clone().neg()
+    }
+}
+
+fn add_ref_ref<const B: Word>(lhs: &Ball<B>, rhs: &Ball<B>) -> Ball<B> {
+    let context = Ball::mid_context(&[lhs, rhs]);
+    let (mid, err) = split_rounded(context.add(&lhs.mid.repr, &rhs.mid.repr));
+    let rad = Ball::<B>::radius_context()
+        .sum([&lhs.rad.repr, &rhs.rad.repr, &err])
+        .value();
+    Ball { mid, rad }
+}
+
+fn sub_ref_ref<const B: Word>(lhs: &Ball<B>, rhs: &Ball<B>) -> Ball<B> {
+    let context = Ball::mid_context(&[lhs, rhs]);
+    let (mid, err) = split_rounded(context.sub(&lhs.mid.repr, &rhs.mid.repr));
+    let rad = Ball::<B>::radius_context()
+        .sum([&lhs.rad.repr, &rhs.rad.repr, &err])
+        .value();
+    Ball { mid, rad }
+}
+
+fn mul_ref_ref<const B: Word>(lhs: &Ball<B>, rhs: &Ball<B>) -> Ball<B> {
+    let context = Ball::mid_context(&[lhs, rhs]);
+    let (mid, err) = split_rounded(context.mul(&lhs.mid.repr, &rhs.mid.repr));
+
+    // |xy - ab| <= |a| s + |b| r + r s, where x = [a ± r], y = [b ± s]
+    let context = Ball::<B>::radius_context();
+    let (a, b) = (abs_repr(&lhs.mid.repr), abs_repr(&rhs.mid.repr));
+    let t1 = context.mul(&a, &rhs.rad.repr).value();
+    let t2 = context.mul(&b, &lhs.rad.repr).value();
+    let t3 = context.mul(&lhs.rad.repr, &rhs.rad.repr).value();
+    let rad = context.sum([&t1.repr, &t2.repr, &t3.repr, &err]).value();
+    Ball { mid, rad }
+}
+
+fn div_ref_ref<const B: Word>(lhs: &Ball<B>, rhs: &Ball<B>) -> Ball<B> {
+    if rhs.contains_zero() {
+        panic_divide_by_0()
+    }
+    let context = Ball::mid_context(&[lhs, rhs]);
+    let (mid, err) = split_rounded(context.div(&lhs.mid.repr, &rhs.mid.repr));
+
+    // |x/y - a/b| <= (|a| s + |b| r) / (|b| (|b| - s)), where x = [a ± r], y = [b ± s]
+    let context = Ball::<B>::radius_context();
+    let (a, b) = (abs_repr(&lhs.mid.repr), abs_repr(&rhs.mid.repr));
+    let t1 = context.mul(&a, &rhs.rad.repr).value();
+    let t2 = context.mul(&b, &lhs.rad.repr).value();
+    let num = context.add(&t1.repr, &t2.repr).value();
+    let lower = lower_context::<B>();
+    let gap = lower.sub(&b, &rhs.rad.repr).value();
+    let den = lower.mul(&b, &gap.repr).value();
+    let prop = context.div(&num.repr, &den.repr).value();
+    let rad = context.add(&prop.repr, &err).value();
+    Ball { mid, rad }
+}
+
+macro_rules! impl_ball_binop {
+    ($trait:ident, $method:ident, $impl:ident) => {
+        impl<const B: Word> $trait for Ball<B> {
+            type Output = Ball<B>;
+            #[inline]
+            fn $method(self, rhs: Ball<B>) -> Ball<B> {
+                $impl(&self, &rhs)
+            }
+        }
+
+        impl<'r, const B: Word> $trait<&'r Ball<B>> for Ball<B> {
+            type Output = Ball<B>;
+            #[inline]
+            fn $method(self, rhs: &Ball<B>) -> Ball<B> {
+                $impl(&self, rhs)
+            }
+        }
+
+        impl<'l, const B: Word> $trait<Ball<B>> for &'l Ball<B> {
+            type Output = Ball<B>;
+            #[inline]
+            fn $method(self, rhs: Ball<B>) -> Ball<B> {
+                $impl(self, &rhs)
+            }
+        }
+
+        impl<'l, 'r, const B: Word> $trait<&'r Ball<B>> for &'l Ball<B> {
+            type Output = Ball<B>;
+            #[inline]
+            fn $method(self, rhs: &Ball<B>) -> Ball<B> {
+                $impl(self, rhs)
+            }
+        }
+    };
+}
+
+impl_ball_binop!(Add, add, add_ref_ref);
+impl_ball_binop!(Sub, sub, sub_ref_ref);
+impl_ball_binop!(Mul, mul, mul_ref_ref);
+impl_ball_binop!(Div, div, div_ref_ref);
@@ -1,6 +1,15 @@
 use core::cmp::Ordering;
 
-use crate::{fbig::FBig, repr::Repr, repr::Word, round::Round, utils::shl_digits};
+use dashu_base::EstimatedLog2;
+use dashu_int::{IBig, UBig};
+
+use crate::{
+    fbig::FBig,
+    repr::Repr,
+    repr::Word,
+    round::{mode, Round},
+    utils::{shl_digits, shl_digits_in_place},
+};
 
 impl<R1: Round, R2: Round, const B: Word> PartialEq<FBig<R2, B>> for FBig<R1, B> {
     #[inline]
@@ -119,4 +128,236 @@ impl<R: Round, const B: Word> Ord for FBig<R, B> {
     }
 }
 
-// TODO: implement comparison with IBig
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Compare two numbers with a total order on both the value and the precision.
+    ///
+    /// The numbers are first compared by their values, and the numbers with the same value
+    /// are ordered by their precisions (the unlimited precision is treated as the lowest).
+    /// Therefore, unlike [Ord::cmp], this method returns [Ordering::Equal] only if both numbers
+    /// have the same value and the same precision. It's similar to [f64::total_cmp] and useful
+    /// for sorting or deduplicating numbers while keeping their precisions distinguishable.
+    ///
+    /// The [min][Ord::min], [max][Ord::max] and [clamp][Ord::clamp] methods are provided
+    /// through the [Ord] trait, where the infinities are the largest and the smallest numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use core::cmp::Ordering;
+    ///
+    /// let a = DBig::from_str_native("1.2")?;
+    /// let b = DBig::from_str_native("1.20")?;
+    /// assert_eq!(a.cmp(&b), Ordering::Equal);
+    /// assert_eq!(a.total_cmp(&b), Ordering::Less);
+    /// assert_eq!(a.total_cmp(&DBig::NEG_INFINITY), Ordering::Greater);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn total_cmp<R2: Round>(&self, other: &FBig<R2, B>) -> Ordering {
+        repr_cmp(&self.repr, &other.repr, None)
+            .then_with(|| self.context.precision.cmp(&other.context.precision))
+    }
+}
+
+/// Compare two representations with (possibly) different bases exactly.
+fn repr_cmp_bases<const B1: Word, const B2: Word>(lhs: &Repr<B1>, rhs: &Repr<B2>) -> Ordering {
+    // case 1: compare with inf
+    match (lhs.is_infinite(), rhs.is_infinite()) {
+        (true, true) => return lhs.exponent.signum().cmp(&rhs.exponent.signum()),
+        (false, true) => {
+            return match rhs.exponent >= 0 {
+                true => Ordering::Less,
+                false => Ordering::Greater,
+            }
+        }
+        (true, false) => {
+            return match lhs.exponent >= 0 {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            }
+        }
+        _ => {}
+    };
+
+    // case 2: compare sign
+    match lhs.significand.signum().cmp(&rhs.significand.signum()) {
+        Ordering::Greater => return Ordering::Greater,
+        Ordering::Less => return Ordering::Less,
+        _ => {}
+    };
+    if lhs.is_zero() {
+        return Ordering::Equal;
+    }
+    let sign = lhs.significand.sign();
+
+    // case 3: compare the magnitudes by their estimated logarithms, a tolerance
+    // is added to cover the rounding errors of the estimation in f32
+    let (lhs_lb, lhs_ub) = lhs.log2_bounds();
+    let (rhs_lb, rhs_ub) = rhs.log2_bounds();
+    let tolerance = 1. + (lhs_ub.abs() + rhs_ub.abs()) / (1u32 << 20) as f32;
+    if lhs_ub + tolerance < rhs_lb {
+        return sign * Ordering::Less;
+    }
+    if lhs_lb > rhs_ub + tolerance {
+        return sign * Ordering::Greater;
+    }
+
+    // case 4: compare the exact values by moving all the powers to the positive side
+    let (mut lhs_exp, mut rhs_exp) = (lhs.exponent, rhs.exponent);
+    if B1 == B2 {
+        let min_exp = lhs_exp.min(rhs_exp);
+        lhs_exp -= min_exp;
+        rhs_exp -= min_exp;
+    }
+    let (mut lhs_signif, mut rhs_signif) = (lhs.significand.clone(), rhs.significand.clone());
+    if lhs_exp >= 0 {
+        shl_digits_in_place::<B1>(&mut lhs_signif, lhs_exp as usize);
+    } else {
+        shl_digits_in_place::<B1>(&mut rhs_signif, lhs_exp.unsigned_abs());
+    }
+    if rhs_exp >= 0 {
+        shl_digits_in_place::<B2>(&mut rhs_signif, rhs_exp as usize);
+    } else {
+        shl_digits_in_place::<B2>(&mut lhs_signif, rhs_exp.unsigned_abs());
+    }
+    lhs_signif.cmp(&rhs_signif)
+}
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Compare the value with a float number in an arbitrary base exactly.
+    ///
+    /// The comparison is performed on the exact values without rounding any of them to
+    /// the other base, and the precisions are not considered. For numbers with the same
+    /// base, this is equivalent to [Ord::cmp]. The comparison operators are also implemented
+    /// between the binary and the decimal float numbers, which are based on this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, DBig};
+    /// use core::cmp::Ordering;
+    ///
+    /// let a: FBig = FBig::from_str_native("0x1p-1")?;
+    /// assert_eq!(a.cmp_any_base(&DBig::from_str_native("0.5")?), Ordering::Equal);
+    /// // 0.1 is not exactly representable in binary
+    /// let b: FBig = FBig::try_from(0.1f64).unwrap();
+    /// assert_eq!(b.cmp_any_base(&DBig::from_str_native("0.1")?), Ordering::Greater);
+    /// assert!(b > DBig::from_str_native("0.1")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn cmp_any_base<R2: Round, const B2: Word>(&self, other: &FBig<R2, B2>) -> Ordering {
+        repr_cmp_bases(&self.repr, &other.repr)
+    }
+}
+
+macro_rules! impl_cmp_between_bases {
+    ($b1:literal, $b2:literal) => {
+        impl<R1: Round, R2: Round> PartialEq<FBig<R2, $b2>> for FBig<R1, $b1> {
+            #[inline]
+            fn eq(&self, other: &FBig<R2, $b2>) -> bool {
+                repr_cmp_bases(&self.repr, &other.repr).is_eq()
+            }
+        }
+
+        impl<R1: Round, R2: Round> PartialOrd<FBig<R2, $b2>> for FBig<R1, $b1> {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R2, $b2>) -> Option<Ordering> {
+                Some(repr_cmp_bases(&self.repr, &other.repr))
+            }
+        }
+    };
+}
+impl_cmp_between_bases!(2, 10);
+impl_cmp_between_bases!(10, 2);
+
+/// Compare the representation with an integer exactly.
+#[inline]
+fn repr_cmp_int<const B: Word>(lhs: &Repr<B>, rhs: IBig) -> Ordering {
+    repr_cmp(lhs, &Repr::new(rhs, 0), None)
+}
+
+/// Check the equality between the representation and an integer.
+#[inline]
+fn repr_eq_int<const B: Word>(lhs: &Repr<B>, rhs: IBig) -> bool {
+    !lhs.is_infinite() && lhs == &Repr::new(rhs, 0)
+}
+
+macro_rules! impl_cmp_with_int {
+    ($t:ty, $to_ibig:expr) => {
+        impl<R: Round, const B: Word> PartialEq<$t> for FBig<R, B> {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                repr_eq_int(&self.repr, $to_ibig(other))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialEq<FBig<R, B>> for $t {
+            #[inline]
+            fn eq(&self, other: &FBig<R, B>) -> bool {
+                repr_eq_int(&other.repr, $to_ibig(self))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<$t> for FBig<R, B> {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                Some(repr_cmp_int(&self.repr, $to_ibig(other)))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<FBig<R, B>> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R, B>) -> Option<Ordering> {
+                Some(repr_cmp_int(&other.repr, $to_ibig(self)).reverse())
+            }
+        }
+    };
+}
+impl_cmp_with_int!(UBig, |v: &UBig| IBig::from(v.clone()));
+impl_cmp_with_int!(IBig, |v: &IBig| v.clone());
+
+macro_rules! impl_cmp_with_primitive_int {
+    ($($t:ty)*) => {$(
+        impl_cmp_with_int!($t, |v: &$t| IBig::from(*v));
+    )*};
+}
+impl_cmp_with_primitive_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_cmp_with_primitive_float {
+    ($($t:ty)*) => {$(
+        impl<R: Round, const B: Word> PartialEq<$t> for FBig<R, B> {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialEq<FBig<R, B>> for $t {
+            #[inline]
+            fn eq(&self, other: &FBig<R, B>) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<$t> for FBig<R, B> {
+            /// The float is converted to a binary [FBig] exactly, and NaN is not comparable.
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                let other = FBig::<mode::Zero, 2>::try_from(*other).ok()?;
+                Some(repr_cmp_bases(&self.repr, &other.repr))
+            }
+        }
+
+        impl<R: Round, const B: Word> PartialOrd<FBig<R, B>> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &FBig<R, B>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )*};
+}
+impl_cmp_with_primitive_float!(f32 f64);
@@ -0,0 +1,625 @@
+use crate::{
+    error::{check_precision_limited, panic_divide_by_0, panic_log_zero},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{mode, Round},
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use dashu_base::{Abs, BitTest, EstimatedLog2, Sign};
+use dashu_int::IBig;
+
+/// An arbitrary precision complex number `re + im·i` with [FBig] real and imaginary parts.
+///
+/// The precision and the rounding of the operations are controlled by the [Context] of the parts,
+/// in the same way as [FBig]: the result of an operation has the highest precision among the
+/// operands, and each part of the result is rounded with the rounding mode `RoundingMode`.
+///
+/// The addition, subtraction, multiplication, division, [abs][CBig::abs] and [norm][CBig::norm]
+/// are correctly rounded. The other functions are evaluated with extra guard digits, so that the
+/// parts of the results are precise to the full precision (`|error| < 1 ulp`) in most cases.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use dashu_float::CBig;
+///
+/// let a = CBig::new(DBig::from_str_native("3")?, DBig::from_str_native("4")?);
+/// let b = CBig::new(DBig::from_str_native("1.0")?, DBig::from_str_native("-2.0")?);
+/// assert_eq!((&a + &b).to_string(), "4+2i");
+/// assert_eq!((&a * &b).to_string(), "11-2i");
+/// assert_eq!((&a / &b).to_string(), "-1+2i");
+/// assert_eq!(a.abs(), DBig::from_str_native("5")?);
+/// assert_eq!(a.conj().to_string(), "3-4i");
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct CBig<RoundingMode: Round = mode::Zero, const BASE: Word = 2> {
+    re: FBig<RoundingMode, BASE>,
+    im: FBig<RoundingMode, BASE>,
+}
+
+impl<R: Round, const B: Word> CBig<R, B> {
+    /// [CBig] with value 0 and unlimited precision
+    pub const ZERO: Self = Self {
+        re: FBig::ZERO,
+        im: FBig::ZERO,
+    };
+
+    /// [CBig] with value 1 and unlimited precision
+    pub const ONE: Self = Self {
+        re: FBig::ONE,
+        im: FBig::ZERO,
+    };
+
+    /// [CBig] with value i (the imaginary unit) and unlimited precision
+    pub const I: Self = Self {
+        re: FBig::ZERO,
+        im: FBig::ONE,
+    };
+
+    /// Create a complex number from the real part and the imaginary part.
+    #[inline]
+    pub const fn new(re: FBig<R, B>, im: FBig<R, B>) -> Self {
+        Self { re, im }
+    }
+
+    /// Create a complex number `r·(cos θ + i sin θ)` from the polar form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let r = DBig::from_str_native("2.0000")?;
+    /// let theta = DBig::from_str_native("0.5")?;
+    /// let z = CBig::from_polar(r, theta);
+    /// assert_eq!(z.to_string(), "1.7552+0.95885i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn from_polar(r: FBig<R, B>, theta: FBig<R, B>) -> Self {
+        let context = Context::max(r.context, theta.context);
+        check_precision_limited(context.precision);
+
+        let work_context = Context::<R>::new(context.precision + guard_digits::<B>(&context));
+        let (sin, cos) = work_context.sin_cos(&theta.repr);
+        Self {
+            re: context.mul(&r.repr, &cos.repr).value(),
+            im: context.mul(&r.repr, &sin.repr).value(),
+        }
+    }
+
+    /// Get the real part of the complex number
+    #[inline]
+    pub const fn re(&self) -> &FBig<R, B> {
+        &self.re
+    }
+
+    /// Get the imaginary part of the complex number
+    #[inline]
+    pub const fn im(&self) -> &FBig<R, B> {
+        &self.im
+    }
+
+    /// Get the real part and the imaginary part of the complex number
+    #[inline]
+    pub fn into_parts(self) -> (FBig<R, B>, FBig<R, B>) {
+        (self.re, self.im)
+    }
+
+    /// Get the context of the complex number, which is the context with
+    /// the higher precision between the two parts.
+    #[inline]
+    pub fn context(&self) -> Context<R> {
+        Context::max(self.re.context, self.im.context)
+    }
+
+    /// Get the precision of the complex number, which is the higher precision between the two parts.
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.re.precision().max(self.im.precision())
+    }
+
+    /// Determine if the complex number is zero
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.re.repr.is_zero() && self.im.repr.is_zero()
+    }
+
+    /// Get the complex conjugate `re - im·i`
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: -&self.im,
+        }
+    }
+
+    /// Calculate the squared magnitude `re² + im²` of the complex number.
+    #[inline]
+    pub fn norm(&self) -> FBig<R, B> {
+        let parts = [&self.re.repr, &self.im.repr];
+        self.context().dot(parts, parts).value()
+    }
+
+    /// Calculate the magnitude `√(re² + im²)` of the complex number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited and neither part is zero.
+    pub fn abs(&self) -> FBig<R, B> {
+        let context = self.context();
+        // the magnitude of a purely real or imaginary number is exact
+        if self.im.repr.is_zero() {
+            round_to(&context, self.re.clone().abs())
+        } else if self.re.repr.is_zero() {
+            round_to(&context, self.im.clone().abs())
+        } else {
+            context.hypot(&self.re.repr, &self.im.repr).value()
+        }
+    }
+
+    /// Calculate the argument (the phase angle) of the complex number in (-π, π].
+    ///
+    /// The argument of zero is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let a = CBig::new(DBig::from_str_native("-1.0000")?, DBig::from_str_native("0")?);
+    /// assert_eq!(a.arg(), DBig::from_str_native("3.1416")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn arg(&self) -> FBig<R, B> {
+        let context = self.context();
+        check_precision_limited(context.precision);
+        round_to(&context, context.atan2(&self.im.repr, &self.re.repr))
+    }
+
+    /// Convert the complex number to the polar form, i.e. return `(abs, arg)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn to_polar(&self) -> (FBig<R, B>, FBig<R, B>) {
+        (self.abs(), self.arg())
+    }
+
+    /// Change the context of both parts (for internal use), the new context must have
+    /// a precision not less than the current one.
+    #[inline]
+    fn with_context(&self, context: Context<R>) -> Self {
+        Self {
+            re: FBig::new(self.re.repr.clone(), context),
+            im: FBig::new(self.im.repr.clone(), context),
+        }
+    }
+
+    /// Calculate the principal square root of the complex number.
+    ///
+    /// The real part of the result is non-negative, and the branch cut is on the negative
+    /// real axis, where the imaginary part of the result is non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let a = CBig::new(DBig::from_str_native("-4.0")?, DBig::from_str_native("0")?);
+    /// assert_eq!(a.sqrt().to_string(), "0+2i");
+    /// let b = CBig::new(DBig::from_str_native("3.0")?, DBig::from_str_native("-4.0")?);
+    /// assert_eq!(b.sqrt().to_string(), "2-1i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn sqrt(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let context = self.context();
+        check_precision_limited(context.precision);
+
+        // let t = √((|z| + |re|) / 2), then √z = t + im/2t·i if re >= 0,
+        // otherwise √z = |im|/2t ± t·i
+        let work_context = Context::<R>::new(context.precision + guard_digits::<B>(&context));
+        let abs = work_context.hypot(&self.re.repr, &self.im.repr).value();
+        let half: FBig<R, B> = (abs + self.re.clone().abs()) / 2;
+        let t = work_context.sqrt(half.repr()).value();
+        let t2: FBig<R, B> = 2 * &t;
+        let u = work_context.div(&self.im.repr, &t2.repr).value();
+        let (re, im) = if self.re.repr.sign() == Sign::Positive {
+            (t, u)
+        } else if self.im.repr.sign() == Sign::Positive {
+            (u.abs(), t)
+        } else {
+            (u.abs(), -t)
+        };
+        Self {
+            re: round_to(&context, re),
+            im: round_to(&context, im),
+        }
+    }
+
+    /// Calculate the exponential function `exp(re)·(cos(im) + i sin(im))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let a = CBig::new(DBig::from_str_native("1.0000")?, DBig::from_str_native("1")?);
+    /// assert_eq!(a.exp().to_string(), "1.4687+2.2874i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn exp(&self) -> Self {
+        let context = self.context();
+        check_precision_limited(context.precision);
+
+        // the error of exp(re) is amplified by the magnitude of re
+        let work_precision =
+            context.precision + guard_digits::<B>(&context) + int_digits(&self.re.repr);
+        let work_context = Context::<R>::new(work_precision);
+        let scale = work_context.exp(&self.re.repr).value();
+        let (sin, cos) = work_context.sin_cos(&self.im.repr);
+        Self {
+            re: context.mul(&scale.repr, &cos.repr).value(),
+            im: context.mul(&scale.repr, &sin.repr).value(),
+        }
+    }
+
+    /// Calculate the principal natural logarithm `ln|z| + arg(z)·i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let a = CBig::new(DBig::from_str_native("0")?, DBig::from_str_native("-1.0000")?);
+    /// assert_eq!(a.ln().to_string(), "0-1.5708i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero, or the precision is unlimited.
+    pub fn ln(&self) -> Self {
+        if self.is_zero() {
+            panic_log_zero()
+        }
+        let context = self.context();
+        check_precision_limited(context.precision);
+
+        // ln|z| = ln(1 + (re² + im² - 1)) / 2, where the argument is calculated exactly
+        // to prevent the cancellation when |z| is close to 1
+        let work_context = Context::<R>::new(context.precision + guard_digits::<B>(&context));
+        let exact = Context::<R>::new(0);
+        let parts = [&self.re.repr, &self.im.repr];
+        let norm_m1 = exact.sub(exact.dot(parts, parts).value().repr(), &Repr::one());
+        let re = work_context.ln_1p(norm_m1.value().repr()).value() / 2;
+        let im = work_context.atan2(&self.im.repr, &self.re.repr);
+        Self {
+            re: round_to(&context, re),
+            im: round_to(&context, im),
+        }
+    }
+
+    /// Raise the complex number to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::CBig;
+    ///
+    /// let a = CBig::new(DBig::from_str_native("1.0")?, DBig::from_str_native("1.0")?);
+    /// assert_eq!(a.powi(8.into()).to_string(), "16+0i");
+    /// assert_eq!(a.powi((-2).into()).to_string(), "0-0.5i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is negative and the number is zero, or the precision is
+    /// unlimited when the exponent is negative.
+    pub fn powi(&self, exp: IBig) -> Self {
+        let (exp_sign, exp) = exp.into_parts();
+        if exp.is_zero() {
+            return Self::ONE;
+        }
+        if exp_sign == Sign::Negative {
+            return Self::ONE / self.powi(exp.into());
+        }
+
+        let context = self.context();
+        let work_context = if context.is_limited() {
+            // increase working precision when the exponent is large
+            let guard_digits = exp.bit_len() + context.precision.bit_len(); // heuristic
+            Context::<R>::new(context.precision + guard_digits)
+        } else {
+            context
+        };
+
+        // binary exponentiation from left to right
+        let base = self.with_context(work_context);
+        let mut res = base.clone();
+        for i in (0..exp.bit_len() - 1).rev() {
+            res = &res * &res;
+            if exp.bit(i) {
+                res = &res * &base;
+            }
+        }
+        Self {
+            re: round_to(&context, res.re),
+            im: round_to(&context, res.im),
+        }
+    }
+
+    /// Raise the complex number to a complex power, i.e. `exp(exp·ln(self))` with the principal
+    /// logarithm.
+    ///
+    /// `0` to the power of `0` is `1`, and `0` to the power of `w` is `0` if `w` has positive real part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{CBig, DBig};
+    /// let i = CBig::new(DBig::from_str_native("0")?, DBig::from_str_native("1.0000")?);
+    /// // i^i = exp(-π/2)
+    /// assert_eq!(i.pow(&i).to_string(), "0.20788+0i");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is zero and the real part of the exponent is not positive (unless the
+    /// exponent is zero), or the precision is unlimited.
+    pub fn pow(&self, exp: &Self) -> Self {
+        if exp.is_zero() {
+            return Self::ONE;
+        }
+        if self.is_zero() {
+            if exp.re.repr.sign() == Sign::Positive && !exp.re.repr.is_zero() {
+                return Self::ZERO;
+            }
+            panic_log_zero()
+        }
+
+        let context = Context::max(self.context(), exp.context());
+        check_precision_limited(context.precision);
+
+        // the error of exp·ln(self) is amplified by its magnitude in the exponentiation
+        let mut extra_digits = 0;
+        let product = loop {
+            let work_precision = context.precision + guard_digits::<B>(&context) + extra_digits;
+            let work_context = Context::<R>::new(work_precision);
+            let product = exp.with_context(work_context) * self.with_context(work_context).ln();
+            let digits = int_digits(&product.re.repr).max(int_digits(&product.im.repr));
+            if digits <= extra_digits {
+                break product;
+            }
+            extra_digits = digits;
+        };
+
+        let result = product.exp();
+        Self {
+            re: round_to(&context, result.re),
+            im: round_to(&context, result.im),
+        }
+    }
+
+    /// Change the precision of both parts.
+    ///
+    /// See [FBig::with_precision] for details.
+    #[inline]
+    pub fn with_precision(&self, precision: usize) -> Self {
+        Self {
+            re: self.re.clone().with_precision(precision).value(),
+            im: self.im.clone().with_precision(precision).value(),
+        }
+    }
+}
+
+/// Number of guard digits used in the complex functions
+#[inline]
+fn guard_digits<const B: Word>(context: &Context<impl Round>) -> usize {
+    (context.precision.log2_est() / B.log2_est()) as usize + 4
+}
+
+/// Number of digits in the integral part of the number
+#[inline]
+fn int_digits<const B: Word>(x: &Repr<B>) -> usize {
+    (x.exponent + x.digits() as isize).max(0) as usize
+}
+
+/// Round a number with higher precision to the context
+#[inline]
+fn round_to<R: Round, const B: Word>(context: &Context<R>, x: FBig<R, B>) -> FBig<R, B> {
+    FBig::new(context.repr_round(x.repr).value(), *context)
+}
+
+// These custom implementations are necessary for the same reason as FBig
+impl<R: Round, const B: Word> Clone for CBig<R, B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: self.im.clone(),
+        }
+    }
+}
+
+impl<R: Round, const B: Word> PartialEq for CBig<R, B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl<R: Round, const B: Word> Eq for CBig<R, B> {}
+
+impl<R: Round, const B: Word> Debug for CBig<R, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CBig")
+            .field("re", &self.re)
+            .field("im", &self.im)
+            .finish()
+    }
+}
+
+impl<R: Round, const B: Word> From<FBig<R, B>> for CBig<R, B> {
+    #[inline]
+    fn from(re: FBig<R, B>) -> Self {
+        Self { re, im: FBig::ZERO }
+    }
+}
+
+impl<R: Round, const B: Word> Display for CBig<R, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.re, f)?;
+        if self.im.repr.sign() == Sign::Negative {
+            write!(f, "-{}i", -&self.im)
+        } else {
+            write!(f, "+{}i", self.im)
+        }
+    }
+}
+
+impl<R: Round, const B: Word> Neg for CBig<R, B> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<R: Round, const B: Word> Neg for &CBig<R, B> {
+    type Output = CBig<R, B>;
+    #[inline]
+    fn neg(self) -> CBig<R, B> {
+        self.clone().neg()
+    }
+}
+
+fn add_ref_ref<R: Round, const B: Word>(lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+    CBig {
+        re: &lhs.re + &rhs.re,
+        im: &lhs.im + &rhs.im,
+    }
+}
+
+fn sub_ref_ref<R: Round, const B: Word>(lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+    CBig {
+        re: &lhs.re - &rhs.re,
+        im: &lhs.im - &rhs.im,
+    }
+}
+
+fn mul_ref_ref<R: Round, const B: Word>(lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+    // (a + bi)(c + di) = (ac - bd) + (ad + bc)i, each part is evaluated with a single rounding
+    let context = Context::max(lhs.context(), rhs.context());
+    let (a, b) = (&lhs.re.repr, &lhs.im.repr);
+    let (c, d) = (&rhs.re.repr, &rhs.im.repr);
+    let neg_d = -d.clone();
+    CBig {
+        re: context.dot([a, b], [c, &neg_d]).value(),
+        im: context.dot([a, b], [d, c]).value(),
+    }
+}
+
+fn div_ref_ref<R: Round, const B: Word>(lhs: &CBig<R, B>, rhs: &CBig<R, B>) -> CBig<R, B> {
+    if rhs.is_zero() {
+        panic_divide_by_0()
+    }
+
+    // (a + bi)/(c + di) = ((ac + bd) + (bc - ad)i) / (c² + d²), where the numerators and the
+    // denominator are evaluated exactly, so that each part is rounded only once
+    let context = Context::max(lhs.context(), rhs.context());
+    let exact = Context::<R>::new(0);
+    let (a, b) = (&lhs.re.repr, &lhs.im.repr);
+    let (c, d) = (&rhs.re.repr, &rhs.im.repr);
+    let neg_a = -a.clone();
+    let den = exact.dot([c, d], [c, d]).value();
+    let re = exact.dot([a, b], [c, d]).value();
+    let im = exact.dot([b, &neg_a], [c, d]).value();
+    CBig {
+        re: context.div(&re.repr, &den.repr).value(),
+        im: context.div(&im.repr, &den.repr).value(),
+    }
+}
+
+macro_rules! impl_complex_binop {
+    ($trait:ident, $method:ident, $impl:ident) => {
+        impl<R: Round, const B: Word> $trait for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> CBig<R, B> {
+                $impl(&self, &rhs)
+            }
+        }
+
+        impl<'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> CBig<R, B> {
+                $impl(&self, rhs)
+            }
+        }
+
+        impl<'l, R: Round, const B: Word> $trait<CBig<R, B>> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: CBig<R, B>) -> CBig<R, B> {
+                $impl(self, &rhs)
+            }
+        }
+
+        impl<'l, 'r, R: Round, const B: Word> $trait<&'r CBig<R, B>> for &'l CBig<R, B> {
+            type Output = CBig<R, B>;
+            #[inline]
+            fn $method(self, rhs: &CBig<R, B>) -> CBig<R, B> {
+                $impl(self, rhs)
+            }
+        }
+    };
+}
+
+impl_complex_binop!(Add, add, add_ref_ref);
+impl_complex_binop!(Sub, sub, sub_ref_ref);
+impl_complex_binop!(Mul, mul, mul_ref_ref);
+impl_complex_binop!(Div, div, div_ref_ref);
@@ -1,16 +1,16 @@
 use core::convert::{TryFrom, TryInto};
 
 use crate::{
-    error::{check_inf, panic_unlimited_precision},
+    error::{check_inf, panic_unlimited_precision, panic_zero_digits},
     fbig::FBig,
     repr::{Context, Repr},
     round::{
         mode::{self, HalfEven},
         Round, Rounded, Rounding,
     },
-    utils::{ilog_exact, shr_digits, split_digits_ref},
+    utils::{digit_len, ilog_exact, shr_digits, split_digits_ref},
 };
-use dashu_base::{Approximation::*, DivRemEuclid, EstimatedLog2};
+use dashu_base::{Approximation::*, DivRem, DivRemEuclid, EstimatedLog2, UnsignedAbs};
 use dashu_int::{error::OutOfBoundsError, IBig, UBig, Word};
 
 impl<R: Round> Context<R> {
@@ -61,8 +61,8 @@ impl<R: Round> TryFrom<f32> for FBig<R, 2> {
 
         // then parse normal values
         let mantissa = if exponent == 0 {
-            exponent = -127;
-            mantissa_bits << 1
+            exponent = -126 - 23; // subnormal
+            mantissa_bits
         } else {
             exponent -= 127 + 23; // bias + mantissa shift
             mantissa_bits | 0x800000
@@ -99,8 +99,8 @@ impl<R: Round> TryFrom<f64> for FBig<R, 2> {
         }
 
         let mantissa = if exponent == 0 {
-            exponent = -1023;
-            mantissa_bits << 1
+            exponent = -1022 - 52; // subnormal
+            mantissa_bits
         } else {
             exponent -= 1023 + 52; // bias + mantissa shift
             mantissa_bits | 0x10000000000000
@@ -256,7 +256,7 @@ impl<R: Round, const B: Word> FBig<R, B> {
     pub fn with_rounding<NewR: Round>(self) -> FBig<NewR, B> {
         FBig {
             repr: self.repr,
-            context: Context::new(self.context.precision),
+            context: self.context.with_rounding(),
         }
     }
 
@@ -634,6 +634,159 @@ impl<R: Round, const B: Word> FBig<R, B> {
         let context = Context::new(self.precision() - precision);
         FBig::new(Repr::new(hi + rounding, 0), context)
     }
+
+    /// Round the float number to an integer, using the rounding mode of the number.
+    ///
+    /// Unlike [trunc][Self::trunc], [floor][Self::floor] and [ceil][Self::ceil], the precision
+    /// of the number is not changed, and the rounding error is returned along with the result.
+    /// It's equivalent to `self.quantize(0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let a = DBig::from_str_native("1.5")?;
+    /// assert_eq!(a.round(), Inexact(DBig::from_str_native("2")?, AddOne));
+    /// let b = DBig::from_str_native("-12.34")?;
+    /// assert_eq!(b.round(), Inexact(DBig::from_str_native("-12")?, NoOp));
+    /// let c = DBig::from_str_native("1e10")?;
+    /// assert_eq!(c.round(), Exact(c));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinte
+    #[inline]
+    pub fn round(&self) -> Rounded<Self> {
+        self.quantize(0)
+    }
+
+    /// Round the float number to the given number of digits after the radix point (in base `B`),
+    /// using the rounding mode of the number.
+    ///
+    /// A negative `places` rounds the number to a multiple of `B ^ -places`. The precision of the
+    /// number is not changed. It's equivalent to `self.quantize(-places)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let a = DBig::from_str_native("-3.14159")?;
+    /// assert_eq!(a.round_to(2), Inexact(DBig::from_str_native("-3.14")?, NoOp));
+    /// assert_eq!(a.round_to(4), Inexact(DBig::from_str_native("-3.1416")?, SubOne));
+    /// assert_eq!(a.round_to(10), Exact(a.clone()));
+    ///
+    /// let b = DBig::from_str_native("1250")?;
+    /// assert_eq!(b.round_to(-2), Inexact(DBig::from_str_native("1300")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinte
+    #[inline]
+    pub fn round_to(&self, places: isize) -> Rounded<Self> {
+        self.quantize(-places)
+    }
+
+    /// Round the float number to a multiple of `B ^ exp`, using the rounding mode of the number.
+    ///
+    /// This can be used to match the exponent of another number (e.g. `a.quantize(b.repr().exponent())`).
+    /// Note that the representation is always normalized, so the exponent of the result can be
+    /// larger than `exp` when there are trailing zeros. The precision of the number is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let a = DBig::from_str_native("123.456")?;
+    /// let b = DBig::from_str_native("0.01")?;
+    /// assert_eq!(a.quantize(b.repr().exponent()), Inexact(DBig::from_str_native("123.46")?, AddOne));
+    /// assert_eq!(a.quantize(1), Inexact(DBig::from_str_native("120")?, NoOp));
+    /// assert_eq!(a.quantize(3), Inexact(DBig::ZERO, NoOp));
+    /// assert_eq!(a.quantize(-5), Exact(a));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinte
+    pub fn quantize(&self, exp: isize) -> Rounded<Self> {
+        check_inf(&self.repr);
+        if self.repr.exponent >= exp || self.repr.is_zero() {
+            return Exact(self.clone());
+        }
+
+        let shift = (exp - self.repr.exponent) as usize;
+        let digits = self.repr.digits();
+        let (hi, adjust) = if shift > digits {
+            // |self| < B ^ (exp - 1), the fraction can be evaluated with a smaller shift
+            // without changing the rounding result
+            let signif = self.repr.significand.clone();
+            (IBig::ZERO, R::round_fract::<B>(&IBig::ZERO, signif, digits + 1))
+        } else {
+            let (hi, lo) = split_digits_ref::<B>(&self.repr.significand, shift);
+            if lo.is_zero() {
+                return Exact(self.clone());
+            }
+            let adjust = R::round_fract::<B>(&hi, lo, shift);
+            (hi, adjust)
+        };
+        let repr = Repr::new(hi + adjust, exp);
+        Inexact(FBig::new(repr, self.context), adjust)
+    }
+
+    /// Round the float number to the given number of significant digits (in base `B`),
+    /// using the rounding mode of the number.
+    ///
+    /// Unlike [with_precision][Self::with_precision], the precision of the number is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let a = DBig::from_str_native("-123.456")?;
+    /// assert_eq!(a.round_to_significant(2), Inexact(DBig::from_str_native("-120")?, NoOp));
+    /// assert_eq!(a.round_to_significant(5), Inexact(DBig::from_str_native("-123.46")?, SubOne));
+    /// assert_eq!(a.round_to_significant(5).value().precision(), 6);
+    ///
+    /// let b = DBig::from_str_native("9.96")?;
+    /// assert_eq!(b.round_to_significant(2), Inexact(DBig::from_str_native("10")?, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinte or `digits` is zero.
+    pub fn round_to_significant(&self, digits: usize) -> Rounded<Self> {
+        check_inf(&self.repr);
+        if digits == 0 {
+            panic_zero_digits();
+        }
+        if self.repr.is_zero() {
+            return Exact(self.clone());
+        }
+
+        let exp = self.repr.exponent + self.repr.digits() as isize - digits as isize;
+        self.quantize(exp)
+    }
 }
 
 impl<R: Round> FBig<R, 2> {
@@ -653,29 +806,33 @@ impl<R: Round> FBig<R, 2> {
     ///
     /// let b = DBig::INFINITY;
     /// assert_eq!(b.with_base_and_precision::<2>(23).value().to_f32().value(), f32::INFINITY);
+    ///
+    /// // subnormal numbers are rounded to their actual precision
+    /// let c = DBig::from_str_native("1e-40")?;
+    /// assert_eq!(c.with_base_and_precision::<2>(23).value().to_f32().value(), 1e-40);
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn to_f32(&self) -> Rounded<f32> {
         if self.repr.is_infinite() {
             return Inexact(self.repr.sign() * f32::INFINITY, Rounding::NoOp);
-        } else if self > &Self::try_from(f32::MAX).unwrap() {
-            return Inexact(f32::INFINITY, Rounding::AddOne);
-        } else if self < &Self::try_from(f32::MIN).unwrap() {
-            return Inexact(f32::NEG_INFINITY, Rounding::SubOne);
         }
 
-        // TODO: this implementation is a bandaid, it doesn't handles subnormal yet
-        let context = Context::<HalfEven>::new(24);
+        let context = Context::<HalfEven>::new(24).with_exponent_range(-126, 127);
         context.repr_round_ref(&self.repr).map(|v| {
-            let exp2 = if v.exponent > 127 {
-                f32::INFINITY
-            } else if v.exponent < -127 {
-                0.0
+            if v.is_infinite() {
+                v.sign() * f32::INFINITY
+            } else if v.is_zero() {
+                self.repr.sign() * 0f32
             } else {
-                let ebits = (v.exponent + 127) as u32;
-                f32::from_bits(ebits << 23)
-            };
-            v.significand.to_f32().value() * exp2
+                // the significand has at most 24 bits and the exponent is in [-149, 127],
+                // so the result can be represented exactly
+                let exp2 = if v.exponent >= -126 {
+                    f32::from_bits(((v.exponent + 127) as u32) << 23)
+                } else {
+                    f32::from_bits(1 << (v.exponent + 149))
+                };
+                v.significand.to_f32().value() * exp2
+            }
         })
     }
 
@@ -695,30 +852,177 @@ impl<R: Round> FBig<R, 2> {
     ///
     /// let b = DBig::INFINITY;
     /// assert_eq!(b.with_base_and_precision::<2>(53).value().to_f64().value(), f64::INFINITY);
+    ///
+    /// // subnormal numbers are rounded to their actual precision
+    /// let c = DBig::from_str_native("1e-310")?;
+    /// assert_eq!(c.with_base_and_precision::<2>(53).value().to_f64().value(), 1e-310);
     /// # Ok::<(), ParseError>(())
     /// ```
     pub fn to_f64(&self) -> Rounded<f64> {
         if self.repr.is_infinite() {
             return Inexact(self.repr.sign() * f64::INFINITY, Rounding::NoOp);
-        } else if self > &Self::try_from(f64::MAX).unwrap() {
-            return Inexact(f64::INFINITY, Rounding::AddOne);
-        } else if self < &Self::try_from(f64::MIN).unwrap() {
-            return Inexact(f64::NEG_INFINITY, Rounding::SubOne);
         }
 
-        let context = Context::<HalfEven>::new(53);
+        let context = Context::<HalfEven>::new(53).with_exponent_range(-1022, 1023);
         context.repr_round_ref(&self.repr).map(|v| {
-            let exp2 = if v.exponent > 1023 {
-                f64::INFINITY
-            } else if v.exponent < -1023 {
-                0.0
+            if v.is_infinite() {
+                v.sign() * f64::INFINITY
+            } else if v.is_zero() {
+                self.repr.sign() * 0f64
             } else {
-                let ebits = (v.exponent + 1023) as u64;
-                f64::from_bits(ebits << 52)
-            };
-            v.significand.to_f64().value() * exp2
+                // the significand has at most 53 bits and the exponent is in [-1074, 1023],
+                // so the result can be represented exactly
+                let exp2 = if v.exponent >= -1022 {
+                    f64::from_bits(((v.exponent + 1023) as u64) << 52)
+                } else {
+                    f64::from_bits(1 << (v.exponent + 1074))
+                };
+                v.significand.to_f64().value() * exp2
+            }
         })
     }
+
+    /// Convert the float number to the shortest decimal number that converts back to the
+    /// same binary number.
+    ///
+    /// The result is the decimal number with the fewest significant digits, such that rounding
+    /// it to the context of this number (with the same precision, rounding mode and exponent
+    /// range) gives exactly this number. If there are multiple candidates with the same number of
+    /// digits, the one closest to this number is returned, and ties are broken by choosing the one
+    /// with the larger magnitude. The precision of the result is the number of its significant digits.
+    ///
+    /// This is a generalization of the shortest round-trip printing algorithms for [f32] and [f64]
+    /// (such as Ryu and Grisu) to arbitrary precisions and rounding modes. To print a [f64] value
+    /// in the shortest form, convert it to a [FBig] with the [HalfEven][mode::HalfEven] rounding mode.
+    /// Note that the exponent range is not limited for the numbers converted from [f64], so the
+    /// results for subnormal values can be longer than the ones printed by the standard library.
+    ///
+    /// If the precision of the number is unlimited, the exact decimal expansion is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, DBig, round::mode::{HalfEven, Zero}};
+    /// let a = FBig::<HalfEven>::try_from(0.3f64).unwrap();
+    /// assert_eq!(
+    ///     a.clone().with_base_and_precision::<10>(20).value().to_string(),
+    ///     "0.2999999999999999889"
+    /// );
+    /// assert_eq!(a.to_shortest_decimal().to_string(), "0.3");
+    /// assert_eq!(format!("{:e}", FBig::<HalfEven>::try_from(1e-20f64).unwrap().to_shortest_decimal()), "1e-20");
+    ///
+    /// // the result depends on the rounding mode
+    /// let b = FBig::<HalfEven>::try_from(0.1f64).unwrap();
+    /// assert_eq!(b.to_shortest_decimal().to_string(), "0.1");
+    /// let b = FBig::<Zero>::try_from(0.1f64).unwrap();
+    /// assert_eq!(b.to_shortest_decimal().to_string(), "0.10000000000000001");
+    ///
+    /// // the result converts back to the same number
+    /// let c = FBig::<HalfEven>::from_str_native("0x1.921fb54442d18p+1")?.with_precision(80).value();
+    /// let d = c.to_shortest_decimal();
+    /// assert_eq!(d.to_string(), "3.141592653589793115997963");
+    /// assert_eq!(d.with_base_and_precision::<2>(80).value(), c);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn to_shortest_decimal(&self) -> FBig<R, 10> {
+        if self.repr.is_infinite() {
+            return FBig::new(
+                Repr {
+                    significand: self.repr.significand.clone(),
+                    exponent: self.repr.exponent,
+                },
+                Context::new(0),
+            );
+        }
+        if self.repr.is_zero() {
+            return FBig::ZERO;
+        }
+
+        // the exact decimal expansion always converts back to the same number
+        let exact = if self.repr.exponent >= 0 {
+            Repr::<10>::new(&self.repr.significand << self.repr.exponent as usize, 0)
+        } else {
+            let signif = &self.repr.significand * UBig::from(5u8).pow(-self.repr.exponent as usize);
+            Repr::<10>::new(signif, self.repr.exponent)
+        };
+        let exact_digits = exact.digits();
+
+        // find the shortest candidates with binary search. If there are `n`-digit decimal
+        // numbers converting back to this number, then one of them is the nearest `n`-digit
+        // number below or above this number, because the set of such numbers is an interval.
+        let (mut lo, mut hi) = (1, exact_digits);
+        let mut shortest = None;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            match self.shortest_candidate(&exact, mid) {
+                Some(repr) => {
+                    shortest = Some(repr);
+                    hi = mid;
+                }
+                None => lo = mid + 1,
+            }
+        }
+
+        let repr = shortest.unwrap_or(exact);
+        let precision = repr.digits();
+        FBig::new(repr, Context::new(precision))
+    }
+
+    /// Find the decimal number with `n` digits that is nearest to `exact` (the exact decimal
+    /// value of this number) and converts back to this number.
+    fn shortest_candidate(&self, exact: &Repr<10>, n: usize) -> Option<Repr<10>> {
+        let digits = exact.digits();
+        if n >= digits {
+            return Some(exact.clone());
+        }
+
+        // truncate the exact value to `n` digits
+        let shift = digits - n;
+        let exponent = exact.exponent + shift as isize;
+        let (trunc, rem) = split_digits_ref::<10>(&exact.significand, shift);
+        let away = &trunc + exact.sign() * IBig::ONE;
+        let trunc_ok = self.is_rounded_from(&trunc, exponent);
+        let away_ok = self.is_rounded_from(&away, exponent);
+
+        let signif = match (trunc_ok, away_ok) {
+            (false, false) => return None,
+            (true, false) => trunc,
+            (false, true) => away,
+            (true, true) => {
+                // choose the nearest one, ties away from zero
+                let half = UBig::from(5u8) * UBig::from(10u8).pow(shift - 1);
+                if rem.unsigned_abs() < half {
+                    trunc
+                } else {
+                    away
+                }
+            }
+        };
+        Some(Repr::new(signif, exponent))
+    }
+
+    /// Check whether `significand * 10 ^ exponent` is rounded to this number under its context.
+    fn is_rounded_from(&self, significand: &IBig, exponent: isize) -> bool {
+        let context = &self.context;
+        let binary = if exponent >= 0 {
+            Repr::<2>::new(significand * UBig::from(10u8).pow(exponent as usize), 0)
+        } else {
+            // evaluate the quotient with enough bits, and a sticky bit for the remainder,
+            // so that the quotient is rounded correctly
+            let den = IBig::from(UBig::from(5u8).pow(-exponent as usize));
+            let shift = (context.precision + digit_len::<2>(&den) + 2)
+                .saturating_sub(digit_len::<2>(significand));
+            let (q, r) = (significand << shift).div_rem(&den);
+            let exp = exponent - shift as isize;
+            if r.is_zero() {
+                Repr::new(q, exp)
+            } else {
+                Repr::new((q << 1) + r.sign() * IBig::ONE, exp - 1)
+            }
+        };
+        context.repr_round(binary).value() == self.repr
+    }
 }
 
 impl<R: Round, const B: Word> From<IBig> for FBig<R, B> {
@@ -0,0 +1,398 @@
+use crate::{
+    error::{check_inf, panic_divide_by_0, panic_zero_ratios},
+    fbig::FBig,
+    fmt::{fmt_fixed_point, rounding_name},
+    helper_macros,
+    round::{mode, Round, Rounded},
+    utils::{shl_digits, shl_digits_in_place, split_digits, split_digits_ref},
+};
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+use dashu_base::{Abs, Approximation::*, DivRem, Sign, UnsignedAbs};
+use dashu_int::{error::ParseError, IBig, UBig};
+
+/// A decimal fixed-point number with a fixed number of fractional digits, designed for monetary
+/// calculations.
+///
+/// The number is stored as an integer count of the units `10^-SCALE`. Addition, subtraction and
+/// negation are always exact, while the results of multiplication and division are rounded to
+/// `SCALE` fractional digits with the rounding mode `RoundingMode`.
+///
+/// To change the number of fractional digits, use [rescale][Decimal::rescale]. To round the number
+/// to a multiple of a given step (e.g. cash rounding to 0.05), use [quantize][Decimal::quantize].
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// use dashu_float::Decimal;
+///
+/// type Money = Decimal<2>;
+///
+/// let price: Money = "19.99".parse()?;
+/// let rate: Money = "0.08".parse()?;
+/// let tax = &price * &rate; // 1.5992 rounded to 1.60
+/// assert_eq!(tax.to_string(), "1.60");
+/// assert_eq!((price + tax).to_string(), "21.59");
+///
+/// // split an amount without losing cents
+/// let parts = Money::from(100).split(3);
+/// assert_eq!(parts, ["33.34".parse()?, "33.33".parse()?, "33.33".parse()?]);
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct Decimal<const SCALE: usize, RoundingMode: Round = mode::HalfAway> {
+    pub(crate) raw: IBig,
+    _marker: PhantomData<RoundingMode>,
+}
+
+helper_macros::impl_fixed_point_common!(Decimal, 10, "scale");
+
+impl<const S: usize, R: Round> Decimal<S, R> {
+    /// Create a fixed-point number from the number of units `10^-SCALE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::Decimal;
+    ///
+    /// let a = Decimal::<2>::from_raw((-1234).into());
+    /// assert_eq!(a.to_string(), "-12.34");
+    /// ```
+    #[inline]
+    pub const fn from_raw(raw: IBig) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the number of units `10^-SCALE` in the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{error::ParseError, IBig};
+    /// use dashu_float::Decimal;
+    ///
+    /// let a: Decimal<3> = "-1.5".parse()?;
+    /// assert_eq!(a.raw(), &IBig::from(-1500));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub const fn raw(&self) -> &IBig {
+        &self.raw
+    }
+
+    /// Convert the number into the number of units `10^-SCALE`.
+    #[inline]
+    pub fn into_raw(self) -> IBig {
+        self.raw
+    }
+
+    /// The number of fractional digits of this type
+    #[inline]
+    pub const fn scale(&self) -> usize {
+        S
+    }
+
+    /// Round the number to a multiple of the `quantum` with the rounding mode of this type.
+    ///
+    /// The sign of the quantum is ignored, and the rounding adjustment in the result is counted
+    /// in multiples of the absolute value of the quantum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Decimal, round::Rounding::*};
+    ///
+    /// // cash rounding to 0.05
+    /// let a: Decimal<2> = "12.38".parse()?;
+    /// let q: Decimal<2> = "0.05".parse()?;
+    /// assert_eq!(a.quantize(&q), Inexact("12.40".parse()?, AddOne));
+    ///
+    /// // round to whole units
+    /// let b: Decimal<2> = "-7.25".parse()?;
+    /// assert_eq!(b.quantize(&Decimal::from(1)), Inexact("-7".parse()?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quantum is zero.
+    pub fn quantize(&self, quantum: &Self) -> Rounded<Self> {
+        if quantum.is_zero() {
+            panic_divide_by_0()
+        }
+
+        // the rounding direction is relative to a positive divisor
+        let unit = quantum.raw.clone().abs();
+        let (q, r) = (&self.raw).div_rem(&unit);
+        if r.is_zero() {
+            return Exact(self.clone());
+        }
+        let adjust = R::round_ratio(&q, r, &unit);
+        Inexact(Self::from_raw((q + adjust) * unit), adjust)
+    }
+
+    /// Change the number of fractional digits, with rounding if the new scale is smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Decimal, round::Rounding::*};
+    ///
+    /// let a: Decimal<4> = "2.0125".parse()?;
+    /// assert_eq!(a.rescale::<2>(), Inexact("2.01".parse()?, NoOp));
+    /// assert_eq!(a.rescale::<6>(), Exact("2.0125".parse()?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn rescale<const NEW_SCALE: usize>(&self) -> Rounded<Decimal<NEW_SCALE, R>> {
+        if NEW_SCALE >= S {
+            Exact(Decimal::from_raw(shl_digits::<10>(&self.raw, NEW_SCALE - S)))
+        } else {
+            let shift = S - NEW_SCALE;
+            let (q, r) = split_digits_ref::<10>(&self.raw, shift);
+            Decimal::round_fract(q, r, shift)
+        }
+    }
+
+    /// Multiply two numbers, and round the product to `SCALE` fractional digits.
+    ///
+    /// This is the same as the `*` operator, but the rounding adjustment is also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Decimal, round::Rounding::*};
+    ///
+    /// let a: Decimal<2> = "1.25".parse()?;
+    /// let b: Decimal<2> = "0.5".parse()?;
+    /// assert_eq!(a.mul_rounded(&b), Inexact("0.63".parse()?, AddOne));
+    /// assert_eq!(a.mul_rounded(&Decimal::from(2)), Exact("2.5".parse()?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn mul_rounded(&self, rhs: &Self) -> Rounded<Self> {
+        let (q, r) = split_digits::<10>(&self.raw * &rhs.raw, S);
+        Self::round_fract(q, r, S)
+    }
+
+    /// Divide two numbers, and round the quotient to `SCALE` fractional digits.
+    ///
+    /// This is the same as the `/` operator, but the rounding adjustment is also returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero. See [checked_div][Decimal::checked_div] for a non-panicking version.
+    #[inline]
+    pub fn div_rounded(&self, rhs: &Self) -> Rounded<Self> {
+        match self.checked_div(rhs) {
+            Some(q) => q,
+            None => panic_divide_by_0(),
+        }
+    }
+
+    /// Divide two numbers, and round the quotient to `SCALE` fractional digits.
+    /// Return [None] if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Decimal, round::Rounding::*};
+    ///
+    /// let a: Decimal<2> = "10".parse()?;
+    /// let b: Decimal<2> = "3".parse()?;
+    /// assert_eq!(a.checked_div(&b), Some(Inexact("3.33".parse()?, NoOp)));
+    /// assert_eq!(a.checked_div(&"0.4".parse()?), Some(Exact("25".parse()?)));
+    /// assert_eq!(a.checked_div(&Decimal::ZERO), None);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> Option<Rounded<Self>> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        let mut num = self.raw.clone();
+        shl_digits_in_place::<10>(&mut num, S);
+        let (q, r) = num.div_rem(&rhs.raw);
+        Some(Self::round_ratio(q, r, &rhs.raw))
+    }
+
+    /// Split the number into parts proportional to the given ratios, such that the parts
+    /// add up exactly to the number.
+    ///
+    /// Each part is first truncated to `SCALE` fractional digits, then the remaining units are
+    /// distributed one by one to the parts with the largest truncated remainders (the earlier part
+    /// wins in a tie).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::Decimal;
+    /// use dashu_int::UBig;
+    ///
+    /// // 0.10 * 1/3 = 0.0333.., 0.10 * 2/3 = 0.0666..
+    /// let a: Decimal<2> = "0.10".parse()?;
+    /// let parts = a.allocate(&[UBig::from(1u8), UBig::from(2u8)]);
+    /// assert_eq!(parts, ["0.03".parse()?, "0.07".parse()?]);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of the ratios is zero (including when there is no ratio).
+    pub fn allocate(&self, ratios: &[UBig]) -> Vec<Self> {
+        let total: UBig = ratios.iter().sum();
+        if total.is_zero() {
+            panic_zero_ratios()
+        }
+        let total = IBig::from(total);
+
+        let mut parts = Vec::with_capacity(ratios.len());
+        let mut rems = Vec::with_capacity(ratios.len());
+        let mut left = self.raw.clone();
+        for ratio in ratios {
+            let (q, r) = (&self.raw * IBig::from(ratio.clone())).div_rem(&total);
+            left -= &q;
+            parts.push(q);
+            rems.push(r.unsigned_abs());
+        }
+
+        // the remaining units are fewer than the parts with non-zero remainders
+        let unit = sign_unit(self.raw.sign());
+        let mut order: Vec<usize> = (0..ratios.len()).collect();
+        order.sort_by(|&i, &j| rems[j].cmp(&rems[i]));
+        for i in order {
+            if left.is_zero() {
+                break;
+            }
+            parts[i] += &unit;
+            left -= &unit;
+        }
+
+        parts.into_iter().map(Self::from_raw).collect()
+    }
+
+    /// Split the number into `n` parts as even as possible, such that the parts add up
+    /// exactly to the number. The earlier parts are larger in magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::Decimal;
+    ///
+    /// let a: Decimal<2> = "-0.10".parse()?;
+    /// assert_eq!(a.split(4), ["-0.03".parse()?, "-0.03".parse()?, "-0.02".parse()?, "-0.02".parse()?]);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split(&self, n: usize) -> Vec<Self> {
+        if n == 0 {
+            panic_zero_ratios()
+        }
+
+        let (q, r) = (&self.raw).div_rem(IBig::from(n));
+        let extra: usize = r.unsigned_abs().try_into().unwrap();
+        let unit = sign_unit(self.raw.sign());
+        (0..n)
+            .map(|i| {
+                if i < extra {
+                    Self::from_raw(&q + &unit)
+                } else {
+                    Self::from_raw(q.clone())
+                }
+            })
+            .collect()
+    }
+
+    /// Convert a decimal float number to the fixed-point number, with rounding if the
+    /// number has more than `SCALE` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{DBig, Decimal, round::Rounding::*};
+    ///
+    /// let a = DBig::from_str_native("-1.005")?;
+    /// assert_eq!(Decimal::<2>::from_fbig(&a), Inexact("-1.01".parse()?, SubOne));
+    /// assert_eq!(Decimal::<4>::from_fbig(&a), Exact("-1.005".parse()?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    pub fn from_fbig<R2: Round>(x: &FBig<R2, 10>) -> Rounded<Self> {
+        check_inf(&x.repr);
+
+        let exp = x.repr.exponent + S as isize;
+        if exp >= 0 {
+            Exact(Self::from_raw(shl_digits::<10>(&x.repr.significand, exp as usize)))
+        } else {
+            let shift = -exp as usize;
+            let (q, r) = split_digits_ref::<10>(&x.repr.significand, shift);
+            Self::round_fract(q, r, shift)
+        }
+    }
+
+    /// Convert the fixed-point number to a decimal float number.
+    ///
+    /// The conversion is exact, and the precision of the result is the number of digits in
+    /// the (normalized) significand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{DBig, Decimal};
+    ///
+    /// let a: Decimal<2> = "12.50".parse()?;
+    /// assert_eq!(a.to_fbig(), DBig::from_str_native("12.5")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn to_fbig(&self) -> FBig<R, 10> {
+        FBig::from_parts(self.raw.clone(), -(S as isize))
+    }
+}
+
+/// The unit with the given sign
+#[inline]
+fn sign_unit(sign: Sign) -> IBig {
+    match sign {
+        Sign::Positive => IBig::ONE,
+        Sign::Negative => IBig::NEG_ONE,
+    }
+}
+
+impl<const S: usize, R: Round> Display for Decimal<S, R> {
+    /// Print the number with exactly `SCALE` fractional digits. If the precision option of
+    /// the formatter is set, the number will be printed with the given number of fractional
+    /// digits, with rounding if necessary.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_fixed_point::<R>(f, &self.raw, S, S)
+    }
+}
@@ -4,10 +4,10 @@ use crate::{
     helper_macros,
     repr::{Context, Repr, Word},
     round::{Round, Rounded},
-    utils::{digit_len, shl_digits_in_place},
+    utils::{digit_len, shl_digits, shl_digits_in_place, split_digits},
 };
-use core::ops::{Div, DivAssign};
-use dashu_base::{Approximation, DivEuclid, DivRem, DivRemEuclid, RemEuclid};
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+use dashu_base::{Approximation, DivEuclid, DivRem, DivRemEuclid, RemEuclid, Sign};
 use dashu_int::{IBig, UBig};
 
 impl<R: Round, const B: Word> Div<FBig<R, B>> for FBig<R, B> {
@@ -174,10 +174,184 @@ impl<'l, 'r, R: Round, const B: Word> DivRemEuclid<&'r FBig<R, B>> for &'l FBig<
     }
 }
 
+impl<R: Round, const B: Word> Rem<FBig<R, B>> for FBig<R, B> {
+    type Output = FBig<R, B>;
+    #[inline]
+    fn rem(self, rhs: FBig<R, B>) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<'l, R: Round, const B: Word> Rem<FBig<R, B>> for &'l FBig<R, B> {
+    type Output = FBig<R, B>;
+    #[inline]
+    fn rem(self, rhs: FBig<R, B>) -> Self::Output {
+        self.clone().div_rem(rhs).1
+    }
+}
+
+impl<'r, R: Round, const B: Word> Rem<&'r FBig<R, B>> for FBig<R, B> {
+    type Output = FBig<R, B>;
+    #[inline]
+    fn rem(self, rhs: &FBig<R, B>) -> Self::Output {
+        self.div_rem(rhs.clone()).1
+    }
+}
+
+impl<'l, 'r, R: Round, const B: Word> Rem<&'r FBig<R, B>> for &'l FBig<R, B> {
+    type Output = FBig<R, B>;
+    #[inline]
+    fn rem(self, rhs: &FBig<R, B>) -> Self::Output {
+        self.clone().div_rem(rhs.clone()).1
+    }
+}
+
+impl<R: Round, const B: Word> RemAssign for FBig<R, B> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = core::mem::take(self) % rhs
+    }
+}
+impl<R: Round, const B: Word> RemAssign<&FBig<R, B>> for FBig<R, B> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &FBig<R, B>) {
+        *self = core::mem::take(self) % rhs
+    }
+}
+
+impl<R: Round, const B: Word> DivRem<FBig<R, B>> for FBig<R, B> {
+    type OutputDiv = IBig;
+    type OutputRem = FBig<R, B>;
+    #[inline]
+    fn div_rem(self, rhs: FBig<R, B>) -> (IBig, FBig<R, B>) {
+        check_inf_operands(&self.repr, &rhs.repr);
+        let r_exponent = self.repr.exponent.min(rhs.repr.exponent);
+        let context = Context::max(self.context, rhs.context);
+
+        let (num, den) = align_as_int(self, rhs);
+        let (q, r) = num.div_rem(den);
+        (q, FBig::new(Repr::new(r, r_exponent), context))
+    }
+}
+
+impl<'l, R: Round, const B: Word> DivRem<FBig<R, B>> for &'l FBig<R, B> {
+    type OutputDiv = IBig;
+    type OutputRem = FBig<R, B>;
+    #[inline]
+    fn div_rem(self, rhs: FBig<R, B>) -> (IBig, FBig<R, B>) {
+        self.clone().div_rem(rhs)
+    }
+}
+
+impl<'r, R: Round, const B: Word> DivRem<&'r FBig<R, B>> for FBig<R, B> {
+    type OutputDiv = IBig;
+    type OutputRem = FBig<R, B>;
+    #[inline]
+    fn div_rem(self, rhs: &FBig<R, B>) -> (IBig, FBig<R, B>) {
+        self.div_rem(rhs.clone())
+    }
+}
+
+impl<'l, 'r, R: Round, const B: Word> DivRem<&'r FBig<R, B>> for &'l FBig<R, B> {
+    type OutputDiv = IBig;
+    type OutputRem = FBig<R, B>;
+    #[inline]
+    fn div_rem(self, rhs: &FBig<R, B>) -> (IBig, FBig<R, B>) {
+        self.clone().div_rem(rhs.clone())
+    }
+}
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the remainder defined by IEEE 754, that is `self - n * rhs` where `n` is
+    /// the quotient `self / rhs` rounded to the nearest integer (ties to even).
+    ///
+    /// The remainder is calculated exactly regardless of the precision, and its magnitude
+    /// is at most half of the magnitude of `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("10")?;
+    /// let b = DBig::from_str_native("3")?;
+    /// assert_eq!(a.remainder(&b), DBig::from_str_native("1")?);
+    ///
+    /// let b = DBig::from_str_native("4")?;
+    /// assert_eq!(a.remainder(&b), DBig::from_str_native("2")?); // 10 / 4 = 2.5 is rounded to 2
+    /// let b = DBig::from_str_native("6")?;
+    /// assert_eq!(a.remainder(&b), DBig::from_str_native("-2")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or any of the operands is infinite.
+    #[inline]
+    pub fn remainder(&self, rhs: &Self) -> Self {
+        self.div_rem_nearest(rhs).2
+    }
+
+    /// Calculate the IEEE 754 remainder (see [remainder()][FBig::remainder]) along with
+    /// the low bits of the rounded quotient.
+    ///
+    /// The second returned value has the sign of the quotient, and its magnitude is the
+    /// lowest 31 bits of the magnitude of the quotient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-10")?;
+    /// let b = DBig::from_str_native("3")?;
+    /// assert_eq!(a.remquo(&b), (DBig::from_str_native("-1")?, -3));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or any of the operands is infinite.
+    pub fn remquo(&self, rhs: &Self) -> (Self, i32) {
+        let (sign, mut q, r) = self.div_rem_nearest(rhs);
+        q.clear_high_bits(31);
+        let q = i32::try_from(&q).unwrap();
+        match sign {
+            Sign::Positive => (r, q),
+            Sign::Negative => (r, -q),
+        }
+    }
+
+    /// Divide and round the quotient to the nearest integer (ties to even),
+    /// return the sign and magnitude of the quotient and the exact remainder.
+    fn div_rem_nearest(&self, rhs: &Self) -> (Sign, UBig, Self) {
+        check_inf_operands(&self.repr, &rhs.repr);
+        let r_exponent = self.repr.exponent.min(rhs.repr.exponent);
+        let context = Context::max(self.context, rhs.context);
+
+        let (num, den) = align_as_int(self.clone(), rhs.clone());
+        let (num_sign, num) = num.into_parts();
+        let (den_sign, den) = den.into_parts();
+        let (mut q, mut r) = num.div_rem(&den);
+        let mut r_sign = num_sign;
+
+        let double = &r << 1;
+        if double > den || (double == den && q.bit(0)) {
+            q += 1u8;
+            r = den - r;
+            r_sign = -r_sign;
+        }
+        let r = FBig::new(Repr::new(r_sign * r, r_exponent), context);
+        (num_sign * den_sign, q, r)
+    }
+}
+
 macro_rules! impl_add_sub_primitive_with_fbig {
     ($($t:ty)*) => {$(
         helper_macros::impl_commutative_binop_with_primitive!(impl Div<$t>, div);
         helper_macros::impl_binop_assign_with_primitive!(impl DivAssign<$t>, div_assign);
+        helper_macros::impl_commutative_binop_with_primitive!(impl Rem<$t>, rem);
+        helper_macros::impl_binop_assign_with_primitive!(impl RemAssign<$t>, rem_assign);
     )*};
 }
 impl_add_sub_primitive_with_fbig!(u8 u16 u32 u64 u128 usize UBig i8 i16 i32 i64 i128 isize IBig);
@@ -205,7 +379,7 @@ impl<R: Round> Context<R> {
         let (mut q, mut r) = lhs.significand.div_rem(&rhs.significand);
         let mut e = lhs.exponent - rhs.exponent;
         if r.is_zero() {
-            return Approximation::Exact(Repr::new(q, e));
+            return self.repr_fit_range(Approximation::Exact(Repr::new(q, e))).0;
         }
 
         let ddigits = digit_len::<B>(&rhs.significand);
@@ -233,12 +407,18 @@ impl<R: Round> Context<R> {
             }
         }
 
-        if r.is_zero() {
+        let rounded = if r.is_zero() {
             Approximation::Exact(Repr::new(q, e))
         } else {
-            let adjust = R::round_ratio(&q, r, &rhs.significand);
-            Approximation::Inexact(Repr::new(q + adjust, e), adjust)
-        }
+            // the quotient might have to be rounded to fewer digits in the subnormal range
+            let shift = self.round_shift(e, digit_len::<B>(&q));
+            let (hi, lo) = split_digits::<B>(q, shift);
+            let num = lo * &rhs.significand + r;
+            let den = shl_digits::<B>(&rhs.significand, shift);
+            let adjust = R::round_ratio(&hi, num, &den);
+            Approximation::Inexact(Repr::new(hi + adjust, e + shift as isize), adjust)
+        };
+        self.repr_fit_range(rounded).0
     }
 
     /// Divide two floating point numbers under this context.
@@ -265,15 +445,29 @@ impl<R: Round> Context<R> {
     ///
     pub fn div<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
         check_inf_operands(lhs, rhs);
+        check_precision_limited(self.precision);
 
-        let lhs_repr = if !lhs.is_zero() && lhs.digits_ub() > rhs.digits_lb() + self.precision {
-            // shrink lhs if it's larger than necessary
-            Self::new(rhs.digits() + self.precision)
-                .repr_round_ref(lhs)
-                .value()
-        } else {
-            lhs.clone()
-        };
-        self.repr_div(lhs_repr, rhs).map(|v| FBig::new(v, *self))
+        if !lhs.is_zero()
+            && lhs.digits_ub() > rhs.digits_lb() + self.precision
+            && lhs.digits() > rhs.digits() + self.precision
+        {
+            // if lhs is larger than necessary, the integer quotient already has enough digits,
+            // and it's rounded with the remainder directly to prevent the double rounding
+            let (q, r) = (&lhs.significand).div_rem(&rhs.significand);
+            let e = lhs.exponent - rhs.exponent;
+            let shift = self.round_shift(e, digit_len::<B>(&q));
+            let (hi, lo) = split_digits::<B>(q, shift);
+            let rounded = if lo.is_zero() && r.is_zero() {
+                Approximation::Exact(Repr::new(hi, e + shift as isize))
+            } else {
+                // the rounding fraction is (lo + r / rhs) / B^shift
+                let num = lo * &rhs.significand + r;
+                let den = shl_digits::<B>(&rhs.significand, shift);
+                let adjust = R::round_ratio(&hi, num, &den);
+                Approximation::Inexact(Repr::new(hi + adjust, e + shift as isize), adjust)
+            };
+            return self.repr_fit_range(rounded).0.map(|v| FBig::new(v, *self));
+        }
+        self.repr_div(lhs.clone(), rhs).map(|v| FBig::new(v, *self))
     }
 }
@@ -0,0 +1,594 @@
+//! Floating point numbers with the rounding mode and the base selected at runtime.
+//!
+//! The rounding mode and the base of [FBig] are fixed by its type parameters. When they are only
+//! known at runtime (e.g. read from a configuration or user input), the [DynFBig] type in this module
+//! can be used instead. It stores the rounding mode as a [RoundingMode] value and the base as a
+//! [Word], and each operation is dispatched to the same algorithms used by the corresponding [FBig] type.
+//!
+//! # Examples
+//!
+//! ```
+//! # use dashu_int::error::ParseError;
+//! use dashu_float::dynamic::{DynFBig, RoundingMode};
+//!
+//! let mode = RoundingMode::HalfEven; // e.g. from the configuration
+//! let a = DynFBig::parse("1.25", 10, mode)?;
+//! let b = DynFBig::parse("3.0", 10, mode)?;
+//! assert_eq!((a.clone() * b).to_string(), "3.75");
+//! assert_eq!(a.with_precision(2).value().to_string(), "1.2");
+//! # Ok::<(), ParseError>(())
+//! ```
+
+use crate::{
+    error::{panic_different_base, panic_different_rounding, panic_unsupported_base},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{mode, Round, Rounded},
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use dashu_base::Sign;
+use dashu_int::{
+    error::{OutOfBoundsError, ParseError},
+    IBig,
+};
+
+/// The rounding modes that can be selected at runtime.
+///
+/// Each variant corresponds to a type in the [mode] module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// See [mode::Zero]
+    Zero,
+    /// See [mode::Away]
+    Away,
+    /// See [mode::Up]
+    Up,
+    /// See [mode::Down]
+    Down,
+    /// See [mode::HalfEven]
+    HalfEven,
+    /// See [mode::HalfAway]
+    HalfAway,
+    /// See [mode::HalfDown]
+    HalfDown,
+    /// See [mode::HalfOdd]
+    HalfOdd,
+    /// See [mode::ToOdd]
+    ToOdd,
+    /// See [mode::Stochastic]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    Stochastic,
+}
+
+impl Display for RoundingMode {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// The bases supported by the operations of [DynFBig]
+pub const SUPPORTED_BASES: [Word; 4] = [2, 8, 10, 16];
+
+/// Evaluate the expression with the type `$R` set to the rounding mode type
+macro_rules! dispatch_mode {
+    ($mode:expr, $R:ident => $body:expr) => {
+        match $mode {
+            RoundingMode::Zero => {
+                type $R = mode::Zero;
+                $body
+            }
+            RoundingMode::Away => {
+                type $R = mode::Away;
+                $body
+            }
+            RoundingMode::Up => {
+                type $R = mode::Up;
+                $body
+            }
+            RoundingMode::Down => {
+                type $R = mode::Down;
+                $body
+            }
+            RoundingMode::HalfEven => {
+                type $R = mode::HalfEven;
+                $body
+            }
+            RoundingMode::HalfAway => {
+                type $R = mode::HalfAway;
+                $body
+            }
+            RoundingMode::HalfDown => {
+                type $R = mode::HalfDown;
+                $body
+            }
+            RoundingMode::HalfOdd => {
+                type $R = mode::HalfOdd;
+                $body
+            }
+            RoundingMode::ToOdd => {
+                type $R = mode::ToOdd;
+                $body
+            }
+            #[cfg(all(feature = "rand", feature = "std"))]
+            RoundingMode::Stochastic => {
+                type $R = mode::Stochastic;
+                $body
+            }
+        }
+    };
+}
+
+/// Evaluate the expression with the constant `$B` set to the base,
+/// `$fallback` is evaluated if the base is not supported
+macro_rules! dispatch_base {
+    ($base:expr, $B:ident => $body:expr, _ => $fallback:expr) => {
+        match $base {
+            2 => {
+                const $B: Word = 2;
+                $body
+            }
+            8 => {
+                const $B: Word = 8;
+                $body
+            }
+            10 => {
+                const $B: Word = 10;
+                $body
+            }
+            16 => {
+                const $B: Word = 16;
+                $body
+            }
+            _ => $fallback,
+        }
+    };
+    ($base:expr, $B:ident => $body:expr) => {
+        dispatch_base!($base, $B => $body, _ => panic_unsupported_base())
+    };
+}
+
+/// Evaluate the expression with the rounding mode type `$R` and the base `$B` of the number
+macro_rules! dispatch {
+    ($num:expr, $R:ident, $B:ident => $body:expr) => {
+        dispatch_mode!($num.rounding, $R => dispatch_base!($num.base, $B => $body))
+    };
+}
+
+/// An arbitrary precision floating point number with the rounding mode and base selected at runtime.
+///
+/// The number can be converted from any [FBig] with a built-in rounding mode (see [DynRound]) and a base
+/// in [SUPPORTED_BASES], and it can be converted back to [FBig] using [TryFrom] if both the base and the
+/// rounding mode match. The rounding
+/// mode can be changed explicitly with [with_rounding()][DynFBig::with_rounding], and the base can be
+/// changed with [with_base()][DynFBig::with_base].
+///
+/// The arithmetic operations follow the behavior of [FBig]: the result has the higher precision
+/// between the two operands. The two operands of a binary operation must have the same base and
+/// rounding mode.
+///
+/// # Supported bases
+///
+/// Only the bases in [SUPPORTED_BASES] (2, 8, 10 and 16) are supported, because each supported base
+/// requires a separate instantiation of the algorithms. Therefore [From] is only implemented for [FBig]
+/// with these bases, and a number in another base can't be converted:
+///
+/// ```compile_fail
+/// use dashu_float::{dynamic::DynFBig, round::mode::Zero, FBig};
+///
+/// let a = DynFBig::from(FBig::<Zero, 3>::ONE);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use core::convert::TryFrom;
+/// use dashu_float::dynamic::{DynFBig, RoundingMode};
+///
+/// let a = DynFBig::from(DBig::from_str_native("1.5")?);
+/// assert_eq!(a.base(), 10);
+/// assert_eq!(a.rounding(), RoundingMode::HalfAway);
+///
+/// let b = a.with_rounding(RoundingMode::HalfEven).with_precision(1).value();
+/// assert_eq!(b.to_string(), "2");
+/// let c = DBig::try_from(b.with_rounding(RoundingMode::HalfAway)).unwrap();
+/// assert_eq!(c, DBig::from_str_native("2")?);
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Clone)]
+pub struct DynFBig {
+    significand: IBig,
+    exponent: isize,
+    base: Word,
+    rounding: RoundingMode,
+    /// The precision and exponent range, the rounding mode in the type is not used
+    context: Context<mode::Zero>,
+}
+
+/// The rounding mode types that have a corresponding [RoundingMode] variant.
+///
+/// This trait is implemented for all the built-in rounding modes in the [mode] module,
+/// and it's required for converting between [FBig] and [DynFBig].
+pub trait DynRound: Round {
+    /// The rounding mode selected at runtime that is equivalent to this type
+    const MODE: RoundingMode;
+}
+
+macro_rules! impl_dyn_round {
+    ($($mode:ident)*) => {$(
+        impl DynRound for mode::$mode {
+            const MODE: RoundingMode = RoundingMode::$mode;
+        }
+    )*};
+}
+impl_dyn_round!(Zero Away Up Down HalfEven HalfAway HalfDown HalfOdd ToOdd);
+#[cfg(all(feature = "rand", feature = "std"))]
+impl_dyn_round!(Stochastic);
+
+macro_rules! impl_from_fbig {
+    ($($base:literal)*) => {$(
+        impl<R: DynRound> From<FBig<R, $base>> for DynFBig {
+            #[inline]
+            fn from(f: FBig<R, $base>) -> Self {
+                let (significand, exponent) = f.repr.into_parts();
+                DynFBig {
+                    significand,
+                    exponent,
+                    base: $base,
+                    rounding: R::MODE,
+                    context: f.context.with_rounding(),
+                }
+            }
+        }
+    )*};
+}
+impl_from_fbig!(2 8 10 16); // must be consistent with SUPPORTED_BASES
+
+impl<R: DynRound, const B: Word> TryFrom<DynFBig> for FBig<R, B> {
+    type Error = OutOfBoundsError;
+
+    /// Convert to [FBig] with the base `B` and the rounding mode `R`.
+    ///
+    /// Returns [Err] if the base of the number is not `B` or the rounding mode of the number is not `R`.
+    /// Use [DynFBig::with_base] and [DynFBig::with_rounding] to convert the base or change the rounding
+    /// mode explicitly before the conversion.
+    #[inline]
+    fn try_from(f: DynFBig) -> Result<Self, Self::Error> {
+        if f.base == B && f.rounding == R::MODE {
+            Ok(f.into_fbig())
+        } else {
+            Err(OutOfBoundsError)
+        }
+    }
+}
+
+impl DynFBig {
+    /// Convert to [FBig] with the given type parameters, assuming the base matches
+    #[inline]
+    fn into_fbig<R: Round, const B: Word>(self) -> FBig<R, B> {
+        debug_assert_eq!(self.base, B);
+        let repr = Repr {
+            significand: self.significand,
+            exponent: self.exponent,
+        };
+        FBig::new(repr, self.context.with_rounding())
+    }
+
+    #[inline]
+    fn to_fbig<R: Round, const B: Word>(&self) -> FBig<R, B> {
+        self.clone().into_fbig()
+    }
+
+    /// Parse a number in the given base from a string, with the same format as
+    /// [FBig::from_str_native].
+    ///
+    /// Returns [ParseError::UnsupportedRadix] if the base is not in [SUPPORTED_BASES].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::dynamic::{DynFBig, RoundingMode};
+    ///
+    /// let a = DynFBig::parse("-1.1", 2, RoundingMode::Zero)?;
+    /// assert_eq!(a.to_string(), "-1.1");
+    /// assert_eq!(a.precision(), 2);
+    /// assert_eq!(DynFBig::parse("1", 3, RoundingMode::Zero).unwrap_err(), ParseError::UnsupportedRadix);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn parse(src: &str, base: Word, rounding: RoundingMode) -> Result<Self, ParseError> {
+        dispatch_mode!(rounding, R => dispatch_base!(base,
+            B => FBig::<R, B>::from_str_native(src).map(Self::from),
+            _ => Err(ParseError::UnsupportedRadix)
+        ))
+    }
+
+    /// Create a number from the significand and exponent, the value is `significand * base^exponent`.
+    ///
+    /// The precision is set to the number of digits of the significand, like [FBig::from_parts].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not in [SUPPORTED_BASES].
+    pub fn from_parts(
+        significand: IBig,
+        exponent: isize,
+        base: Word,
+        rounding: RoundingMode,
+    ) -> Self {
+        dispatch_mode!(rounding, R => dispatch_base!(base,
+            B => Self::from(FBig::<R, B>::from_parts(significand, exponent))
+        ))
+    }
+
+    /// Get the base of the number
+    #[inline]
+    pub const fn base(&self) -> Word {
+        self.base
+    }
+
+    /// Get the rounding mode of the number
+    #[inline]
+    pub const fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// Get the maximum precision set for the number. Zero means unlimited precision.
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.context.precision
+    }
+
+    /// Get the significand of the number
+    #[inline]
+    pub const fn significand(&self) -> &IBig {
+        &self.significand
+    }
+
+    /// Get the exponent of the number
+    #[inline]
+    pub const fn exponent(&self) -> isize {
+        self.exponent
+    }
+
+    /// Determine if the number is zero
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.significand.is_zero() && self.exponent == 0
+    }
+
+    /// Determine if the number is infinite
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.significand.is_zero() && self.exponent != 0
+    }
+
+    /// Get the sign of the number. Zero value has a positive sign.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        if self.significand.is_zero() {
+            if self.exponent >= 0 {
+                Sign::Positive
+            } else {
+                Sign::Negative
+            }
+        } else {
+            self.significand.sign()
+        }
+    }
+
+    /// Change the rounding mode of the number. The value is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use core::convert::TryFrom;
+    /// use dashu_float::{dynamic::{DynFBig, DynRound, RoundingMode}, round::mode::Zero, FBig};
+    ///
+    /// let a = DynFBig::parse("1.5", 10, RoundingMode::HalfEven)?;
+    /// assert!(FBig::<Zero, 10>::try_from(a.clone()).is_err());
+    /// let b = FBig::<Zero, 10>::try_from(a.with_rounding(Zero::MODE)).unwrap();
+    /// assert_eq!(b.to_string(), "1.5");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn with_rounding(self, rounding: RoundingMode) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Change the precision of the number, see [FBig::with_precision].
+    #[inline]
+    pub fn with_precision(self, precision: usize) -> Rounded<Self> {
+        dispatch!(self, R, B => self.into_fbig::<R, B>().with_precision(precision).map(Self::from))
+    }
+
+    /// Convert the number to another base, see [FBig::with_base].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::dynamic::{DynFBig, RoundingMode};
+    ///
+    /// let a = DynFBig::parse("0.1", 10, RoundingMode::HalfEven)?;
+    /// let b = a.with_base(2).value();
+    /// assert_eq!(b.base(), 2);
+    /// assert_eq!(b.with_base(10).value().to_string(), "0.1");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the target base is not in [SUPPORTED_BASES].
+    pub fn with_base(self, base: Word) -> Rounded<Self> {
+        if base == self.base {
+            return Rounded::Exact(self);
+        }
+        dispatch!(self, R, B => {
+            let f = self.into_fbig::<R, B>();
+            dispatch_base!(base, NB => f.with_base::<NB>().map(Self::from))
+        })
+    }
+
+    /// Calculate the square root of the number, see [FBig::sqrt].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative or the precision is unlimited.
+    pub fn sqrt(&self) -> Self {
+        dispatch!(self, R, B => Self::from(self.to_fbig::<R, B>().sqrt()))
+    }
+
+    /// Calculate the exponential function `eˣ` of the number, see [FBig::exp].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn exp(&self) -> Self {
+        dispatch!(self, R, B => Self::from(self.to_fbig::<R, B>().exp()))
+    }
+
+    /// Calculate the natural logarithm of the number, see [FBig::ln].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn ln(&self) -> Self {
+        dispatch!(self, R, B => Self::from(self.to_fbig::<R, B>().ln()))
+    }
+
+    /// Raise the number to an integer power, see [FBig::powi].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited and the exponent is negative.
+    pub fn powi(&self, exp: IBig) -> Self {
+        dispatch!(self, R, B => Self::from(self.to_fbig::<R, B>().powi(exp)))
+    }
+}
+
+impl PartialEq for DynFBig {
+    /// Two numbers are equal if they have the same base and value, the precision and
+    /// the rounding mode are not compared.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+            && self.significand == other.significand
+            && self.exponent == other.exponent
+    }
+}
+impl Eq for DynFBig {}
+
+impl Display for DynFBig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        dispatch!(self, R, B => Display::fmt(&self.to_fbig::<R, B>(), f))
+    }
+}
+
+impl Debug for DynFBig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        dispatch!(self, R, B => Debug::fmt(&self.to_fbig::<R, B>(), f))
+    }
+}
+
+impl Neg for DynFBig {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if self.significand.is_zero() {
+            self.exponent = -self.exponent; // negate the infinity
+        } else {
+            self.significand = -self.significand;
+        }
+        self
+    }
+}
+
+impl Neg for &DynFBig {
+    type Output = DynFBig;
+
+    #[inline]
+    fn neg(self) -> DynFBig {
+        self.clone().neg()
+    }
+}
+
+/// Check that the operands have the same base and rounding mode
+#[inline]
+fn check_same_kind(lhs: &DynFBig, rhs: &DynFBig) {
+    if lhs.base != rhs.base {
+        panic_different_base()
+    }
+    if lhs.rounding != rhs.rounding {
+        panic_different_rounding()
+    }
+}
+
+macro_rules! impl_dyn_binop {
+    ($trait:ident, $method:ident) => {
+        impl $trait for DynFBig {
+            type Output = DynFBig;
+
+            #[inline]
+            fn $method(self, rhs: DynFBig) -> DynFBig {
+                check_same_kind(&self, &rhs);
+                dispatch!(self, R, B => {
+                    let (lhs, rhs) = (self.into_fbig::<R, B>(), rhs.into_fbig::<R, B>());
+                    DynFBig::from(lhs.$method(rhs))
+                })
+            }
+        }
+
+        impl<'r> $trait<&'r DynFBig> for DynFBig {
+            type Output = DynFBig;
+
+            #[inline]
+            fn $method(self, rhs: &DynFBig) -> DynFBig {
+                check_same_kind(&self, rhs);
+                dispatch!(self, R, B => {
+                    let (lhs, rhs) = (self.into_fbig::<R, B>(), rhs.to_fbig::<R, B>());
+                    DynFBig::from(lhs.$method(rhs))
+                })
+            }
+        }
+
+        impl<'l> $trait<DynFBig> for &'l DynFBig {
+            type Output = DynFBig;
+
+            #[inline]
+            fn $method(self, rhs: DynFBig) -> DynFBig {
+                check_same_kind(self, &rhs);
+                dispatch!(self, R, B => {
+                    let (lhs, rhs) = (self.to_fbig::<R, B>(), rhs.into_fbig::<R, B>());
+                    DynFBig::from(lhs.$method(rhs))
+                })
+            }
+        }
+
+        impl<'l, 'r> $trait<&'r DynFBig> for &'l DynFBig {
+            type Output = DynFBig;
+
+            #[inline]
+            fn $method(self, rhs: &DynFBig) -> DynFBig {
+                check_same_kind(self, rhs);
+                dispatch!(self, R, B => {
+                    let (lhs, rhs) = (self.to_fbig::<R, B>(), rhs.to_fbig::<R, B>());
+                    DynFBig::from(lhs.$method(rhs))
+                })
+            }
+        }
+    };
+}
+
+impl_dyn_binop!(Add, add);
+impl_dyn_binop!(Sub, sub);
+impl_dyn_binop!(Mul, mul);
+impl_dyn_binop!(Div, div);
@@ -29,3 +29,68 @@ pub(crate) fn check_precision_limited(precision: usize) {
 pub(crate) const fn panic_unlimited_precision() -> ! {
     panic!("precision cannot be 0 (unlimited) for this operation!")
 }
+
+/// Panics when taking the square root of a negative number
+pub(crate) const fn panic_root_negative() -> ! {
+    panic!("square root of negative numbers are not allowed!")
+}
+
+/// Panics when the two sequences of a dot product have different lengths
+pub(crate) const fn panic_length_mismatch() -> ! {
+    panic!("the two sequences of the dot product must have the same length!")
+}
+
+/// Panics when the base is not supported by the dynamic float type
+pub(crate) const fn panic_unsupported_base() -> ! {
+    panic!("the base is not supported by DynFBig!")
+}
+
+/// Panics when the operands of a dynamic float operation have different bases
+pub(crate) const fn panic_different_base() -> ! {
+    panic!("the operands must have the same base!")
+}
+
+/// Panics when the operands of a dynamic float operation have different rounding modes
+pub(crate) const fn panic_different_rounding() -> ! {
+    panic!("the operands must have the same rounding mode!")
+}
+
+/// Panics when dividing by zero
+pub(crate) const fn panic_divide_by_0() -> ! {
+    panic!("divisor must not be 0")
+}
+
+/// Panics when taking the logarithm of a non-positive number
+pub(crate) const fn panic_log_non_positive() -> ! {
+    panic!("logarithm of non-positive numbers are not allowed!")
+}
+
+/// Panics when taking the logarithm of zero
+pub(crate) const fn panic_log_zero() -> ! {
+    panic!("logarithm of zero is not allowed!")
+}
+
+/// Panics when the endpoints of an interval are invalid
+pub(crate) const fn panic_invalid_interval() -> ! {
+    panic!("the lower endpoint of an interval must not be greater than the upper endpoint, and an interval must not be infinite at a single point!")
+}
+
+/// Panics when the midpoint or the radius of a ball is invalid
+pub(crate) const fn panic_invalid_ball() -> ! {
+    panic!("the midpoint and the radius of a ball must be finite, and the radius must not be negative!")
+}
+
+/// Panics when allocating an amount with ratios that sum to zero
+pub(crate) const fn panic_zero_ratios() -> ! {
+    panic!("the sum of the allocation ratios must not be zero!")
+}
+
+/// Panics when rounding a number to zero significant digits
+pub(crate) const fn panic_zero_digits() -> ! {
+    panic!("the number of significant digits must be positive!")
+}
+
+/// Panics when finding the adjacent number of zero without a bounded exponent range
+pub(crate) const fn panic_next_of_zero() -> ! {
+    panic!("the adjacent numbers of zero don't exist when the exponent range is unbounded!")
+}
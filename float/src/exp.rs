@@ -96,6 +96,10 @@ impl<R: Round> Context<R> {
     /// result is likely to have infinite digits.
     pub fn powi<const B: Word>(&self, base: &Repr<B>, exp: IBig) -> Rounded<FBig<R, B>> {
         check_inf(base);
+        if self.exponent_range().is_some() {
+            // evaluate with unbounded exponent, then fit the result into the range
+            return self.eval_unbounded(|ctx| ctx.powi(base, exp));
+        }
 
         let (exp_sign, exp) = exp.into_parts();
         if exp_sign == Sign::Negative {
@@ -191,6 +195,10 @@ impl<R: Round> Context<R> {
     fn exp_internal<const B: Word>(&self, x: &Repr<B>, minus_one: bool) -> Rounded<FBig<R, B>> {
         check_inf(x);
         check_precision_limited(self.precision);
+        if self.exponent_range().is_some() {
+            // evaluate with unbounded exponent, then fit the result into the range
+            return self.eval_unbounded(|ctx| ctx.exp_internal(x, minus_one));
+        }
 
         if x.is_zero() {
             return match minus_one {
@@ -251,10 +259,11 @@ impl<R: Round> Context<R> {
         loop {
             factorial *= k;
             pow *= &r;
-            // TODO: use &pow / &factorial < ulp as stop criteria?
 
-            let next = &sum + &pow / &factorial;
-            if next == sum {
+            let term = &pow / &factorial;
+            let next = &sum + &term;
+            if next == sum || term.is_below_ulp_of(&next) {
+                sum = next;
                 break;
             }
             sum = next;
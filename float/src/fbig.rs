@@ -1,9 +1,15 @@
 use crate::{
+    error::{check_precision_limited, panic_next_of_zero},
     repr::{Context, Repr, Word},
     round::{mode, Round},
+    utils::{shl_digits, shl_digits_in_place},
 };
-use dashu_base::Sign;
-use dashu_int::{DoubleWord, IBig};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use dashu_base::{Approximation::Exact, Sign, UnsignedAbs};
+use dashu_int::{DoubleWord, IBig, UBig};
 
 /// An arbitrary precision floating point number with arbitrary base and rounding mode.
 ///
@@ -77,6 +83,25 @@ use dashu_int::{DoubleWord, IBig};
 /// test and comparsion, two [FBig] instances can have different rounding modes (but not different bases),
 /// because rounding will never happends during comparison.
 ///
+/// # Equality and hashing
+///
+/// The equality test only compares the values of the numbers, and the precisions are not considered. The
+/// [Hash] implementation is consistent with the equality, so the numbers with the same value but different
+/// precisions (e.g. `1.0`, `1.00` and `10e-1`) have the same hash, and [FBig] can be used as keys of a hash map.
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::DBig;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(DBig::from_str_native("1.0")?);
+/// set.insert(DBig::from_str_native("1.00")?);
+/// set.insert(DBig::from_str_native("10e-1")?);
+/// assert_eq!(set.len(), 1);
+/// # Ok::<(), ParseError>(())
+/// ```
+///
 /// # Convert from/to `f32`/`f64`
 ///
 /// The conversion between [FBig] and [f32]/[f64] is only defined for base 2 [FBig]. To convert
@@ -361,6 +386,158 @@ impl<R: Round, const B: Word> FBig<R, B> {
         };
         Self::new(repr, self.context)
     }
+
+    /// Check whether the magnitude of this number is less than the ulp of `other`.
+    /// It's used as the stop criterion of series evaluation, because under directed
+    /// rounding modes adding a tiny term can still change the sum.
+    #[inline]
+    pub(crate) fn is_below_ulp_of(&self, other: &Self) -> bool {
+        if self.repr.is_zero() {
+            return true;
+        }
+        let top = self.repr.exponent + self.repr.digits() as isize;
+        let ulp_exp =
+            other.repr.exponent + other.repr.digits() as isize - other.context.precision as isize;
+        top <= ulp_exp
+    }
+
+    /// Get the smallest number at the current precision that is greater than this number.
+    ///
+    /// If the exponent range of the context is limited, the result respects the subnormal numbers,
+    /// and the largest finite number goes up to the infinity. The infinities are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{Context, DBig};
+    /// let a = DBig::from_str_native("1.23")?;
+    /// assert_eq!(a.next_up(), DBig::from_str_native("1.24")?);
+    /// assert_eq!(a.next_down(), DBig::from_str_native("1.22")?);
+    ///
+    /// let b = DBig::from_str_native("-1.00")?;
+    /// assert_eq!(b.next_up(), DBig::from_str_native("-0.999")?);
+    /// assert_eq!(b.next_down(), DBig::from_str_native("-1.01")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, or if the number is zero and the exponent
+    /// range is not limited.
+    #[inline]
+    pub fn next_up(&self) -> Self {
+        self.next_adjacent(Sign::Positive)
+    }
+
+    /// Get the largest number at the current precision that is less than this number.
+    ///
+    /// See [next_up][Self::next_up] for details.
+    #[inline]
+    pub fn next_down(&self) -> Self {
+        self.next_adjacent(Sign::Negative)
+    }
+
+    /// Get the next number at the current precision from this number toward the target.
+    ///
+    /// If the number is equal to the target, then the number itself is returned.
+    /// See [next_up][Self::next_up] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.23")?;
+    /// assert_eq!(a.next_toward(&DBig::INFINITY), DBig::from_str_native("1.24")?);
+    /// assert_eq!(a.next_toward(&DBig::ZERO), DBig::from_str_native("1.22")?);
+    /// assert_eq!(a.next_toward(&a), a);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn next_toward<R2: Round>(&self, target: &FBig<R2, B>) -> Self {
+        match self.partial_cmp(target) {
+            Some(Ordering::Less) => self.next_up(),
+            Some(Ordering::Greater) => self.next_down(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Get the adjacent number in the direction of the sign
+    fn next_adjacent(&self, direction: Sign) -> Self {
+        if self.repr.is_infinite() {
+            return self.clone();
+        }
+        check_precision_limited(self.context.precision);
+        let precision = self.context.precision;
+        let qmin = self.context.min_quantum();
+
+        if self.repr.is_zero() {
+            let exponent = match qmin {
+                Some(q) => q,
+                None => panic_next_of_zero(),
+            };
+            let repr = Repr::new(direction * IBig::ONE, exponent);
+            return Self::new(repr, self.context);
+        }
+
+        // scale the significand to the full precision, unless the number is subnormal
+        let digits = self.repr.digits();
+        let mut exponent = self.repr.exponent + digits as isize - precision as isize;
+        if let Some(q) = qmin {
+            exponent = exponent.max(q);
+        }
+        exponent = exponent.min(self.repr.exponent);
+        let mut signif =
+            shl_digits::<B>(&self.repr.significand, (self.repr.exponent - exponent) as usize);
+
+        let toward_zero = signif.sign() != direction;
+        let lower_bound = UBig::from_word(B).pow(precision - 1);
+        let at_lower_bound = signif.clone().unsigned_abs() == lower_bound;
+        if toward_zero && at_lower_bound && qmin.map_or(true, |q| exponent > q) {
+            // the ulp is smaller in the lower binade
+            shl_digits_in_place::<B>(&mut signif, 1);
+            exponent -= 1;
+        }
+        signif += direction * IBig::ONE;
+
+        let repr = self
+            .context
+            .repr_fit_range(Exact(Repr::new(signif, exponent)))
+            .0
+            .value();
+        Self::new(repr, self.context)
+    }
+
+    /// Decompose the number into a significand in range `[1/B, 1)` (in magnitude) and an exponent,
+    /// such that `self = significand * B ^ exponent`.
+    ///
+    /// The zero is decomposed into `(0, 0)`, and the infinities are decomposed into themselves
+    /// and a zero exponent. The precision of the significand is the same as this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-12.34")?;
+    /// let (signif, exp) = a.frexp();
+    /// assert_eq!(signif, DBig::from_str_native("-0.1234")?);
+    /// assert_eq!(exp, 2);
+    /// assert_eq!(signif.ldexp(exp), a);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn frexp(&self) -> (Self, isize) {
+        if self.repr.is_infinite() || self.repr.is_zero() {
+            return (self.clone(), 0);
+        }
+
+        let digits = self.repr.digits() as isize;
+        let repr = Repr {
+            significand: self.repr.significand.clone(),
+            exponent: -digits,
+        };
+        (Self::new(repr, self.context), self.repr.exponent + digits)
+    }
 }
 
 // This custom implementation is necessary due to https://github.com/rust-lang/rust/issues/98374
@@ -387,3 +564,11 @@ impl<R: Round, const B: Word> Default for FBig<R, B> {
         Self::ZERO
     }
 }
+
+impl<R: Round, const B: Word> Hash for FBig<R, B> {
+    /// The hash only depends on the value (which is normalized), the context is not hashed.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state)
+    }
+}
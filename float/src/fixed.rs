@@ -0,0 +1,350 @@
+use crate::{
+    error::{check_inf, panic_divide_by_0},
+    fbig::FBig,
+    fmt::{fmt_fixed_point, rounding_name},
+    helper_macros,
+    repr::Word,
+    round::{mode, Round, Rounded},
+    utils::{shl_digits, split_digits, split_digits_ref},
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Shl, Shr, Sub},
+    str::FromStr,
+};
+use dashu_base::{Abs, Approximation::*, DivRem, Sign};
+use dashu_int::{
+    error::{OutOfBoundsError, ParseError},
+    IBig,
+};
+
+/// A binary fixed-point number with `FRAC` fractional bits.
+///
+/// The number is stored as an integer count of the units `2^-FRAC`, so that the arithmetic is
+/// deterministic and independent of the platform. Addition, subtraction, negation and left
+/// shifts are always exact, while the results of multiplication, division and right shifts
+/// are rounded to `FRAC` fractional bits with the rounding mode `RoundingMode`.
+///
+/// Since every binary fraction has a finite decimal expansion, the number is always printed
+/// exactly in decimal (unless a precision is set in the formatter).
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// use dashu_float::FixedI;
+///
+/// type Q16 = FixedI<16>;
+///
+/// let a: Q16 = "1.5".parse()?;
+/// let b: Q16 = "-0.25".parse()?;
+/// assert_eq!((&a * &b).to_string(), "-0.375");
+/// assert_eq!((b.clone() / 3).to_string(), "-0.0833282470703125"); // rounded toward zero
+/// assert_eq!((b >> 16).to_string(), "0"); // -2^-18 is rounded toward zero
+/// # Ok::<(), ParseError>(())
+/// ```
+pub struct FixedI<const FRAC: usize, RoundingMode: Round = mode::Zero> {
+    pub(crate) raw: IBig,
+    _marker: PhantomData<RoundingMode>,
+}
+
+helper_macros::impl_fixed_point_common!(FixedI, 2, "frac_bits");
+
+impl<const F: usize, R: Round> FixedI<F, R> {
+    /// Create a fixed-point number from the number of units `2^-FRAC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::FixedI;
+    ///
+    /// let a = FixedI::<4>::from_raw((-20).into());
+    /// assert_eq!(a.to_string(), "-1.25");
+    /// ```
+    #[inline]
+    pub const fn from_raw(raw: IBig) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the number of units `2^-FRAC` in the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::{error::ParseError, IBig};
+    /// use dashu_float::FixedI;
+    ///
+    /// let a: FixedI<8> = "-1.5".parse()?;
+    /// assert_eq!(a.raw(), &IBig::from(-384));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub const fn raw(&self) -> &IBig {
+        &self.raw
+    }
+
+    /// Convert the number into the number of units `2^-FRAC`.
+    #[inline]
+    pub fn into_raw(self) -> IBig {
+        self.raw
+    }
+
+    /// The number of fractional bits of this type
+    #[inline]
+    pub const fn frac_bits(&self) -> usize {
+        F
+    }
+
+    /// Change the number of fractional bits, with rounding if the new number is smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{FixedI, round::{mode::HalfEven, Rounding::*}};
+    ///
+    /// let a: FixedI<4, HalfEven> = "2.6875".parse()?; // 2 + 11/16
+    /// assert_eq!(a.rescale::<2>(), Inexact("2.75".parse()?, AddOne));
+    /// assert_eq!(a.rescale::<8>(), Exact("2.6875".parse()?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn rescale<const NEW_FRAC: usize>(&self) -> Rounded<FixedI<NEW_FRAC, R>> {
+        if NEW_FRAC >= F {
+            Exact(FixedI::from_raw(&self.raw << (NEW_FRAC - F)))
+        } else {
+            let shift = F - NEW_FRAC;
+            let (q, r) = split_digits_ref::<2>(&self.raw, shift);
+            FixedI::round_fract(q, r, shift)
+        }
+    }
+
+    /// Multiply two numbers, and round the product to `FRAC` fractional bits.
+    ///
+    /// This is the same as the `*` operator, but the rounding adjustment is also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{FixedI, round::{mode::Up, Rounding::*}};
+    ///
+    /// let a: FixedI<2, Up> = "1.25".parse()?;
+    /// assert_eq!(a.mul_rounded(&a), Inexact("1.75".parse()?, AddOne)); // 1.5625
+    /// assert_eq!(a.mul_rounded(&(-a.clone())), Inexact("-1.5".parse()?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn mul_rounded(&self, rhs: &Self) -> Rounded<Self> {
+        let (q, r) = split_digits::<2>(&self.raw * &rhs.raw, F);
+        Self::round_fract(q, r, F)
+    }
+
+    /// Divide two numbers, and round the quotient to `FRAC` fractional bits.
+    ///
+    /// This is the same as the `/` operator, but the rounding adjustment is also returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero. See [checked_div][FixedI::checked_div] for a non-panicking version.
+    #[inline]
+    pub fn div_rounded(&self, rhs: &Self) -> Rounded<Self> {
+        match self.checked_div(rhs) {
+            Some(q) => q,
+            None => panic_divide_by_0(),
+        }
+    }
+
+    /// Divide two numbers, and round the quotient to `FRAC` fractional bits.
+    /// Return [None] if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{FixedI, round::Rounding::*};
+    ///
+    /// let a: FixedI<4> = "1".parse()?;
+    /// let b: FixedI<4> = "3".parse()?;
+    /// assert_eq!(a.checked_div(&b), Some(Inexact("0.3125".parse()?, NoOp)));
+    /// assert_eq!(a.checked_div(&"0.25".parse()?), Some(Exact("4".parse()?)));
+    /// assert_eq!(a.checked_div(&FixedI::ZERO), None);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn checked_div(&self, rhs: &Self) -> Option<Rounded<Self>> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        let (q, r) = (&self.raw << F).div_rem(&rhs.raw);
+        Some(Self::round_ratio(q, r, &rhs.raw))
+    }
+
+    /// Convert a float number (in any base) to the fixed-point number, with rounding if the
+    /// number cannot be represented with `FRAC` fractional bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{DBig, FBig, FixedI, round::{mode::Down, Rounding::*}};
+    ///
+    /// let a = FBig::<Down>::from_parts((-13).into(), -3); // -1.625
+    /// assert_eq!(FixedI::<2>::from_fbig(&a), Inexact("-1.5".parse()?, NoOp));
+    /// assert_eq!(FixedI::<2, Down>::from_fbig(&a), Inexact("-1.75".parse()?, SubOne));
+    ///
+    /// let b = DBig::from_str_native("0.1")?;
+    /// assert_eq!(FixedI::<8>::from_fbig(&b), Inexact(FixedI::from_raw(25.into()), NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    pub fn from_fbig<R2: Round, const B: Word>(x: &FBig<R2, B>) -> Rounded<Self> {
+        check_inf(&x.repr);
+
+        let signif = &x.repr.significand;
+        let exp = x.repr.exponent;
+        if exp >= 0 {
+            let int = signif * IBig::from(B).pow(exp as usize);
+            Exact(Self::from_raw(int << F))
+        } else {
+            let den = IBig::from(B).pow(-exp as usize);
+            let (q, r) = (signif << F).div_rem(&den);
+            Self::round_ratio(q, r, &den)
+        }
+    }
+
+    /// Convert the fixed-point number to a binary float number.
+    ///
+    /// The conversion is exact, and the precision of the result is the number of bits in
+    /// the (normalized) significand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::{FBig, FixedI};
+    ///
+    /// let a: FixedI<8> = "-2.5".parse()?;
+    /// let b: FBig = FBig::from_parts((-5).into(), -1);
+    /// assert_eq!(a.to_fbig(), b);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn to_fbig(&self) -> FBig<R, 2> {
+        FBig::from_parts(self.raw.clone(), -(F as isize))
+    }
+
+    /// Convert the fixed-point number to [f64], with rounding to nearest (ties to even).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::FixedI;
+    ///
+    /// let a: FixedI<8> = "-2.5".parse()?;
+    /// assert_eq!(a.to_f64().value(), -2.5);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn to_f64(&self) -> Rounded<f64> {
+        self.to_fbig().to_f64()
+    }
+}
+
+impl<const F: usize, R: Round> Display for FixedI<F, R> {
+    /// Print the exact decimal expansion of the number. If the precision option of the
+    /// formatter is set, the number will be printed with the given number of fractional
+    /// (decimal) digits, with rounding if necessary.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // the exact expansion has `F` decimal digits, without the trailing zeros it has
+        // `F - k` digits where `2^k` is the largest power of two dividing the raw number
+        let units = &self.raw * IBig::from(5u8).pow(F);
+        let digits = F.saturating_sub(self.raw.trailing_zeros().unwrap_or(F));
+        fmt_fixed_point::<R>(f, &units, F, digits)
+    }
+}
+
+impl<const F: usize, R: Round> TryFrom<f64> for FixedI<F, R> {
+    type Error = OutOfBoundsError;
+
+    /// Convert a [f64] to the fixed-point number, with rounding if it cannot be represented
+    /// with `FRAC` fractional bits. Use [FixedI::from_fbig] to get the rounding adjustment.
+    ///
+    /// Returns an error if the float is infinite or NaN.
+    fn try_from(f: f64) -> Result<Self, Self::Error> {
+        let x = FBig::<R, 2>::try_from(f)?;
+        if x.repr.is_infinite() {
+            return Err(OutOfBoundsError);
+        }
+        Ok(Self::from_fbig(&x).value())
+    }
+}
+
+impl<const F: usize, R: Round> Shl<usize> for FixedI<F, R> {
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: usize) -> Self {
+        Self::from_raw(self.raw << rhs)
+    }
+}
+
+impl<const F: usize, R: Round> Shl<usize> for &FixedI<F, R> {
+    type Output = FixedI<F, R>;
+    #[inline]
+    fn shl(self, rhs: usize) -> FixedI<F, R> {
+        FixedI::from_raw(&self.raw << rhs)
+    }
+}
+
+impl<const F: usize, R: Round> Shr<usize> for FixedI<F, R> {
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: usize) -> Self {
+        let (q, r) = split_digits::<2>(self.raw, rhs);
+        FixedI::round_fract(q, r, rhs).value()
+    }
+}
+
+impl<const F: usize, R: Round> Shr<usize> for &FixedI<F, R> {
+    type Output = FixedI<F, R>;
+    #[inline]
+    fn shr(self, rhs: usize) -> FixedI<F, R> {
+        let (q, r) = split_digits_ref::<2>(&self.raw, rhs);
+        FixedI::round_fract(q, r, rhs).value()
+    }
+}
+
+// multiplication and division by integers are common in DSP code (e.g. averaging)
+impl<const F: usize, R: Round> Mul<i32> for FixedI<F, R> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        Self::from_raw(self.raw * IBig::from(rhs))
+    }
+}
+
+impl<const F: usize, R: Round> Div<i32> for FixedI<F, R> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: i32) -> Self {
+        if rhs == 0 {
+            panic_divide_by_0()
+        }
+        let rhs = IBig::from(rhs);
+        let (q, r) = self.raw.div_rem(&rhs);
+        Self::round_ratio(q, r, &rhs).value()
+    }
+}
@@ -4,11 +4,18 @@ use crate::{
     fbig::FBig,
     repr::{Context, Repr},
     round::{mode::Zero, Round},
-    utils::{digit_len, split_digits_ref},
+    utils::{digit_len, shl_digits, split_digits, split_digits_ref},
+};
+use alloc::{format, string::String};
+use core::{
+    fmt::{
+        self, Alignment, Binary, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+        Write,
+    },
+    iter,
 };
-use core::fmt::{self, Alignment, Display, Formatter, Write};
 use dashu_base::{Sign, UnsignedAbs};
-use dashu_int::{IBig, Word};
+use dashu_int::{IBig, UBig, Word};
 
 trait DebugStructHelper {
     /// Print the full debug info for the significand
@@ -52,13 +59,49 @@ impl<const B: Word> fmt::Debug for Repr<B> {
     }
 }
 
+/// Get the name of the rounding mode type without the module path
+pub(crate) fn rounding_name<R: Round>() -> &'static str {
+    let name = core::any::type_name::<R>();
+    name.rfind("::").map(|pos| &name[pos + 2..]).unwrap_or(name)
+}
+
+/// Print a fixed-point number given as the number of units `10^-scale`, with `digits` fractional
+/// digits, or with the number of fractional digits set by the precision option of the formatter.
+/// The number is rounded if fewer digits than `scale` are printed, and the sign is taken from the
+/// rounded number so that no negative zero is printed.
+pub(crate) fn fmt_fixed_point<R: Round>(
+    f: &mut Formatter<'_>,
+    units: &IBig,
+    scale: usize,
+    digits: usize,
+) -> fmt::Result {
+    let digits = f.precision().unwrap_or(digits);
+    let units = if digits >= scale {
+        shl_digits::<10>(units, digits - scale)
+    } else {
+        let shift = scale - digits;
+        let (q, r) = split_digits_ref::<10>(units, shift);
+        if r.is_zero() {
+            q
+        } else {
+            let adjust = R::round_fract::<10>(&q, r, shift);
+            q + adjust
+        }
+    };
+
+    let positive = units.sign() == Sign::Positive;
+    let (int, fract) = split_digits::<10>(units.unsigned_abs().into(), digits);
+    let body = if digits == 0 {
+        format!("{}", int)
+    } else {
+        format!("{}.{:0>width$}", int, fract, width = digits)
+    };
+    f.pad_integral(positive, "", &body)
+}
+
 impl<R: Round> fmt::Debug for Context<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let rnd_name = core::any::type_name::<R>();
-        let rnd_name = rnd_name
-            .rfind("::")
-            .map(|pos| &rnd_name[pos + 2..])
-            .unwrap_or(rnd_name);
+        let rnd_name = rounding_name::<R>();
         f.debug_struct("Context")
             .field("precision", &self.precision)
             .field("rounding", &format_args!("{}", rnd_name))
@@ -70,16 +113,12 @@ impl<const B: Word> Repr<B> {
     /// Print the float number with given rounding mode. The rounding may happen if the precision option
     /// of the formatter is set.
     fn fmt_round<R: Round>(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        // shortcut for infinities
+        let negative = self.sign() == Sign::Negative;
         if self.is_infinite() {
-            return match self.sign() {
-                Sign::Positive => f.write_str("inf"),
-                Sign::Negative => f.write_str("-inf"),
-            };
+            return write_padded(f, negative, "", "inf");
         }
 
         // first perform rounding before actual printing if necessary
-        let negative = self.significand.sign() == Sign::Negative;
         let rounded_signif;
         let (signif, exp) = if let Some(prec) = f.precision() {
             let diff = prec as isize + self.exponent;
@@ -96,168 +135,49 @@ impl<const B: Word> Repr<B> {
             (&self.significand, self.exponent)
         };
 
-        // calculate padding if necessary
-        let (left_pad, right_pad) = if let Some(min_width) = f.width() {
-            // first calculate the with of the formatted digits without padding
-
-            let mut signif_digits = digit_len::<B>(signif);
-            // the leading zeros needs to be printed (when the exponent of the number is very small).
-            let leading_zeros = -(exp + signif_digits as isize - 1).min(0) as usize;
-            // the trailing zeros needs to be printed (when the exponent of the number is very large)
-            let mut trailing_zeros = exp.max(0) as usize;
-
-            // if the precision option is set, there might be extra trailing zeros
-            if let Some(prec) = f.precision() {
-                let diff = prec as isize + exp.min(0);
-                if diff > 0 {
-                    trailing_zeros += diff as usize;
-                }
-            }
-            if leading_zeros == 0 {
-                // there is at least one digit to print (0)
-                signif_digits = signif_digits.max(1);
-            }
-
-            let has_sign = (negative || f.sign_plus()) as usize;
-            let has_float_point = if exp > 0 {
-                // if there's no fractional part, the result has the floating point
-                // only if the precision is set to be non-zero
-                f.precision().unwrap_or(0) > 0
-            } else {
-                // if there is fractional part, the result has the floating point
-                // if the precision is not set, or set to be non-zero
-                f.precision() != Some(0) // non-zero or none
-            } as usize;
-
-            let width = signif_digits + has_sign + has_float_point + leading_zeros + trailing_zeros;
-
-            // check alignment and calculate padding
-            if width >= min_width {
-                (0, 0)
-            } else if f.sign_aware_zero_pad() {
-                (min_width - width, 0)
-            } else {
-                match f.align() {
-                    Some(Alignment::Left) => (0, min_width - width),
-                    Some(Alignment::Right) | None => (min_width - width, 0),
-                    Some(Alignment::Center) => {
-                        let diff = min_width - width;
-                        (diff / 2, diff - diff / 2)
-                    }
-                }
-            }
-        } else {
-            (0, 0)
-        };
-
-        // print left padding
-        let fill = if f.sign_aware_zero_pad() {
-            '0'
-        } else {
-            f.fill()
-        };
-        for _ in 0..left_pad {
-            f.write_char(fill)?;
-        }
-
-        // print the actual digits
+        let mut body = String::new();
         if exp < 0 {
             // If the exponent is negative, then the float number has fractional part
             let exp = -exp as usize;
             let (int, fract) = split_digits_ref::<B>(signif, exp);
-
-            let frac_digits = digit_len::<B>(&fract);
+            let frac_digits = if fract.is_zero() {
+                0
+            } else {
+                digit_len::<B>(&fract)
+            };
             debug_assert!(frac_digits <= exp);
 
             // print the integral part.
-            if !negative && f.sign_plus() {
-                f.write_char('+')?;
-            }
-            if int.is_zero() {
-                if negative {
-                    f.write_char('-')?;
-                }
-                f.write_char('0')?;
-            } else {
-                f.write_fmt(format_args!("{}", int.in_radix(B as u32)))?;
-            }
+            write!(body, "{}", int.unsigned_abs().in_radix(B as u32))?;
 
-            // print the fractional part, it has exactly `exp` digits (with left zero padding)
-            let fract = fract.unsigned_abs(); // don't print sign for fractional part
-            if let Some(prec) = f.precision() {
-                // don't print any fractional part if precision is zero
-                if prec != 0 {
-                    f.write_char('.')?;
-                    if exp >= prec {
-                        // the fractional part should be already rounded at the beginning
-                        debug_assert!(exp == prec);
-
-                        // print padding zeros
-                        if prec > frac_digits {
-                            for _ in 0..prec - frac_digits {
-                                f.write_char('0')?;
-                            }
-                        }
-                        if frac_digits > 0 {
-                            f.write_fmt(format_args!("{}", fract.in_radix(B as u32)))?;
-                        }
-                    } else {
-                        // append zeros if the required precision is larger
-                        for _ in 0..exp - frac_digits {
-                            f.write_char('0')?;
-                        }
-                        f.write_fmt(format_args!("{}", fract.in_radix(B as u32)))?;
-                        for _ in 0..prec - exp {
-                            f.write_char('0')?;
-                        }
-                    }
-                }
-            } else if frac_digits > 0 {
-                f.write_char('.')?;
-                for _ in 0..(exp - frac_digits) {
-                    f.write_char('0')?;
-                }
-                f.write_fmt(format_args!("{}", fract.in_radix(B as u32)))?;
+            // print the fractional part, it has exactly `exp` digits (with left zero padding).
+            // If the precision is set, the fractional part is already rounded at the beginning.
+            if frac_digits > 0 {
+                body.push('.');
+                body.extend(iter::repeat('0').take(exp - frac_digits));
+                let fract = fract.unsigned_abs(); // don't print sign for fractional part
+                write!(body, "{}", fract.in_radix(B as u32))?;
             }
         } else {
             // In this case, the number is actually an integer and it can be trivially formatted.
-            // However, when the precision option is set, we need to append zeros.
-
-            // print the significand
-            if !negative && f.sign_plus() {
-                f.write_char('+')?;
-            }
-            if signif.is_zero() {
-                if negative {
-                    f.write_char('-')?;
-                }
-                f.write_char('0')?;
-            } else {
-                f.write_fmt(format_args!("{}", signif.in_radix(B as u32)))?;
-            }
+            write!(body, "{}", signif.unsigned_abs().in_radix(B as u32))?;
 
             // append zeros if needed
-            for _ in 0..exp {
-                f.write_char('0')?;
-            }
+            body.extend(iter::repeat('0').take(exp as usize));
+        };
 
-            // print trailing zeros after the float point if the precision is set to be nonzero
-            if let Some(prec) = f.precision() {
-                if prec > 0 {
-                    f.write_char('.')?;
-                    for _ in 0..prec {
-                        f.write_char('0')?;
-                    }
+        // append trailing zeros if the precision is larger than the number of fractional digits
+        if let Some(prec) = f.precision() {
+            let frac_len = body.find('.').map_or(0, |pos| body.len() - pos - 1);
+            if prec > frac_len {
+                if frac_len == 0 {
+                    body.push('.');
                 }
+                body.extend(iter::repeat('0').take(prec - frac_len));
             }
-        };
-
-        // print right padding
-        for _ in 0..right_pad {
-            f.write_char(f.fill())?;
         }
 
-        Ok(())
+        write_padded(f, negative, "", &body)
     }
 }
 
@@ -278,11 +198,7 @@ impl<R: Round, const B: Word> fmt::Debug for FBig<R, B> {
             };
         }
 
-        let rnd_name = core::any::type_name::<R>();
-        let rnd_name = rnd_name
-            .rfind("::")
-            .map(|pos| &rnd_name[pos + 2..])
-            .unwrap_or(rnd_name);
+        let rnd_name = rounding_name::<R>();
 
         if f.alternate() {
             f.debug_struct("FBig")
@@ -307,4 +223,367 @@ impl<R: Round, const B: Word> Display for FBig<R, B> {
     }
 }
 
-// TODO: impl LowerHex and UpperHex for FBig with base 2, printing the "0xabcp-n" format.
+/// Write the formatted number with the sign and padding according to the formatter options.
+///
+/// The `prefix` and `body` must contain only ASCII characters. If the zero padding is enabled,
+/// the zeros are inserted between the prefix and the body.
+fn write_padded(f: &mut Formatter<'_>, negative: bool, prefix: &str, body: &str) -> fmt::Result {
+    let sign = if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let width = sign.len() + prefix.len() + body.len();
+    let pad = f.width().map_or(0, |w| w.saturating_sub(width));
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        for _ in 0..pad {
+            f.write_char('0')?;
+        }
+        return f.write_str(body);
+    }
+
+    let (left_pad, right_pad) = match f.align() {
+        Some(Alignment::Left) => (0, pad),
+        Some(Alignment::Right) | None => (pad, 0),
+        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..left_pad {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    f.write_str(body)?;
+    for _ in 0..right_pad {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl<const B: Word> Repr<B> {
+    /// Round the number so that there are at most `frac_digits` digits after the leading digit
+    /// (in the native base).
+    fn round_frac_digits<R: Round>(&self, frac_digits: usize) -> Self {
+        let digits = digit_len::<B>(&self.significand);
+        if digits <= frac_digits + 1 {
+            return self.clone();
+        }
+
+        let shift = digits - frac_digits - 1;
+        let (signif, rem) = split_digits_ref::<B>(&self.significand, shift);
+        let adjust = R::round_fract::<B>(&signif, rem, shift);
+        Repr::new(signif + adjust, self.exponent + shift as isize)
+    }
+
+    /// Print the number in the scientific notation `d.ddd{marker}{exp}` in the native base,
+    /// where the exponent is printed in decimal and it's always a multiple of `step`. (`step` is
+    /// 1 for the scientific notation and 3 for the engineering notation.) The number is rounded
+    /// if the precision option of the formatter is set (as the number of digits after the point).
+    fn fmt_native_sci<R: Round>(
+        &self,
+        f: &mut Formatter<'_>,
+        marker: char,
+        step: usize,
+        upper: bool,
+    ) -> fmt::Result {
+        let negative = self.sign() == Sign::Negative;
+        if self.is_infinite() {
+            return write_padded(f, negative, "", "inf");
+        }
+
+        // the number of digits before the point
+        let int_digits = |repr: &Self| {
+            if repr.significand.is_zero() {
+                1
+            } else {
+                let lead = repr.exponent + digit_len::<B>(&repr.significand) as isize - 1;
+                lead.rem_euclid(step as isize) as usize + 1
+            }
+        };
+
+        let mut int_len = int_digits(self);
+        let repr = match f.precision() {
+            Some(prec) => {
+                let rounded = self.round_frac_digits::<R>(int_len - 1 + prec);
+                int_len = int_digits(&rounded);
+                rounded
+            }
+            None => self.clone(),
+        };
+
+        let (mut body, exp) = if repr.significand.is_zero() {
+            (String::from("0"), 0)
+        } else {
+            let digits = format!("{}", repr.significand.unsigned_abs().in_radix(B as u32));
+            let exp = repr.exponent + digits.len() as isize - int_len as isize;
+            let mut body = String::from(&digits[..int_len.min(digits.len())]);
+            if digits.len() > int_len {
+                body.push('.');
+                body.push_str(&digits[int_len..]);
+            } else {
+                body.extend(iter::repeat('0').take(int_len - digits.len()));
+            }
+            (body, exp)
+        };
+
+        // append zeros if the precision is larger than the number of digits
+        if let Some(prec) = f.precision() {
+            let frac_len = body.len().saturating_sub(int_len + 1);
+            if prec > frac_len {
+                if frac_len == 0 {
+                    body.push('.');
+                }
+                body.extend(iter::repeat('0').take(prec - frac_len));
+            }
+        }
+
+        if upper {
+            body.make_ascii_uppercase();
+        }
+        body.push(marker);
+        body.push_str(&format!("{}", exp));
+        write_padded(f, negative, "", &body)
+    }
+}
+
+impl Repr<2> {
+    /// Print the number in the hexadecimal float format `0x1.hhhp{exp}` (the same as `%a` in C99).
+    /// The number is rounded if the precision option of the formatter is set (as the number of
+    /// hexadecimal digits after the point).
+    fn fmt_hex<R: Round>(&self, f: &mut Formatter<'_>, upper: bool) -> fmt::Result {
+        let negative = self.sign() == Sign::Negative;
+        if self.is_infinite() {
+            return write_padded(f, negative, "", if upper { "INF" } else { "inf" });
+        }
+
+        let repr = match f.precision() {
+            Some(prec) => self.round_frac_digits::<R>(4 * prec),
+            None => self.clone(),
+        };
+
+        let (mut body, frac_len, exp) = if repr.significand.is_zero() {
+            (String::from("0"), 0, 0)
+        } else {
+            // the leading digit is always 1, and the fractional bits are padded to whole hex digits
+            let signif = repr.significand.unsigned_abs();
+            let frac_bits = signif.bit_len() - 1;
+            let frac_len = (frac_bits + 3) / 4;
+            let exp = repr.exponent + frac_bits as isize;
+            let mut body = String::from("1");
+            if frac_len > 0 {
+                let fract = (signif - (UBig::ONE << frac_bits)) << (4 * frac_len - frac_bits);
+                body.push_str(&format!(".{:0width$x}", fract, width = frac_len));
+            }
+            (body, frac_len, exp)
+        };
+
+        // append zeros if the precision is larger than the number of digits
+        if let Some(prec) = f.precision() {
+            if prec > frac_len {
+                if frac_len == 0 {
+                    body.push('.');
+                }
+                body.extend(core::iter::repeat('0').take(prec - frac_len));
+            }
+        }
+
+        body.push_str(&format!("p{:+}", exp));
+        if upper {
+            body.make_ascii_uppercase();
+            write_padded(f, negative, "0X", &body)
+        } else {
+            write_padded(f, negative, "0x", &body)
+        }
+    }
+}
+
+impl<R: Round> LowerHex for FBig<R, 2> {
+    /// Print the number in the hexadecimal float format (`0x1.8p+3`), which is the same as
+    /// the `%a` specifier in C99. The output can be parsed back by [FBig::from_str_native] losslessly.
+    ///
+    /// If the precision option is set, the number is rounded to the given number of hexadecimal
+    /// digits after the point, using the rounding mode of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, round::mode::HalfEven};
+    /// let a = FBig::<HalfEven>::try_from(-12.375f64).unwrap();
+    /// assert_eq!(format!("{:x}", a), "-0x1.8cp+3");
+    /// assert_eq!(format!("{:.1x}", a), "-0x1.9p+3");
+    /// assert_eq!(format!("{:X}", a), "-0X1.8CP+3");
+    /// assert_eq!(FBig::<HalfEven>::from_str_native("-0x1.8cp+3")?, a);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr.fmt_hex::<R>(f, false)
+    }
+}
+
+impl<R: Round> UpperHex for FBig<R, 2> {
+    /// Same as [LowerHex], but the letters are printed in upper case.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr.fmt_hex::<R>(f, true)
+    }
+}
+
+impl<R: Round> Binary for FBig<R, 2> {
+    /// Print the number in the binary scientific notation (`1.1001b3`), where the exponent
+    /// (base 2) is printed in decimal. The output can be parsed back by [FBig::from_str_native]
+    /// losslessly.
+    ///
+    /// If the precision option is set, the number is rounded to the given number of binary
+    /// digits after the point, using the rounding mode of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, round::mode::HalfEven};
+    /// let a = FBig::<HalfEven>::try_from(-12.5f64).unwrap();
+    /// assert_eq!(format!("{:b}", a), "-1.1001b3");
+    /// assert_eq!(format!("{:.2b}", a), "-1.10b3");
+    /// assert_eq!(FBig::<HalfEven>::from_str_native("-1.1001b3")?, a);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr.fmt_native_sci::<R>(f, 'b', 1, false)
+    }
+}
+
+impl<R: Round> Octal for FBig<R, 8> {
+    /// Print the number in the octal scientific notation (`1.44o1`), where the exponent
+    /// (base 8) is printed in decimal. The output can be parsed back by [FBig::from_str_native]
+    /// losslessly.
+    ///
+    /// If the precision option is set, the number is rounded to the given number of octal
+    /// digits after the point, using the rounding mode of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, round::mode::HalfEven};
+    /// let a = FBig::<HalfEven, 8>::from_str_native("-14.4")?;
+    /// assert_eq!(format!("{:o}", a), "-1.44o1");
+    /// assert_eq!(format!("{:.1o}", a), "-1.4o1");
+    /// assert_eq!(FBig::<HalfEven, 8>::from_str_native("-1.44o1")?, a);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr.fmt_native_sci::<R>(f, 'o', 1, false)
+    }
+}
+
+/// The marker of the exponent in the scientific notation
+const fn exp_marker<const B: Word>(upper: bool) -> char {
+    match (B, upper) {
+        (10, false) => 'e',
+        (10, true) => 'E',
+        _ => '@',
+    }
+}
+
+impl<R: Round, const B: Word> LowerExp for FBig<R, B> {
+    /// Print the number in the scientific notation (`1.2345e3`) in the native base. The exponent
+    /// is printed in decimal, and the marker is `e` for base 10 and `@` for other bases.
+    ///
+    /// If the precision option is set, the number is rounded to the given number of digits
+    /// after the point, using the rounding mode of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{DBig, FBig};
+    /// let a = DBig::from_str_native("-1234.5")?;
+    /// assert_eq!(format!("{:e}", a), "-1.2345e3");
+    /// assert_eq!(format!("{:.2e}", a), "-1.23e3");
+    /// assert_eq!(format!("{:E}", a), "-1.2345E3");
+    ///
+    /// let b = FBig::<dashu_float::round::mode::Zero, 16>::from_str_native("ff.8")?;
+    /// assert_eq!(format!("{:e}", b), "f.f8@1");
+    /// assert_eq!(format!("{:E}", b), "F.F8@1");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr
+            .fmt_native_sci::<R>(f, exp_marker::<B>(false), 1, false)
+    }
+}
+
+impl<R: Round, const B: Word> UpperExp for FBig<R, B> {
+    /// Print the number in the scientific notation (`1.2345E3`) in the native base, with
+    /// the digits and the exponent marker in upper case. See [LowerExp] for details.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.repr
+            .fmt_native_sci::<R>(f, exp_marker::<B>(true), 1, true)
+    }
+}
+
+/// A wrapper of [FBig] for printing the number in the engineering notation,
+/// created by [FBig::engineering].
+///
+/// In the engineering notation, the exponent is always a multiple of 3, so there
+/// are one to three digits before the point. The formatting options are the same
+/// as [LowerExp] for [FBig] (the `#` flag is ignored), and the [UpperExp] trait
+/// prints the digits and the exponent marker in upper case.
+pub struct EngineeringNotation<'a, R: Round, const B: Word>(&'a FBig<R, B>);
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Get an object that prints the number in the engineering notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("-12345.6")?;
+    /// assert_eq!(a.engineering().to_string(), "-12.3456e3");
+    /// assert_eq!(format!("{:.1}", a.engineering()), "-12.3e3");
+    /// assert_eq!(format!("{:E}", a.engineering()), "-12.3456E3");
+    /// assert_eq!(DBig::from_str_native("0.00042")?.engineering().to_string(), "420e-6");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn engineering(&self) -> EngineeringNotation<'_, R, B> {
+        EngineeringNotation(self)
+    }
+}
+
+impl<R: Round, const B: Word> Display for EngineeringNotation<'_, R, B> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0
+            .repr
+            .fmt_native_sci::<R>(f, exp_marker::<B>(false), 3, false)
+    }
+}
+
+impl<R: Round, const B: Word> LowerExp for EngineeringNotation<'_, R, B> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<R: Round, const B: Word> UpperExp for EngineeringNotation<'_, R, B> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0
+            .repr
+            .fmt_native_sci::<R>(f, exp_marker::<B>(true), 3, true)
+    }
+}
@@ -0,0 +1,1729 @@
+//! Decimal arithmetic conforming to the [General Decimal Arithmetic](https://speleotrove.com/decimal/decarith.html)
+//! specification.
+//!
+//! The representation of [FBig] is always normalized, so [DBig][crate::DBig] can't distinguish
+//! `1.2` from `1.20`, and it has no negative zero or NaN. The [DecNumber] type in this module
+//! follows the data model of the specification instead: a finite number consists of a sign, an
+//! integral coefficient and an exponent (so the trailing zeros are preserved), and the special
+//! values are the infinities and the quiet and signaling NaNs with diagnostic payloads. Every
+//! value carries a sign, including the zeros and the NaNs.
+//!
+//! The arithmetic operations are provided by [DecContext], which holds the precision, the
+//! rounding mode ([DecRounding]), the exponent range and the clamping option, and records the
+//! conditions raised by the operations in sticky [Flags]. The conditions *conversion syntax*,
+//! *division impossible*, *division undefined* and *invalid context* of the specification are
+//! all reported as [Exception::InvalidOperation][crate::status::Exception::InvalidOperation].
+//! Traps are not supported, the operations always return the default results of the specification.
+//!
+//! The following operations of the specification are supported: abs, add, compare,
+//! compare-total, compare-total-magnitude, copy, copy-abs, copy-negate, copy-sign, divide,
+//! divide-integer, max, max-magnitude, min, min-magnitude, minus, multiply, plus, quantize,
+//! reduce, remainder, same-quantum, subtract, to-integral-exact, to-integral-value, to-number,
+//! to-scientific-string and to-engineering-string.
+//!
+//! # Examples
+//!
+//! ```
+//! # use dashu_int::error::ParseError;
+//! use dashu_float::gda::{DecContext, DecNumber};
+//! use dashu_float::status::Flags;
+//!
+//! let mut ctxt = DecContext::new(9);
+//! let a: DecNumber = "1.20".parse()?;
+//! let b: DecNumber = "3.80".parse()?;
+//! let c = ctxt.add(&a, &b);
+//! assert_eq!(c.to_string(), "5.00"); // the exponent is preserved
+//! assert_eq!(ctxt.reduce(&c).to_string(), "5");
+//!
+//! let third = ctxt.divide(&"1".parse()?, &"3".parse()?);
+//! assert_eq!(third.to_string(), "0.333333333");
+//! assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+//!
+//! // signed zeros and NaNs
+//! let zero: DecNumber = "0".parse()?;
+//! assert_eq!(ctxt.multiply(&"-1".parse()?, &zero).to_string(), "-0");
+//! assert_eq!(ctxt.divide(&zero, &zero).to_string(), "NaN");
+//! assert!(ctxt.flags().contains(Flags::INVALID_OPERATION));
+//! # Ok::<(), ParseError>(())
+//! ```
+
+use crate::{
+    dynamic::RoundingMode,
+    fbig::FBig,
+    interchange::DecimalFormat,
+    repr::{Context, Repr},
+    round::Round,
+    status::Flags,
+};
+use alloc::string::{String, ToString};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+use dashu_base::{DivRem, Sign};
+use dashu_int::{
+    error::{OutOfBoundsError, ParseError},
+    IBig, UBig,
+};
+
+/// The exponents parsed from strings are saturated at this magnitude, which is far beyond the
+/// exponent range of any practical context but leaves room for the exponent arithmetic.
+const EXPONENT_LIMIT: isize = isize::MAX / 4;
+
+/// The rounding modes defined by the General Decimal Arithmetic specification.
+///
+/// The variants are named after the rounding modes in [mode][crate::round::mode] (and
+/// [RoundingMode]), so that [Up][DecRounding::Up] and [Down][DecRounding::Down] always round
+/// towards the infinities. The names in the specification are listed in the documentation of
+/// each variant, and they are used by the [Display] implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecRounding {
+    /// Round towards zero (`round-down` in the specification)
+    Zero,
+    /// Round away from zero (`round-up` in the specification)
+    Away,
+    /// Round towards positive infinity (`round-ceiling` in the specification)
+    Up,
+    /// Round towards negative infinity (`round-floor` in the specification)
+    Down,
+    /// Round to nearest, ties to even (`round-half-even` in the specification)
+    HalfEven,
+    /// Round to nearest, ties away from zero (`round-half-up` in the specification)
+    HalfAway,
+    /// Round to nearest, ties towards zero (`round-half-down` in the specification)
+    HalfDown,
+    /// Round towards zero, unless the last digit would be 0 or 5, in which case away from zero
+    /// (`round-05up` in the specification). It has no counterpart in [RoundingMode].
+    ZeroFiveUp,
+}
+
+impl TryFrom<RoundingMode> for DecRounding {
+    type Error = RoundingMode;
+
+    /// Convert a rounding mode of [DynFBig][crate::dynamic::DynFBig] to the rounding mode of the
+    /// specification, the modes not defined by the specification are returned as the error.
+    fn try_from(mode: RoundingMode) -> Result<Self, Self::Error> {
+        match mode {
+            RoundingMode::Zero => Ok(DecRounding::Zero),
+            RoundingMode::Away => Ok(DecRounding::Away),
+            RoundingMode::Up => Ok(DecRounding::Up),
+            RoundingMode::Down => Ok(DecRounding::Down),
+            RoundingMode::HalfEven => Ok(DecRounding::HalfEven),
+            RoundingMode::HalfAway => Ok(DecRounding::HalfAway),
+            RoundingMode::HalfDown => Ok(DecRounding::HalfDown),
+            other => Err(other),
+        }
+    }
+}
+
+impl DecRounding {
+    /// Decide whether the truncated coefficient should be incremented, given its last digit,
+    /// the comparison of the discarded part with a half unit and whether the discarded part is nonzero.
+    fn should_increment(self, sign: Sign, last_digit: u8, half: Ordering, inexact: bool) -> bool {
+        match self {
+            DecRounding::Zero => false,
+            DecRounding::Away => inexact,
+            DecRounding::Up => inexact && sign == Sign::Positive,
+            DecRounding::Down => inexact && sign == Sign::Negative,
+            DecRounding::HalfAway => half != Ordering::Less,
+            DecRounding::HalfDown => half == Ordering::Greater,
+            DecRounding::HalfEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && last_digit % 2 == 1)
+            }
+            DecRounding::ZeroFiveUp => inexact && (last_digit == 0 || last_digit == 5),
+        }
+    }
+}
+
+impl Display for DecRounding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecRounding::Zero => "down",
+            DecRounding::HalfAway => "half_up",
+            DecRounding::HalfEven => "half_even",
+            DecRounding::Up => "ceiling",
+            DecRounding::Down => "floor",
+            DecRounding::HalfDown => "half_down",
+            DecRounding::Away => "up",
+            DecRounding::ZeroFiveUp => "05up",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Value {
+    Finite { coefficient: UBig, exponent: isize },
+    Infinity,
+    NaN { payload: UBig, signaling: bool },
+}
+
+/// A decimal number in the data model of the General Decimal Arithmetic specification.
+///
+/// The number is either a finite number `(-1)^sign × coefficient × 10^exponent`, an infinity or
+/// a (quiet or signaling) NaN, and it always has a sign. Unlike [DBig][crate::DBig], the
+/// representation is not normalized: `1.20` and `1.2` are different representations of the same value.
+///
+/// The [PartialEq], [Eq] and [Hash] implementations compare the representations, so `1.20 != 1.2`
+/// and `NaN == NaN`. Use [DecContext::compare] for the numerical comparison, and
+/// [compare_total()][DecNumber::compare_total] for the total ordering of the specification.
+///
+/// The string conversions follow the specification: [Display] produces the scientific string,
+/// [to_eng_string()][DecNumber::to_eng_string] produces the engineering string, and [FromStr]
+/// converts a numeric string exactly (use [DecContext::parse] to round it to a context).
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// use dashu_base::Sign;
+/// use dashu_float::gda::DecNumber;
+///
+/// let a: DecNumber = "1.20".parse()?;
+/// assert_eq!(a.coefficient().unwrap(), &120u8);
+/// assert_eq!(a.exponent(), Some(-2));
+/// assert_eq!(a.to_string(), "1.20");
+///
+/// let b: DecNumber = "-1.2E+4".parse()?;
+/// assert_eq!(b.to_string(), "-1.2E+4");
+/// assert_eq!(b.to_eng_string(), "-12E+3");
+///
+/// let nan: DecNumber = "-sNaN12".parse()?;
+/// assert!(nan.is_snan() && nan.sign() == Sign::Negative);
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecNumber {
+    sign: Sign,
+    value: Value,
+}
+
+/// Calculate `10^n`
+#[inline]
+fn pow10(n: usize) -> UBig {
+    UBig::from(10u8).pow(n)
+}
+
+/// The number of decimal digits in the coefficient (zero has one digit)
+#[inline]
+fn num_digits(n: &UBig) -> usize {
+    if n.is_zero() {
+        1
+    } else {
+        n.ilog(&UBig::from(10u8)) + 1
+    }
+}
+
+/// The number of digits in the payload of a NaN (an empty payload has no digits)
+#[inline]
+fn payload_len(payload: &UBig) -> usize {
+    if payload.is_zero() {
+        0
+    } else {
+        num_digits(payload)
+    }
+}
+
+/// Remove the lowest `shift` digits of the coefficient and round the result with the given
+/// rounding mode. Returns the rounded coefficient and whether any nonzero digit is discarded.
+fn round_digits(coeff: &UBig, shift: usize, sign: Sign, rounding: DecRounding) -> (UBig, bool) {
+    if shift == 0 {
+        return (coeff.clone(), false);
+    }
+    let (hi, half, inexact) = if shift > num_digits(coeff) {
+        // the discarded part is less than a tenth of the unit
+        (UBig::ZERO, Ordering::Less, !coeff.is_zero())
+    } else {
+        let (hi, lo) = coeff.div_rem(pow10(shift));
+        let half = pow10(shift - 1) * 5u8;
+        let half = lo.cmp(&half);
+        (hi, half, !lo.is_zero())
+    };
+    let last_digit = &hi % 10u8;
+    if rounding.should_increment(sign, last_digit, half, inexact) {
+        (hi + UBig::ONE, inexact)
+    } else {
+        (hi, inexact)
+    }
+}
+
+/// Remove at most `max` trailing zeros of the coefficient, returns the count of removed zeros
+fn strip_trailing_zeros(coeff: &mut UBig, max: usize) -> usize {
+    let mut count = 0;
+    while count < max && !coeff.is_zero() {
+        let (q, r) = (&*coeff).div_rem(10u8);
+        if r != 0 {
+            break;
+        }
+        *coeff = q;
+        count += 1;
+    }
+    count
+}
+
+impl DecNumber {
+    /// Positive zero with exponent 0
+    pub const ZERO: Self = Self::finite(Sign::Positive, UBig::ZERO, 0);
+    /// Negative zero with exponent 0
+    pub const NEG_ZERO: Self = Self::finite(Sign::Negative, UBig::ZERO, 0);
+    /// Positive infinity
+    pub const INFINITY: Self = Self::special(Sign::Positive, Value::Infinity);
+    /// Negative infinity
+    pub const NEG_INFINITY: Self = Self::special(Sign::Negative, Value::Infinity);
+    /// Quiet NaN without payload
+    pub const NAN: Self = Self::special(
+        Sign::Positive,
+        Value::NaN {
+            payload: UBig::ZERO,
+            signaling: false,
+        },
+    );
+    /// Signaling NaN without payload
+    pub const SNAN: Self = Self::special(
+        Sign::Positive,
+        Value::NaN {
+            payload: UBig::ZERO,
+            signaling: true,
+        },
+    );
+
+    #[inline]
+    const fn finite(sign: Sign, coefficient: UBig, exponent: isize) -> Self {
+        Self {
+            sign,
+            value: Value::Finite {
+                coefficient,
+                exponent,
+            },
+        }
+    }
+
+    #[inline]
+    const fn special(sign: Sign, value: Value) -> Self {
+        Self { sign, value }
+    }
+
+    #[inline]
+    const fn infinity(sign: Sign) -> Self {
+        Self::special(sign, Value::Infinity)
+    }
+
+    /// Create a finite number `(-1)^sign × coefficient × 10^exponent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_base::Sign;
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// let a = DecNumber::from_parts(Sign::Negative, 1200u16.into(), -3);
+    /// assert_eq!(a.to_string(), "-1.200");
+    /// let z = DecNumber::from_parts(Sign::Negative, 0u8.into(), 2);
+    /// assert_eq!(z.to_string(), "-0E+2");
+    /// ```
+    #[inline]
+    pub const fn from_parts(sign: Sign, coefficient: UBig, exponent: isize) -> Self {
+        Self::finite(sign, coefficient, exponent)
+    }
+
+    /// Create a NaN with the diagnostic payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// assert_eq!(DecNumber::nan_with_payload(12u8.into(), false).to_string(), "NaN12");
+    /// assert_eq!(DecNumber::nan_with_payload(0u8.into(), true).to_string(), "sNaN");
+    /// ```
+    #[inline]
+    pub const fn nan_with_payload(payload: UBig, signaling: bool) -> Self {
+        Self::special(Sign::Positive, Value::NaN { payload, signaling })
+    }
+
+    /// Get the sign of the number. The zeros, infinities and NaNs are also signed.
+    #[inline]
+    pub const fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// Get the coefficient of a finite number, or [None] for the special values.
+    #[inline]
+    pub const fn coefficient(&self) -> Option<&UBig> {
+        match &self.value {
+            Value::Finite { coefficient, .. } => Some(coefficient),
+            _ => None,
+        }
+    }
+
+    /// Get the exponent of a finite number, or [None] for the special values.
+    #[inline]
+    pub const fn exponent(&self) -> Option<isize> {
+        match &self.value {
+            Value::Finite { exponent, .. } => Some(*exponent),
+            _ => None,
+        }
+    }
+
+    /// Get the diagnostic payload of a NaN, or [None] for the other values.
+    #[inline]
+    pub const fn payload(&self) -> Option<&UBig> {
+        match &self.value {
+            Value::NaN { payload, .. } => Some(payload),
+            _ => None,
+        }
+    }
+
+    /// Get the adjusted exponent (the exponent of the most significant digit) of a finite number,
+    /// or [None] for the special values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// assert_eq!("123.45".parse::<DecNumber>()?.adjusted(), Some(2));
+    /// assert_eq!("0.00E-3".parse::<DecNumber>()?.adjusted(), Some(-5));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn adjusted(&self) -> Option<isize> {
+        match &self.value {
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => Some(exponent + num_digits(coefficient) as isize - 1),
+            _ => None,
+        }
+    }
+
+    /// Check whether the number is finite
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        matches!(self.value, Value::Finite { .. })
+    }
+
+    /// Check whether the number is an infinity
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        matches!(self.value, Value::Infinity)
+    }
+
+    /// Check whether the number is a NaN (quiet or signaling)
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        matches!(self.value, Value::NaN { .. })
+    }
+
+    /// Check whether the number is a quiet NaN
+    #[inline]
+    pub const fn is_qnan(&self) -> bool {
+        matches!(
+            self.value,
+            Value::NaN {
+                signaling: false,
+                ..
+            }
+        )
+    }
+
+    /// Check whether the number is a signaling NaN
+    #[inline]
+    pub const fn is_snan(&self) -> bool {
+        matches!(
+            self.value,
+            Value::NaN {
+                signaling: true,
+                ..
+            }
+        )
+    }
+
+    /// Check whether the number is a zero (with any sign and exponent)
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        match &self.value {
+            Value::Finite { coefficient, .. } => coefficient.is_zero(),
+            _ => false,
+        }
+    }
+
+    /// Check whether the number is neither finite nor a NaN
+    #[inline]
+    const fn is_special(&self) -> bool {
+        !self.is_finite()
+    }
+
+    /// Get a copy of the number with the sign set to positive (copy-abs).
+    /// No condition is raised, even for the signaling NaNs.
+    #[inline]
+    pub fn copy_abs(&self) -> Self {
+        Self::special(Sign::Positive, self.value.clone())
+    }
+
+    /// Get a copy of the number with the sign inverted (copy-negate).
+    /// No condition is raised, even for the signaling NaNs.
+    #[inline]
+    pub fn copy_negate(&self) -> Self {
+        Self::special(-self.sign, self.value.clone())
+    }
+
+    /// Get a copy of the number with the sign of `other` (copy-sign).
+    /// No condition is raised, even for the signaling NaNs.
+    #[inline]
+    pub fn copy_sign(&self, other: &Self) -> Self {
+        Self::special(other.sign, self.value.clone())
+    }
+
+    /// Compare the magnitudes of two numbers that are not NaNs
+    fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (&self.value, &other.value) {
+            (Value::Infinity, Value::Infinity) => Ordering::Equal,
+            (Value::Infinity, _) => Ordering::Greater,
+            (_, Value::Infinity) => Ordering::Less,
+            (
+                Value::Finite {
+                    coefficient: c1,
+                    exponent: e1,
+                },
+                Value::Finite {
+                    coefficient: c2,
+                    exponent: e2,
+                },
+            ) => match (c1.is_zero(), c2.is_zero()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    let (a1, a2) = (self.adjusted().unwrap(), other.adjusted().unwrap());
+                    if a1 != a2 {
+                        return a1.cmp(&a2);
+                    }
+                    // the exponent difference is bounded by the coefficient lengths here
+                    match e1.cmp(e2) {
+                        Ordering::Greater => (c1 * pow10((e1 - e2) as usize)).cmp(c2),
+                        Ordering::Less => c1.cmp(&(c2 * pow10((e2 - e1) as usize))),
+                        Ordering::Equal => c1.cmp(c2),
+                    }
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Numerically compare two numbers that are not NaNs
+    fn cmp_value(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => {
+                return match other.sign {
+                    Sign::Positive => Ordering::Less,
+                    Sign::Negative => Ordering::Greater,
+                }
+            }
+            (false, true) => {
+                return match self.sign {
+                    Sign::Positive => Ordering::Greater,
+                    Sign::Negative => Ordering::Less,
+                }
+            }
+            (false, false) => {}
+        }
+        match (self.sign, other.sign) {
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Positive) => self.cmp_abs(other),
+            (Sign::Negative, Sign::Negative) => other.cmp_abs(self),
+        }
+    }
+
+    /// The total ordering of the magnitudes, ignoring the signs.
+    fn total_cmp_abs(&self, other: &Self) -> Ordering {
+        // finite numbers and infinities < signaling NaNs < quiet NaNs
+        let rank = |x: &Self| match x.value {
+            Value::NaN {
+                signaling: false, ..
+            } => 2,
+            Value::NaN {
+                signaling: true, ..
+            } => 1,
+            _ => 0,
+        };
+        match rank(self).cmp(&rank(other)) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+        match (&self.value, &other.value) {
+            (Value::NaN { payload: p1, .. }, Value::NaN { payload: p2, .. }) => p1.cmp(p2),
+            (Value::Finite { exponent: e1, .. }, Value::Finite { exponent: e2, .. }) => {
+                self.cmp_abs(other).then(e1.cmp(e2))
+            }
+            _ => self.cmp_abs(other),
+        }
+    }
+
+    /// Compare two numbers using the total ordering of the specification (compare-total).
+    ///
+    /// The order is: -NaN < -sNaN < -Infinity < negative finite numbers < -0 < +0
+    /// < positive finite numbers < +Infinity < +sNaN < +NaN. The numerically equal finite
+    /// numbers are ordered by their exponents (e.g. `1.20 < 1.2` and `-1.2 < -1.20`), and the
+    /// NaNs are ordered by their payloads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use core::cmp::Ordering;
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// let a: DecNumber = "12.30".parse()?;
+    /// let b: DecNumber = "12.3".parse()?;
+    /// assert_eq!(a.compare_total(&b), Ordering::Less);
+    /// assert_eq!(DecNumber::NEG_ZERO.compare_total(&DecNumber::ZERO), Ordering::Less);
+    /// assert_eq!(DecNumber::INFINITY.compare_total(&DecNumber::NAN), Ordering::Less);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn compare_total(&self, other: &Self) -> Ordering {
+        match (self.sign, other.sign) {
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Positive) => self.total_cmp_abs(other),
+            (Sign::Negative, Sign::Negative) => other.total_cmp_abs(self),
+        }
+    }
+
+    /// Compare two numbers using the total ordering of their absolute values (compare-total-magnitude).
+    /// See [compare_total()][Self::compare_total] for the ordering.
+    #[inline]
+    pub fn compare_total_mag(&self, other: &Self) -> Ordering {
+        self.total_cmp_abs(other)
+    }
+
+    /// Check whether two numbers have the same exponent (same-quantum). The infinities have the
+    /// same quantum, and the NaNs have the same quantum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// let a: DecNumber = "2.17".parse()?;
+    /// assert!(a.same_quantum(&"0.01".parse()?));
+    /// assert!(!a.same_quantum(&"0.1".parse()?));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn same_quantum(&self, other: &Self) -> bool {
+        match (&self.value, &other.value) {
+            (Value::Finite { exponent: e1, .. }, Value::Finite { exponent: e2, .. }) => e1 == e2,
+            (Value::Infinity, Value::Infinity) => true,
+            (Value::NaN { .. }, Value::NaN { .. }) => true,
+            _ => false,
+        }
+    }
+
+    /// Convert the number to the engineering string (to-engineering-string), where the exponent
+    /// is a multiple of three.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecNumber;
+    ///
+    /// assert_eq!("1.23E-7".parse::<DecNumber>()?.to_eng_string(), "123E-9");
+    /// assert_eq!("0E+7".parse::<DecNumber>()?.to_eng_string(), "0.00E+9");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn to_eng_string(&self) -> String {
+        let mut s = String::new();
+        self.write_string(&mut s, true).unwrap();
+        s
+    }
+
+    /// Write the scientific or engineering string of the number
+    fn write_string<W: Write>(&self, f: &mut W, eng: bool) -> fmt::Result {
+        if self.sign == Sign::Negative {
+            f.write_char('-')?;
+        }
+        let (coeff, exp) = match &self.value {
+            Value::Infinity => return f.write_str("Infinity"),
+            Value::NaN { payload, signaling } => {
+                if *signaling {
+                    f.write_char('s')?;
+                }
+                f.write_str("NaN")?;
+                if !payload.is_zero() {
+                    write!(f, "{}", payload)?;
+                }
+                return Ok(());
+            }
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => (coefficient, *exponent),
+        };
+
+        let digits = coeff.in_radix(10).to_string();
+        let len = digits.len() as isize;
+        let left_digits = exp + len;
+        let dot_place = if exp <= 0 && left_digits > -6 {
+            left_digits
+        } else if !eng {
+            1
+        } else if coeff.is_zero() {
+            (left_digits + 1).rem_euclid(3) - 1
+        } else {
+            (left_digits - 1).rem_euclid(3) + 1
+        };
+
+        if dot_place <= 0 {
+            f.write_str("0.")?;
+            for _ in 0..-dot_place {
+                f.write_char('0')?;
+            }
+            f.write_str(&digits)?;
+        } else if dot_place >= len {
+            f.write_str(&digits)?;
+            for _ in 0..dot_place - len {
+                f.write_char('0')?;
+            }
+        } else {
+            let (int, frac) = digits.split_at(dot_place as usize);
+            f.write_str(int)?;
+            f.write_char('.')?;
+            f.write_str(frac)?;
+        }
+        if left_digits != dot_place {
+            write!(f, "E{:+}", left_digits - dot_place)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DecNumber {
+    /// Write the scientific string of the number (to-scientific-string)
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_string(f, false)
+    }
+}
+
+impl FromStr for DecNumber {
+    type Err = ParseError;
+
+    /// Convert a numeric string to a [DecNumber] exactly, without rounding.
+    ///
+    /// The syntax is the numeric string of the specification: an optional sign followed by
+    /// a decimal number with an optional exponent part (e.g. `-1.20E+3`, `.5`, `7.`), or
+    /// `Infinity`/`Inf`, `NaN` or `sNaN` with optional payload digits (case-insensitive).
+    /// Exponents beyond ±`isize::MAX / 4` are saturated.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (sign, body) = match s.as_bytes().first() {
+            Some(b'+') => (Sign::Positive, &s[1..]),
+            Some(b'-') => (Sign::Negative, &s[1..]),
+            _ => (Sign::Positive, s),
+        };
+        if body.is_empty() {
+            return Err(ParseError::NoDigits);
+        }
+
+        let lower = body.to_ascii_lowercase();
+        if lower == "inf" || lower == "infinity" {
+            return Ok(Self::infinity(sign));
+        }
+        let nan = lower
+            .strip_prefix("nan")
+            .map(|p| (p, false))
+            .or_else(|| lower.strip_prefix("snan").map(|p| (p, true)));
+        if let Some((payload, signaling)) = nan {
+            let payload = if payload.is_empty() {
+                UBig::ZERO
+            } else if payload.bytes().all(|b| b.is_ascii_digit()) {
+                UBig::from_str_radix(payload, 10)?
+            } else {
+                return Err(ParseError::InvalidDigit);
+            };
+            return Ok(Self::special(sign, Value::NaN { payload, signaling }));
+        }
+
+        let (mantissa, exp_str) = match body.find(['e', 'E']) {
+            Some(pos) => (&body[..pos], Some(&body[pos + 1..])),
+            None => (body, None),
+        };
+        let (int, frac) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseError::NoDigits);
+        }
+        if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::InvalidDigit);
+        }
+
+        let mut exponent: isize = 0;
+        if let Some(exp_str) = exp_str {
+            let (exp_neg, exp_digits) = match exp_str.as_bytes().first() {
+                Some(b'+') => (false, &exp_str[1..]),
+                Some(b'-') => (true, &exp_str[1..]),
+                _ => (false, exp_str),
+            };
+            if exp_digits.is_empty() {
+                return Err(ParseError::NoDigits);
+            }
+            for b in exp_digits.bytes() {
+                if !b.is_ascii_digit() {
+                    return Err(ParseError::InvalidDigit);
+                }
+                exponent = exponent
+                    .saturating_mul(10)
+                    .saturating_add((b - b'0') as isize)
+                    .min(EXPONENT_LIMIT);
+            }
+            if exp_neg {
+                exponent = -exponent;
+            }
+        }
+        let exponent = (exponent - frac.len() as isize).max(-EXPONENT_LIMIT);
+
+        let mut digits = String::with_capacity(int.len() + frac.len());
+        digits.push_str(int);
+        digits.push_str(frac);
+        let coefficient = UBig::from_str_radix(&digits, 10)?;
+        Ok(Self::finite(sign, coefficient, exponent))
+    }
+}
+
+impl<R: Round> From<FBig<R, 10>> for DecNumber {
+    /// Convert a decimal float to a [DecNumber] with the same value. The exponent of the
+    /// result is the exponent of the normalized representation of the float.
+    fn from(f: FBig<R, 10>) -> Self {
+        let repr = f.into_repr();
+        if repr.is_infinite() {
+            return Self::infinity(repr.sign());
+        }
+        let exponent = repr.exponent();
+        let (sign, coefficient) = repr.significand().clone().into_parts();
+        Self::finite(sign, coefficient, exponent)
+    }
+}
+
+impl<R: Round> TryFrom<DecNumber> for FBig<R, 10> {
+    type Error = OutOfBoundsError;
+
+    /// Convert a [DecNumber] to a decimal float with the same value, where the precision of the
+    /// result is the number of digits in the coefficient. The sign of a zero is lost.
+    ///
+    /// Returns [OutOfBoundsError] if the number is a NaN.
+    fn try_from(n: DecNumber) -> Result<Self, OutOfBoundsError> {
+        match n.value {
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => {
+                let precision = num_digits(&coefficient);
+                let repr = Repr::new(IBig::from_parts(n.sign, coefficient), exponent);
+                Ok(FBig::from_repr(repr, Context::new(precision)))
+            }
+            Value::Infinity => Ok(match n.sign {
+                Sign::Positive => FBig::INFINITY,
+                Sign::Negative => FBig::NEG_INFINITY,
+            }),
+            Value::NaN { .. } => Err(OutOfBoundsError),
+        }
+    }
+}
+
+/// The arithmetic context of the General Decimal Arithmetic specification.
+///
+/// The context consists of the precision (the maximum number of digits in the coefficient),
+/// the [rounding mode][DecRounding], the exponent range `[emin, emax]` of the adjusted exponents,
+/// and the clamp option, which restricts the exponents of the results to `emax - precision + 1`
+/// like the interchange formats. The conditions raised by the operations are recorded in the
+/// sticky [flags][DecContext::flags].
+///
+/// The operations that take operands follow the specification: the NaN operands propagate
+/// (a signaling NaN raises [Flags::INVALID_OPERATION] and becomes quiet), and the result is
+/// rounded to the context, except for the operations that are exact by definition.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// use dashu_float::gda::{DecContext, DecNumber, DecRounding};
+/// use dashu_float::status::Flags;
+/// use dashu_float::DecimalFormat;
+///
+/// let mut ctxt = DecContext::new(5).with_rounding(DecRounding::Zero);
+/// let a = ctxt.parse("3.14159265");
+/// assert_eq!(a.to_string(), "3.1415");
+/// assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+///
+/// // the context of the decimal32 format
+/// let mut ctxt = DecContext::from(DecimalFormat::DECIMAL32);
+/// let big = ctxt.parse("1E+96");
+/// assert_eq!(big.to_string(), "1.000000E+96");
+/// assert_eq!(ctxt.flags(), Flags::CLAMPED);
+/// # Ok::<(), ParseError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecContext {
+    precision: usize,
+    rounding: DecRounding,
+    emin: isize,
+    emax: isize,
+    clamp: bool,
+    flags: Flags,
+}
+
+impl DecContext {
+    /// Create a context with the given precision, rounding mode [DecRounding::HalfEven], exponent
+    /// range `[-999999, 999999]` and no clamping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is zero.
+    pub const fn new(precision: usize) -> Self {
+        assert!(precision > 0);
+        Self {
+            precision,
+            rounding: DecRounding::HalfEven,
+            emin: -999999,
+            emax: 999999,
+            clamp: false,
+            flags: Flags::EMPTY,
+        }
+    }
+
+    /// Set the rounding mode of the context.
+    #[inline]
+    pub const fn with_rounding(mut self, rounding: DecRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set the range of the adjusted exponents of the context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `emin > 0` or `emax < 0`, or the exponents are not in the range ±`isize::MAX / 4`.
+    #[inline]
+    pub const fn with_exponent_range(mut self, emin: isize, emax: isize) -> Self {
+        assert!(emin <= 0 && emax >= 0);
+        assert!(emin >= -EXPONENT_LIMIT && emax <= EXPONENT_LIMIT);
+        self.emin = emin;
+        self.emax = emax;
+        self
+    }
+
+    /// Set whether the exponents of the results are clamped to `emax - precision + 1`.
+    #[inline]
+    pub const fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// Get the precision of the context
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Get the rounding mode of the context
+    #[inline]
+    pub const fn rounding(&self) -> DecRounding {
+        self.rounding
+    }
+
+    /// Get the minimum adjusted exponent of the context
+    #[inline]
+    pub const fn emin(&self) -> isize {
+        self.emin
+    }
+
+    /// Get the maximum adjusted exponent of the context
+    #[inline]
+    pub const fn emax(&self) -> isize {
+        self.emax
+    }
+
+    /// Check whether the exponents are clamped
+    #[inline]
+    pub const fn clamp(&self) -> bool {
+        self.clamp
+    }
+
+    /// Get the sticky flags raised by the operations since the last call of [clear_flags()][Self::clear_flags]
+    #[inline]
+    pub const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Reset the sticky flags
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = Flags::EMPTY;
+    }
+
+    /// The minimum exponent of the subnormal numbers
+    #[inline]
+    fn etiny(&self) -> isize {
+        self.emin - self.precision as isize + 1
+    }
+
+    /// The maximum exponent of the numbers with full precision
+    #[inline]
+    fn etop(&self) -> isize {
+        self.emax - self.precision as isize + 1
+    }
+
+    #[inline]
+    fn raise(&mut self, flags: Flags) {
+        self.flags |= flags;
+    }
+
+    /// Raise an invalid operation and return the quiet NaN
+    #[inline]
+    fn invalid(&mut self) -> DecNumber {
+        self.raise(Flags::INVALID_OPERATION);
+        DecNumber::NAN
+    }
+
+    /// The result of an overflow with the given sign
+    fn overflow_result(&self, sign: Sign) -> DecNumber {
+        let to_infinity = match self.rounding {
+            DecRounding::HalfAway
+            | DecRounding::HalfEven
+            | DecRounding::HalfDown
+            | DecRounding::Away => true,
+            DecRounding::Up => sign == Sign::Positive,
+            DecRounding::Down => sign == Sign::Negative,
+            DecRounding::Zero | DecRounding::ZeroFiveUp => false,
+        };
+        if to_infinity {
+            DecNumber::infinity(sign)
+        } else {
+            let max = pow10(self.precision) - UBig::ONE;
+            DecNumber::finite(sign, max, self.etop())
+        }
+    }
+
+    /// Round the finite number to the context, and handle the overflow, underflow and clamping.
+    fn finalize(&mut self, sign: Sign, coeff: UBig, exp: isize) -> DecNumber {
+        let (etiny, etop) = (self.etiny(), self.etop());
+        if coeff.is_zero() {
+            let exp_max = if self.clamp { etop } else { self.emax };
+            let new_exp = exp.clamp(etiny, exp_max);
+            if new_exp != exp {
+                self.raise(Flags::CLAMPED);
+            }
+            return DecNumber::finite(sign, coeff, new_exp);
+        }
+
+        let mut exp_min = exp + num_digits(&coeff) as isize - self.precision as isize;
+        if exp_min > etop {
+            self.raise(Flags::OVERFLOW | Flags::INEXACT | Flags::ROUNDED);
+            return self.overflow_result(sign);
+        }
+        let subnormal = exp_min < etiny;
+        if subnormal {
+            exp_min = etiny;
+        }
+
+        if exp < exp_min {
+            let (mut rounded, inexact) =
+                round_digits(&coeff, (exp_min - exp) as usize, sign, self.rounding);
+            if num_digits(&rounded) > self.precision {
+                rounded /= UBig::from(10u8);
+                exp_min += 1;
+            }
+            let mut flags = Flags::ROUNDED;
+            let result = if exp_min > etop {
+                flags |= Flags::OVERFLOW;
+                self.overflow_result(sign)
+            } else {
+                DecNumber::finite(sign, rounded, exp_min)
+            };
+            if subnormal {
+                flags |= Flags::SUBNORMAL;
+                if inexact {
+                    flags |= Flags::UNDERFLOW;
+                }
+            }
+            if inexact {
+                flags |= Flags::INEXACT;
+            }
+            if result.is_zero() {
+                flags |= Flags::CLAMPED;
+            }
+            self.raise(flags);
+            return result;
+        }
+
+        if subnormal {
+            self.raise(Flags::SUBNORMAL);
+        }
+        if self.clamp && exp > etop {
+            self.raise(Flags::CLAMPED);
+            let coeff = coeff * pow10((exp - etop) as usize);
+            return DecNumber::finite(sign, coeff, etop);
+        }
+        DecNumber::finite(sign, coeff, exp)
+    }
+
+    /// Round the number to the context. The special values are returned unchanged.
+    fn fix(&mut self, x: DecNumber) -> DecNumber {
+        match x.value {
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => self.finalize(x.sign, coefficient, exponent),
+            _ => x,
+        }
+    }
+
+    /// Truncate the payload of a NaN to fit the precision
+    fn fix_nan(&self, sign: Sign, payload: &UBig) -> DecNumber {
+        let max_len = self.precision - self.clamp as usize;
+        let payload = if payload_len(payload) > max_len {
+            payload % pow10(max_len)
+        } else {
+            payload.clone()
+        };
+        DecNumber::special(
+            sign,
+            Value::NaN {
+                payload,
+                signaling: false,
+            },
+        )
+    }
+
+    /// Propagate the NaN operands. The signaling NaNs take precedence and raise an invalid operation.
+    fn check_nans(&mut self, a: &DecNumber, b: Option<&DecNumber>) -> Option<DecNumber> {
+        for x in [Some(a), b].into_iter().flatten() {
+            if let Value::NaN {
+                payload,
+                signaling: true,
+            } = &x.value
+            {
+                self.raise(Flags::INVALID_OPERATION);
+                return Some(self.fix_nan(x.sign, payload));
+            }
+        }
+        for x in [Some(a), b].into_iter().flatten() {
+            if let Value::NaN { payload, .. } = &x.value {
+                return Some(self.fix_nan(x.sign, payload));
+            }
+        }
+        None
+    }
+
+    /// Rescale the finite number to the given exponent using the rounding mode of the context,
+    /// without checking the range. Returns the result and whether it's inexact.
+    fn rescale(&self, x: &DecNumber, exp: isize) -> (DecNumber, bool) {
+        let (coeff, x_exp) = match &x.value {
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => (coefficient, *exponent),
+            _ => unreachable!(),
+        };
+        if coeff.is_zero() {
+            return (DecNumber::finite(x.sign, UBig::ZERO, exp), false);
+        }
+        if x_exp >= exp {
+            let coeff = coeff * pow10((x_exp - exp) as usize);
+            return (DecNumber::finite(x.sign, coeff, exp), false);
+        }
+        let (coeff, inexact) = round_digits(coeff, (exp - x_exp) as usize, x.sign, self.rounding);
+        (DecNumber::finite(x.sign, coeff, exp), inexact)
+    }
+
+    /// Convert a string to a number and round it to the context (to-number).
+    ///
+    /// An invalid string results in a quiet NaN and raises [Flags::INVALID_OPERATION]
+    /// (*conversion syntax*), so does a NaN whose payload is too long for the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::gda::DecContext;
+    /// use dashu_float::status::Flags;
+    ///
+    /// let mut ctxt = DecContext::new(3);
+    /// assert_eq!(ctxt.parse("1.2345E-2").to_string(), "0.0123");
+    /// assert_eq!(ctxt.parse("-0.00").to_string(), "-0.00");
+    /// assert_eq!(ctxt.parse("1,000").to_string(), "NaN");
+    /// assert!(ctxt.flags().contains(Flags::INVALID_OPERATION));
+    /// ```
+    pub fn parse(&mut self, s: &str) -> DecNumber {
+        let n = match DecNumber::from_str(s) {
+            Ok(n) => n,
+            Err(_) => return self.invalid(),
+        };
+        if let Value::NaN { payload, .. } = &n.value {
+            if payload_len(payload) > self.precision - self.clamp as usize {
+                return self.invalid();
+            }
+        }
+        self.fix(n)
+    }
+
+    /// Round the number to the context (plus), which is equivalent to adding it to zero.
+    pub fn plus(&mut self, x: &DecNumber) -> DecNumber {
+        if let Some(nan) = self.check_nans(x, None) {
+            return nan;
+        }
+        let mut x = x.clone();
+        if x.is_zero() && self.rounding != DecRounding::Down {
+            x.sign = Sign::Positive;
+        }
+        self.fix(x)
+    }
+
+    /// Negate the number and round it to the context (minus), which is equivalent to subtracting
+    /// it from zero.
+    pub fn minus(&mut self, x: &DecNumber) -> DecNumber {
+        if let Some(nan) = self.check_nans(x, None) {
+            return nan;
+        }
+        let mut x = x.copy_negate();
+        if x.is_zero() && self.rounding != DecRounding::Down {
+            x.sign = Sign::Positive;
+        }
+        self.fix(x)
+    }
+
+    /// Calculate the absolute value of the number and round it to the context (abs).
+    pub fn abs(&mut self, x: &DecNumber) -> DecNumber {
+        match x.sign {
+            Sign::Positive => self.plus(x),
+            Sign::Negative => self.minus(x),
+        }
+    }
+
+    /// Add two numbers (add).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let (a, b) = ("1.3".parse()?, "-1.30".parse()?);
+    /// assert_eq!(ctxt.add(&a, &b).to_string(), "0.00");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn add(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        if lhs.is_special() || rhs.is_special() {
+            if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+                return nan;
+            }
+            if lhs.is_infinite() {
+                if rhs.is_infinite() && lhs.sign != rhs.sign {
+                    return self.invalid();
+                }
+                return lhs.clone();
+            }
+            return rhs.clone();
+        }
+
+        let (c1, e1) = (lhs.coefficient().unwrap(), lhs.exponent().unwrap());
+        let (c2, e2) = (rhs.coefficient().unwrap(), rhs.exponent().unwrap());
+        let mut exp = e1.min(e2);
+        let zero_sign = if self.rounding == DecRounding::Down && lhs.sign != rhs.sign {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        if c1.is_zero() && c2.is_zero() {
+            let sign = if lhs.sign == Sign::Negative && rhs.sign == Sign::Negative {
+                Sign::Negative
+            } else {
+                zero_sign
+            };
+            return self.finalize(sign, UBig::ZERO, exp);
+        }
+        if c1.is_zero() || c2.is_zero() {
+            let (x, ex) = if c1.is_zero() { (rhs, e2) } else { (lhs, e1) };
+            exp = exp.max(ex - self.precision as isize - 1);
+            let (result, _) = self.rescale(x, exp);
+            return self.fix(result);
+        }
+
+        // align the operands, a far smaller operand only has to be kept as a sticky digit
+        let (big, small) = if e1 < e2 { (rhs, lhs) } else { (lhs, rhs) };
+        let (big_coeff, big_exp) = (big.coefficient().unwrap(), big.exponent().unwrap());
+        let (small_coeff, small_exp) = (small.coefficient().unwrap(), small.exponent().unwrap());
+        let limit =
+            big_exp + (num_digits(big_coeff) as isize - self.precision as isize - 2).min(-1);
+        let (small_coeff, small_exp) = if small.adjusted().unwrap() < limit {
+            (UBig::ONE, limit)
+        } else {
+            (small_coeff.clone(), small_exp)
+        };
+        let big_coeff = big_coeff * pow10((big_exp - small_exp) as usize);
+
+        let (sign, coeff) = if big.sign == small.sign {
+            (big.sign, big_coeff + small_coeff)
+        } else {
+            match big_coeff.cmp(&small_coeff) {
+                Ordering::Equal => return self.finalize(zero_sign, UBig::ZERO, exp),
+                Ordering::Greater => (big.sign, big_coeff - small_coeff),
+                Ordering::Less => (small.sign, small_coeff - big_coeff),
+            }
+        };
+        self.finalize(sign, coeff, small_exp)
+    }
+
+    /// Subtract two numbers (subtract).
+    pub fn subtract(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+            return nan;
+        }
+        self.add(lhs, &rhs.copy_negate())
+    }
+
+    /// Multiply two numbers (multiply).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let (a, b) = ("1.20".parse()?, "3".parse()?);
+    /// assert_eq!(ctxt.multiply(&a, &b).to_string(), "3.60");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn multiply(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        let sign = lhs.sign * rhs.sign;
+        if lhs.is_special() || rhs.is_special() {
+            if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+                return nan;
+            }
+            if lhs.is_zero() || rhs.is_zero() {
+                return self.invalid();
+            }
+            return DecNumber::infinity(sign);
+        }
+        let coeff = lhs.coefficient().unwrap() * rhs.coefficient().unwrap();
+        let exp = lhs.exponent().unwrap() + rhs.exponent().unwrap();
+        self.finalize(sign, coeff, exp)
+    }
+
+    /// Divide two numbers (divide).
+    ///
+    /// The exponent of an exact result is the closest one to the difference of the operand exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let d = |ctxt: &mut DecContext, a: &str, b: &str| ctxt.divide(&a.parse().unwrap(), &b.parse().unwrap());
+    /// assert_eq!(d(&mut ctxt, "2.40", "2").to_string(), "1.20");
+    /// assert_eq!(d(&mut ctxt, "1000", "100").to_string(), "10");
+    /// assert_eq!(d(&mut ctxt, "2", "3").to_string(), "0.666666667");
+    /// assert_eq!(d(&mut ctxt, "-1", "0").to_string(), "-Infinity");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn divide(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        let sign = lhs.sign * rhs.sign;
+        if lhs.is_special() || rhs.is_special() {
+            if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+                return nan;
+            }
+            if lhs.is_infinite() && rhs.is_infinite() {
+                return self.invalid();
+            }
+            if lhs.is_infinite() {
+                return DecNumber::infinity(sign);
+            }
+            self.raise(Flags::CLAMPED);
+            return DecNumber::finite(sign, UBig::ZERO, self.etiny());
+        }
+        if rhs.is_zero() {
+            if lhs.is_zero() {
+                return self.invalid();
+            }
+            self.raise(Flags::DIVISION_BY_ZERO);
+            return DecNumber::infinity(sign);
+        }
+
+        let (c1, e1) = (lhs.coefficient().unwrap(), lhs.exponent().unwrap());
+        let (c2, e2) = (rhs.coefficient().unwrap(), rhs.exponent().unwrap());
+        let ideal_exp = e1 - e2;
+        if c1.is_zero() {
+            return self.finalize(sign, UBig::ZERO, ideal_exp);
+        }
+
+        // calculate precision + 1 digits at least, and make the last digit sticky
+        let shift = num_digits(c2) as isize - num_digits(c1) as isize + self.precision as isize + 1;
+        let mut exp = ideal_exp - shift;
+        let (mut coeff, rem) = if shift >= 0 {
+            (c1 * pow10(shift as usize)).div_rem(c2)
+        } else {
+            c1.div_rem(c2 * pow10((-shift) as usize))
+        };
+        if !rem.is_zero() {
+            if &coeff % 5u8 == 0 {
+                coeff += UBig::ONE;
+            }
+        } else if exp < ideal_exp {
+            exp += strip_trailing_zeros(&mut coeff, (ideal_exp - exp) as usize) as isize;
+        }
+        self.finalize(sign, coeff, exp)
+    }
+
+    /// The integer part of the quotient and the remainder of two finite numbers, or [None] if the
+    /// integer part doesn't fit the precision. The remainder is not rounded.
+    fn divide_parts(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> Option<(DecNumber, DecNumber)> {
+        let sign = lhs.sign * rhs.sign;
+        let ideal_exp = match rhs.exponent() {
+            Some(e2) => lhs.exponent().unwrap().min(e2),
+            None => lhs.exponent().unwrap(),
+        };
+        if lhs.is_zero()
+            || rhs.is_infinite()
+            || lhs.adjusted().unwrap() - rhs.adjusted().unwrap() <= -2
+        {
+            let (rem, _) = self.rescale(lhs, ideal_exp);
+            return Some((DecNumber::finite(sign, UBig::ZERO, 0), rem));
+        }
+
+        let (c1, e1) = (lhs.coefficient().unwrap(), lhs.exponent().unwrap());
+        let (c2, e2) = (rhs.coefficient().unwrap(), rhs.exponent().unwrap());
+        if lhs.adjusted().unwrap() - rhs.adjusted().unwrap() <= self.precision as isize {
+            let (q, r) = if e1 >= e2 {
+                (c1 * pow10((e1 - e2) as usize)).div_rem(c2)
+            } else {
+                c1.div_rem(c2 * pow10((e2 - e1) as usize))
+            };
+            if q < pow10(self.precision) {
+                return Some((
+                    DecNumber::finite(sign, q, 0),
+                    DecNumber::finite(lhs.sign, r, ideal_exp),
+                ));
+            }
+        }
+        None
+    }
+
+    /// Calculate the integer part of the quotient of two numbers, truncated towards zero (divide-integer).
+    ///
+    /// If the result has more digits than the precision, [Flags::INVALID_OPERATION] is raised
+    /// (*division impossible*).
+    pub fn divide_integer(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        let sign = lhs.sign * rhs.sign;
+        if lhs.is_special() || rhs.is_special() {
+            if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+                return nan;
+            }
+            if lhs.is_infinite() {
+                if rhs.is_infinite() {
+                    return self.invalid();
+                }
+                return DecNumber::infinity(sign);
+            }
+            return DecNumber::finite(sign, UBig::ZERO, 0);
+        }
+        if rhs.is_zero() {
+            if lhs.is_zero() {
+                return self.invalid();
+            }
+            self.raise(Flags::DIVISION_BY_ZERO);
+            return DecNumber::infinity(sign);
+        }
+        match self.divide_parts(lhs, rhs) {
+            Some((q, _)) => self.fix(q),
+            None => self.invalid(),
+        }
+    }
+
+    /// Calculate the remainder of the truncated division of two numbers (remainder). The sign of
+    /// the result is the sign of `lhs`.
+    ///
+    /// If the integer part of the quotient has more digits than the precision,
+    /// [Flags::INVALID_OPERATION] is raised (*division impossible*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let (a, b) = ("-10".parse()?, "3".parse()?);
+    /// assert_eq!(ctxt.remainder(&a, &b).to_string(), "-1");
+    /// assert_eq!(ctxt.divide_integer(&a, &b).to_string(), "-3");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn remainder(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        if lhs.is_special() || rhs.is_special() {
+            if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+                return nan;
+            }
+            if lhs.is_infinite() {
+                return self.invalid();
+            }
+        }
+        if rhs.is_zero() {
+            return self.invalid();
+        }
+        match self.divide_parts(lhs, rhs) {
+            Some((_, r)) => self.fix(r),
+            None => self.invalid(),
+        }
+    }
+
+    /// Compare two numbers numerically (compare). The result is `-1`, `0` or `1`, or a NaN
+    /// if any operand is a NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let (a, b) = ("2.10".parse()?, "2.1".parse()?);
+    /// assert_eq!(ctxt.compare(&a, &b).to_string(), "0");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn compare(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        if let Some(nan) = self.check_nans(lhs, Some(rhs)) {
+            return nan;
+        }
+        match lhs.cmp_value(rhs) {
+            Ordering::Less => DecNumber::finite(Sign::Negative, UBig::ONE, 0),
+            Ordering::Equal => DecNumber::ZERO,
+            Ordering::Greater => DecNumber::finite(Sign::Positive, UBig::ONE, 0),
+        }
+    }
+
+    /// Select one of the operands with the comparison, where a quiet NaN is ignored if the
+    /// other operand is a number.
+    fn select<F>(
+        &mut self,
+        lhs: &DecNumber,
+        rhs: &DecNumber,
+        cmp: F,
+        take_lhs: Ordering,
+    ) -> DecNumber
+    where
+        F: Fn(&DecNumber, &DecNumber) -> Ordering,
+    {
+        if lhs.is_nan() || rhs.is_nan() {
+            if rhs.is_qnan() && !lhs.is_nan() {
+                return self.fix(lhs.clone());
+            }
+            if lhs.is_qnan() && !rhs.is_nan() {
+                return self.fix(rhs.clone());
+            }
+            return self.check_nans(lhs, Some(rhs)).unwrap();
+        }
+        let ord = match cmp(lhs, rhs) {
+            Ordering::Equal => lhs.compare_total(rhs),
+            ord => ord,
+        };
+        let selected = if ord == take_lhs { lhs } else { rhs };
+        self.fix(selected.clone())
+    }
+
+    /// Get the larger one of two numbers (max). If the operands are numerically equal, the one
+    /// that is larger in the total ordering is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::{DecContext, DecNumber};
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let a: DecNumber = "1.0".parse()?;
+    /// assert_eq!(ctxt.max(&a, &DecNumber::NAN).to_string(), "1.0");
+    /// assert_eq!(ctxt.max(&a, &"1".parse()?).to_string(), "1");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn max(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        self.select(lhs, rhs, DecNumber::cmp_value, Ordering::Greater)
+    }
+
+    /// Get the smaller one of two numbers (min). See [max()][Self::max] for the details.
+    pub fn min(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        self.select(lhs, rhs, DecNumber::cmp_value, Ordering::Less)
+    }
+
+    /// Get the one of two numbers with the larger magnitude (max-magnitude).
+    pub fn max_mag(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        self.select(lhs, rhs, DecNumber::cmp_abs, Ordering::Greater)
+    }
+
+    /// Get the one of two numbers with the smaller magnitude (min-magnitude).
+    pub fn min_mag(&mut self, lhs: &DecNumber, rhs: &DecNumber) -> DecNumber {
+        self.select(lhs, rhs, DecNumber::cmp_abs, Ordering::Less)
+    }
+
+    /// Round the number to the exponent of `exp` (quantize).
+    ///
+    /// If the result doesn't fit the precision or the exponent range of the context,
+    /// [Flags::INVALID_OPERATION] is raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::{DecContext, DecNumber};
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// let a: DecNumber = "2.17".parse()?;
+    /// assert_eq!(ctxt.quantize(&a, &"0.1".parse()?).to_string(), "2.2");
+    /// assert_eq!(ctxt.quantize(&a, &"1e-4".parse()?).to_string(), "2.1700");
+    /// assert_eq!(ctxt.quantize(&a, &"1e+1".parse()?).to_string(), "0E+1");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn quantize(&mut self, x: &DecNumber, exp: &DecNumber) -> DecNumber {
+        if x.is_special() || exp.is_special() {
+            if let Some(nan) = self.check_nans(x, Some(exp)) {
+                return nan;
+            }
+            if x.is_infinite() && exp.is_infinite() {
+                return x.clone();
+            }
+            return self.invalid();
+        }
+
+        let exp = exp.exponent().unwrap();
+        if exp < self.etiny() || exp > self.emax {
+            return self.invalid();
+        }
+        if x.is_zero() {
+            return self.finalize(x.sign, UBig::ZERO, exp);
+        }
+        let adjusted = x.adjusted().unwrap();
+        if adjusted > self.emax || adjusted - exp + 1 > self.precision as isize {
+            return self.invalid();
+        }
+
+        let (result, inexact) = self.rescale(x, exp);
+        let result_adjusted = result.adjusted().unwrap();
+        if result_adjusted > self.emax || num_digits(result.coefficient().unwrap()) > self.precision
+        {
+            return self.invalid();
+        }
+        if !result.is_zero() && result_adjusted < self.emin {
+            self.raise(Flags::SUBNORMAL);
+        }
+        if exp > x.exponent().unwrap() {
+            if inexact {
+                self.raise(Flags::INEXACT);
+            }
+            self.raise(Flags::ROUNDED);
+        }
+        self.fix(result)
+    }
+
+    /// Round the number to the context and remove the trailing zeros of the coefficient (reduce).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// assert_eq!(ctxt.reduce(&"120.00".parse()?).to_string(), "1.2E+2");
+    /// assert_eq!(ctxt.reduce(&"-0.000".parse()?).to_string(), "-0");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn reduce(&mut self, x: &DecNumber) -> DecNumber {
+        if let Some(nan) = self.check_nans(x, None) {
+            return nan;
+        }
+        let x = self.fix(x.clone());
+        let (mut coeff, mut exp) = match x.value {
+            Value::Finite {
+                coefficient,
+                exponent,
+            } => (coefficient, exponent),
+            _ => return x,
+        };
+        if coeff.is_zero() {
+            return DecNumber::finite(x.sign, coeff, 0);
+        }
+        let exp_max = if self.clamp { self.etop() } else { self.emax };
+        if exp < exp_max {
+            exp += strip_trailing_zeros(&mut coeff, (exp_max - exp) as usize) as isize;
+        }
+        DecNumber::finite(x.sign, coeff, exp)
+    }
+
+    /// Round the number to an integer using the rounding mode of the context, without
+    /// raising [Flags::INEXACT] or [Flags::ROUNDED] (to-integral-value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// use dashu_float::gda::DecContext;
+    ///
+    /// let mut ctxt = DecContext::new(9);
+    /// assert_eq!(ctxt.to_integral_value(&"2.5".parse()?).to_string(), "2");
+    /// assert_eq!(ctxt.to_integral_value(&"-0.7".parse()?).to_string(), "-1");
+    /// assert_eq!(ctxt.to_integral_value(&"7.8E+2".parse()?).to_string(), "7.8E+2");
+    /// assert!(ctxt.flags().is_empty());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn to_integral_value(&mut self, x: &DecNumber) -> DecNumber {
+        self.round_to_integral(x).0
+    }
+
+    /// Round the number to an integer using the rounding mode of the context, raising
+    /// [Flags::INEXACT] and [Flags::ROUNDED] as usual (to-integral-exact).
+    pub fn to_integral_exact(&mut self, x: &DecNumber) -> DecNumber {
+        let (result, rounded, inexact) = self.round_to_integral(x);
+        if rounded {
+            self.raise(Flags::ROUNDED);
+        }
+        if inexact {
+            self.raise(Flags::INEXACT);
+        }
+        result
+    }
+
+    /// Round the number to an integer, returns the result, whether it's rounded and whether it's inexact
+    fn round_to_integral(&mut self, x: &DecNumber) -> (DecNumber, bool, bool) {
+        if let Some(nan) = self.check_nans(x, None) {
+            return (nan, false, false);
+        }
+        match x.exponent() {
+            None => (x.clone(), false, false),
+            Some(e) if e >= 0 => (x.clone(), false, false),
+            Some(_) if x.is_zero() => (DecNumber::finite(x.sign, UBig::ZERO, 0), false, false),
+            Some(_) => {
+                let (result, inexact) = self.rescale(x, 0);
+                (result, true, inexact)
+            }
+        }
+    }
+}
+
+impl From<DecimalFormat> for DecContext {
+    /// Create the context of the decimal interchange format, with rounding mode [DecRounding::HalfEven]
+    /// and the exponents clamped.
+    #[inline]
+    fn from(format: DecimalFormat) -> Self {
+        DecContext::new(format.precision())
+            .with_exponent_range(format.emin(), format.emax())
+            .with_clamp(true)
+    }
+}
@@ -111,7 +111,232 @@ macro_rules! impl_binop_assign_by_taking {
     };
 }
 
+/// Implement the common methods and traits for a fixed-point type `$t<const S: usize, R: Round>`
+/// that stores the number of units `$base ^ -S` in the field `raw`. The type should provide the
+/// methods `from_raw`, `from_fbig`, `mul_rounded` and `div_rounded`.
+macro_rules! impl_fixed_point_common {
+    ($t:ident, $base:literal, $scale_name:literal) => {
+        impl<const S: usize, R: Round> $t<S, R> {
+            #[doc = concat!("[", stringify!($t), "] with value 0")]
+            pub const ZERO: Self = Self::from_raw(IBig::ZERO);
+
+            /// Determine if the number is zero
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.raw.is_zero()
+            }
+
+            /// Get the sign of the number. Zero is considered as positive.
+            #[inline]
+            pub const fn sign(&self) -> Sign {
+                self.raw.sign()
+            }
+
+            /// Get the absolute value of the number
+            #[inline]
+            pub fn abs(&self) -> Self {
+                Self::from_raw(self.raw.clone().abs())
+            }
+
+            /// Round (integer + fract / base ^ shift) to a fixed-point number
+            #[inline]
+            fn round_fract(integer: IBig, fract: IBig, shift: usize) -> Rounded<Self> {
+                if fract.is_zero() {
+                    Exact(Self::from_raw(integer))
+                } else {
+                    let adjust = R::round_fract::<$base>(&integer, fract, shift);
+                    Inexact(Self::from_raw(integer + adjust), adjust)
+                }
+            }
+
+            /// Round (integer + num / den) to a fixed-point number
+            #[inline]
+            fn round_ratio(integer: IBig, num: IBig, den: &IBig) -> Rounded<Self> {
+                if num.is_zero() {
+                    Exact(Self::from_raw(integer))
+                } else {
+                    let adjust = R::round_ratio(&integer, num, den);
+                    Inexact(Self::from_raw(integer + adjust), adjust)
+                }
+            }
+
+            fn add_ref_ref(lhs: &Self, rhs: &Self) -> Self {
+                Self::from_raw(&lhs.raw + &rhs.raw)
+            }
+
+            fn sub_ref_ref(lhs: &Self, rhs: &Self) -> Self {
+                Self::from_raw(&lhs.raw - &rhs.raw)
+            }
+
+            fn mul_ref_ref(lhs: &Self, rhs: &Self) -> Self {
+                lhs.mul_rounded(rhs).value()
+            }
+
+            fn div_ref_ref(lhs: &Self, rhs: &Self) -> Self {
+                lhs.div_rounded(rhs).value()
+            }
+        }
+
+        // These custom implementations are necessary for the same reason as FBig
+        impl<const S: usize, R: Round> Clone for $t<S, R> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self::from_raw(self.raw.clone())
+            }
+        }
+
+        impl<const S: usize, R: Round> PartialEq for $t<S, R> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.raw == other.raw
+            }
+        }
+
+        impl<const S: usize, R: Round> Eq for $t<S, R> {}
+
+        impl<const S: usize, R: Round> PartialOrd for $t<S, R> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<const S: usize, R: Round> Ord for $t<S, R> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.raw.cmp(&other.raw)
+            }
+        }
+
+        impl<const S: usize, R: Round> Hash for $t<S, R> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.raw.hash(state)
+            }
+        }
+
+        impl<const S: usize, R: Round> Default for $t<S, R> {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<const S: usize, R: Round> Debug for $t<S, R> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($t))
+                    .field("raw", &self.raw)
+                    .field($scale_name, &S)
+                    .field("rounding", &format_args!("{}", rounding_name::<R>()))
+                    .finish()
+            }
+        }
+
+        impl<const S: usize, R: Round> FromStr for $t<S, R> {
+            type Err = ParseError;
+
+            /// Parse a decimal number, with rounding if it cannot be represented with the
+            /// scale of this type.
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                let x = FBig::<R, 10>::from_str(s)?;
+                Ok(Self::from_fbig(&x).value())
+            }
+        }
+
+        impl<const S: usize, R: Round> From<IBig> for $t<S, R> {
+            #[inline]
+            fn from(n: IBig) -> Self {
+                Self::from_raw(shl_digits::<$base>(&n, S))
+            }
+        }
+
+        impl<const S: usize, R: Round> From<i32> for $t<S, R> {
+            #[inline]
+            fn from(n: i32) -> Self {
+                Self::from(IBig::from(n))
+            }
+        }
+
+        impl<const S: usize, R: Round> From<$t<S, R>> for FBig<R, $base> {
+            #[inline]
+            fn from(x: $t<S, R>) -> Self {
+                FBig::from_parts(x.raw, -(S as isize))
+            }
+        }
+
+        impl<const S: usize, R: Round> Neg for $t<S, R> {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self::from_raw(-self.raw)
+            }
+        }
+
+        impl<const S: usize, R: Round> Neg for &$t<S, R> {
+            type Output = $t<S, R>;
+            #[inline]
+            fn neg(self) -> $t<S, R> {
+                $t::from_raw(-&self.raw)
+            }
+        }
+
+        impl<const S: usize, R: Round> Sum for $t<S, R> {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self::from_raw(iter.map(|x| x.raw).sum())
+            }
+        }
+
+        impl<'a, const S: usize, R: Round> Sum<&'a $t<S, R>> for $t<S, R> {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self::from_raw(iter.map(|x| &x.raw).sum())
+            }
+        }
+
+        crate::helper_macros::impl_fixed_point_common!(impl Add for $t, add, add_ref_ref);
+        crate::helper_macros::impl_fixed_point_common!(impl Sub for $t, sub, sub_ref_ref);
+        crate::helper_macros::impl_fixed_point_common!(impl Mul for $t, mul, mul_ref_ref);
+        crate::helper_macros::impl_fixed_point_common!(impl Div for $t, div, div_ref_ref);
+    };
+    (impl $trait:ident for $t:ident, $method:ident, $impl:ident) => {
+        impl<const S: usize, R: Round> $trait for $t<S, R> {
+            type Output = $t<S, R>;
+            #[inline]
+            fn $method(self, rhs: $t<S, R>) -> $t<S, R> {
+                $t::$impl(&self, &rhs)
+            }
+        }
+
+        impl<'r, const S: usize, R: Round> $trait<&'r $t<S, R>> for $t<S, R> {
+            type Output = $t<S, R>;
+            #[inline]
+            fn $method(self, rhs: &$t<S, R>) -> $t<S, R> {
+                $t::$impl(&self, rhs)
+            }
+        }
+
+        impl<'l, const S: usize, R: Round> $trait<$t<S, R>> for &'l $t<S, R> {
+            type Output = $t<S, R>;
+            #[inline]
+            fn $method(self, rhs: $t<S, R>) -> $t<S, R> {
+                $t::$impl(self, &rhs)
+            }
+        }
+
+        impl<'l, 'r, const S: usize, R: Round> $trait<&'r $t<S, R>> for &'l $t<S, R> {
+            type Output = $t<S, R>;
+            #[inline]
+            fn $method(self, rhs: &$t<S, R>) -> $t<S, R> {
+                $t::$impl(self, rhs)
+            }
+        }
+    };
+}
+
 pub(crate) use impl_binop_assign_by_taking;
 pub(crate) use impl_binop_assign_with_primitive;
 pub(crate) use impl_binop_with_primitive;
 pub(crate) use impl_commutative_binop_with_primitive;
+pub(crate) use impl_fixed_point_common;
@@ -0,0 +1,658 @@
+use crate::{
+    fbig::FBig,
+    repr::{Context, Repr},
+    round::{Round, Rounded},
+};
+use dashu_base::{Approximation::*, Sign};
+use dashu_int::{error::OutOfBoundsError, IBig, UBig};
+
+/// The combination field prefix of the infinities in the decimal formats
+const DECIMAL_INF: u128 = 0b11110;
+/// The combination field prefix of the NaNs in the decimal formats
+const DECIMAL_NAN: u128 = 0b11111;
+
+/// An IEEE 754 binary interchange format, determined by the number of the exponent bits and
+/// the number of the trailing significand (mantissa) bits.
+///
+/// The encoding consists of a sign bit, the biased exponent and the trailing significand with
+/// an implicit leading bit, from the most significant to the least significant. The formats
+/// defined by the standard are provided as constants, and other formats (such as the
+/// [bfloat16](BinaryFormat::BFLOAT16) format) can be created by [BinaryFormat::new].
+///
+/// The numbers can be encoded to and decoded from the interchange formats using
+/// [FBig::to_bits] and [FBig::from_bits] respectively.
+///
+/// # Examples
+///
+/// ```
+/// use dashu_float::BinaryFormat;
+///
+/// let half = BinaryFormat::BINARY16;
+/// assert_eq!(half.total_bits(), 16);
+/// assert_eq!(half.precision(), 11);
+/// assert_eq!((half.emin(), half.emax()), (-14, 15));
+///
+/// let custom = BinaryFormat::new(4, 3); // the 8-bit E4M3 format
+/// assert_eq!(custom.emax(), 7);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryFormat {
+    exp_bits: u32,
+    mantissa_bits: u32,
+}
+
+impl BinaryFormat {
+    /// The IEEE 754 binary16 (half precision) format
+    pub const BINARY16: Self = Self::new(5, 10);
+    /// The bfloat16 (brain floating point) format, which has the same exponent range as [f32]
+    pub const BFLOAT16: Self = Self::new(8, 7);
+    /// The IEEE 754 binary32 (single precision) format, which is used by [f32]
+    pub const BINARY32: Self = Self::new(8, 23);
+    /// The IEEE 754 binary64 (double precision) format, which is used by [f64]
+    pub const BINARY64: Self = Self::new(11, 52);
+    /// The IEEE 754 binary128 (quadruple precision) format
+    pub const BINARY128: Self = Self::new(15, 112);
+    /// The IEEE 754 binary256 (octuple precision) format
+    pub const BINARY256: Self = Self::new(19, 236);
+
+    /// Create a binary interchange format with `exp_bits` exponent bits and `mantissa_bits`
+    /// trailing significand bits (excluding the implicit leading bit).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp_bits` is not in the range \[2, 30\] or `mantissa_bits` is zero.
+    #[inline]
+    pub const fn new(exp_bits: u32, mantissa_bits: u32) -> Self {
+        assert!(exp_bits >= 2 && exp_bits <= 30);
+        assert!(mantissa_bits > 0);
+        Self {
+            exp_bits,
+            mantissa_bits,
+        }
+    }
+
+    /// Get the number of the exponent bits
+    #[inline]
+    pub const fn exp_bits(&self) -> u32 {
+        self.exp_bits
+    }
+
+    /// Get the number of the trailing significand bits
+    #[inline]
+    pub const fn mantissa_bits(&self) -> u32 {
+        self.mantissa_bits
+    }
+
+    /// Get the total number of bits of the encoding
+    #[inline]
+    pub const fn total_bits(&self) -> usize {
+        1 + self.exp_bits as usize + self.mantissa_bits as usize
+    }
+
+    /// Get the precision (in bits) of the normal numbers, including the implicit leading bit
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.mantissa_bits as usize + 1
+    }
+
+    /// Get the maximum exponent of the finite numbers, which is also the exponent bias
+    #[inline]
+    pub const fn emax(&self) -> isize {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    /// Get the minimum exponent of the normal numbers
+    #[inline]
+    pub const fn emin(&self) -> isize {
+        1 - self.emax()
+    }
+
+    /// Create a [Context] with the precision and the exponent range of this format, so that
+    /// the operations under the context produce the same results as the IEEE 754 arithmetic
+    /// in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{BinaryFormat, round::mode::HalfEven};
+    ///
+    /// let context = BinaryFormat::BINARY16.context::<HalfEven>();
+    /// assert_eq!(context.precision(), 11);
+    /// assert_eq!((context.emin(), context.emax()), (Some(-14), Some(15)));
+    /// ```
+    #[inline]
+    pub const fn context<R: Round>(&self) -> Context<R> {
+        Context::new(self.precision()).with_exponent_range(self.emin(), self.emax())
+    }
+
+    /// Check whether the bits encode a NaN value in this format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::BinaryFormat;
+    ///
+    /// assert!(BinaryFormat::BINARY16.is_nan(&0x7e00u16.into()));
+    /// assert!(!BinaryFormat::BINARY16.is_nan(&0x7c00u16.into())); // infinity
+    /// ```
+    pub fn is_nan(&self, bits: &UBig) -> bool {
+        let (mantissa, exponent) = self.split(bits.clone());
+        exponent == self.exp_mask() && !mantissa.is_zero()
+    }
+
+    /// Split the encoding (without the sign bit) into the trailing significand and the exponent
+    #[inline]
+    fn split(&self, mut bits: UBig) -> (UBig, UBig) {
+        bits.clear_high_bits(self.total_bits() - 1);
+        bits.split_bits(self.mantissa_bits as usize)
+    }
+
+    /// The biased exponent of infinities and NaNs
+    #[inline]
+    fn exp_mask(&self) -> UBig {
+        (UBig::ONE << self.exp_bits as usize) - UBig::ONE
+    }
+
+    /// Encode the sign bit and the biased exponent
+    #[inline]
+    fn encode_head(&self, sign: Sign, biased_exp: UBig) -> UBig {
+        let mut bits = biased_exp << self.mantissa_bits as usize;
+        if sign == Sign::Negative {
+            bits.set_bit(self.total_bits() - 1);
+        }
+        bits
+    }
+}
+
+impl<R: Round> FBig<R, 2> {
+    /// Encode the float number in an IEEE 754 binary interchange format.
+    ///
+    /// The number is rounded to the precision and the exponent range of the format with the
+    /// rounding mode of this number, and the subnormal numbers are rounded to their actual
+    /// precision. If the number overflows, the result is either the infinity or the largest
+    /// finite number, depending on the rounding mode. Zero is encoded as the positive zero
+    /// unless a negative number underflows to zero.
+    ///
+    /// To get the result rounded with the default rounding mode of IEEE 754, convert the number
+    /// to the [HalfEven][crate::round::mode::HalfEven] rounding mode first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::FBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{BinaryFormat, round::{mode::HalfEven, Rounding::*}};
+    ///
+    /// let a = FBig::<HalfEven>::from_str_native("-0x1.8p-1")?;
+    /// assert_eq!(a.to_bits(BinaryFormat::BINARY16), Exact(0xba00u16.into()));
+    /// assert_eq!(a.to_bits(BinaryFormat::BFLOAT16), Exact(0xbf40u16.into()));
+    ///
+    /// // 1 + 2^-11 is rounded to 1 in binary16
+    /// let b = FBig::<HalfEven>::from_str_native("0x1.002")?;
+    /// assert_eq!(b.to_bits(BinaryFormat::BINARY16), Inexact(0x3c00u16.into(), NoOp));
+    ///
+    /// // 65520 overflows in binary16
+    /// let c = FBig::<HalfEven>::from(65520);
+    /// assert_eq!(c.to_bits(BinaryFormat::BINARY16), Inexact(0x7c00u16.into(), AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn to_bits(&self, format: BinaryFormat) -> Rounded<UBig> {
+        if self.repr.is_infinite() {
+            let bits = format.encode_head(self.repr.sign(), format.exp_mask());
+            return Exact(bits);
+        }
+
+        let context = format.context::<R>();
+        context.repr_round_ref(&self.repr).map(|v| {
+            if v.is_infinite() {
+                format.encode_head(v.sign(), format.exp_mask())
+            } else if v.is_zero() {
+                format.encode_head(self.repr.sign(), UBig::ZERO)
+            } else {
+                let (sign, signif) = v.significand.into_parts();
+                let digits = signif.bit_len();
+                let top = v.exponent + digits as isize - 1;
+                let mantissa_bits = format.mantissa_bits as usize;
+                if top >= format.emin() {
+                    // normal number, remove the implicit leading bit
+                    let mut mantissa = signif << (mantissa_bits + 1 - digits);
+                    mantissa.clear_high_bits(mantissa_bits);
+                    let biased_exp = (top + format.emax()) as usize;
+                    format.encode_head(sign, UBig::from(biased_exp)) | mantissa
+                } else {
+                    // subnormal number, the exponent is at least emin - mantissa_bits
+                    let shift = v.exponent - format.emin() + mantissa_bits as isize;
+                    format.encode_head(sign, UBig::ZERO) | (signif << shift as usize)
+                }
+            }
+        })
+    }
+
+    /// Decode a float number from an IEEE 754 binary interchange format.
+    ///
+    /// The decoding is lossless, and the precision of the result is the precision of the format.
+    /// The negative zero is decoded as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [OutOfBoundsError] if the bits encode a NaN value (use [BinaryFormat::is_nan] to
+    /// detect this case), or the bits are wider than the total bits of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_float::FBig;
+    /// use dashu_float::{BinaryFormat, round::mode::HalfEven};
+    ///
+    /// let a: FBig = FBig::from_bits(&0xc100u16.into(), BinaryFormat::BINARY16).unwrap();
+    /// assert_eq!(a, -2.5);
+    /// assert_eq!(a.precision(), 11);
+    ///
+    /// // the smallest subnormal number in binary16
+    /// let b: FBig = FBig::from_bits(&1u8.into(), BinaryFormat::BINARY16).unwrap();
+    /// assert_eq!(b, 2f32.powi(-24));
+    ///
+    /// let inf: FBig = FBig::from_bits(&0x7f80u16.into(), BinaryFormat::BFLOAT16).unwrap();
+    /// assert_eq!(inf, f32::INFINITY);
+    /// let nan = FBig::<HalfEven>::from_bits(&0x7fc0u16.into(), BinaryFormat::BFLOAT16);
+    /// assert!(nan.is_err());
+    /// ```
+    pub fn from_bits(bits: &UBig, format: BinaryFormat) -> Result<Self, OutOfBoundsError> {
+        if bits.bit_len() > format.total_bits() {
+            return Err(OutOfBoundsError);
+        }
+        let sign = match bits.bit(format.total_bits() - 1) {
+            true => Sign::Negative,
+            false => Sign::Positive,
+        };
+        let (mantissa, biased_exp) = format.split(bits.clone());
+
+        let repr = if biased_exp == format.exp_mask() {
+            if !mantissa.is_zero() {
+                return Err(OutOfBoundsError); // nan
+            }
+            match sign {
+                Sign::Positive => Repr::infinity(),
+                Sign::Negative => Repr::neg_infinity(),
+            }
+        } else {
+            let mantissa_bits = format.mantissa_bits as isize;
+            let (signif, exponent) = if biased_exp.is_zero() {
+                (mantissa, format.emin() - mantissa_bits) // subnormal
+            } else {
+                let biased_exp: usize = biased_exp.try_into().unwrap();
+                let mut signif = mantissa;
+                signif.set_bit(format.mantissa_bits as usize);
+                (signif, biased_exp as isize - format.emax() - mantissa_bits)
+            };
+            Repr::new(IBig::from_parts(sign, signif), exponent)
+        };
+        Ok(Self::new(repr, Context::new(format.precision())))
+    }
+}
+
+/// An IEEE 754 decimal interchange format.
+///
+/// The decimal formats defined by the standard are provided as constants. A number in these
+/// formats is encoded as a sign bit, a combination field (containing the biased exponent and the
+/// leading bits or the leading digit of the coefficient) and a trailing significand field, which
+/// is encoded in one of the two schemes specified by [DecimalEncoding].
+///
+/// The numbers can be encoded to and decoded from the interchange formats using
+/// [FBig::to_decimal_bits] and [FBig::from_decimal_bits] respectively.
+///
+/// # Examples
+///
+/// ```
+/// use dashu_float::DecimalFormat;
+///
+/// let format = DecimalFormat::DECIMAL64;
+/// assert_eq!(format.total_bits(), 64);
+/// assert_eq!(format.precision(), 16);
+/// assert_eq!((format.emin(), format.emax()), (-383, 384));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalFormat {
+    total_bits: u32,
+}
+
+/// The encoding scheme of the coefficient in the IEEE 754 decimal interchange formats
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecimalEncoding {
+    /// The binary integer decimal encoding, where the coefficient is encoded as a binary integer
+    Bid,
+    /// The densely packed decimal encoding, where every three decimal digits of the coefficient
+    /// are encoded in a 10-bit declet
+    Dpd,
+}
+
+impl DecimalFormat {
+    /// The IEEE 754 decimal32 format
+    pub const DECIMAL32: Self = Self { total_bits: 32 };
+    /// The IEEE 754 decimal64 format
+    pub const DECIMAL64: Self = Self { total_bits: 64 };
+    /// The IEEE 754 decimal128 format
+    pub const DECIMAL128: Self = Self { total_bits: 128 };
+
+    /// Get the total number of bits of the encoding
+    #[inline]
+    pub const fn total_bits(&self) -> usize {
+        self.total_bits as usize
+    }
+
+    /// Get the precision (in decimal digits) of the format
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.total_bits() * 9 / 32 - 2
+    }
+
+    /// Get the maximum exponent of the finite numbers
+    #[inline]
+    pub const fn emax(&self) -> isize {
+        3 << (self.total_bits / 16 + 3)
+    }
+
+    /// Get the minimum exponent of the normal numbers
+    #[inline]
+    pub const fn emin(&self) -> isize {
+        1 - self.emax()
+    }
+
+    /// Create a [Context] with the precision and the exponent range of this format, so that
+    /// the operations under the context produce the same values as the IEEE 754 arithmetic
+    /// in this format.
+    #[inline]
+    pub const fn context<R: Round>(&self) -> Context<R> {
+        Context::new(self.precision()).with_exponent_range(self.emin(), self.emax())
+    }
+
+    /// Check whether the bits encode a NaN value in this format (with either encoding)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::DecimalFormat;
+    ///
+    /// assert!(DecimalFormat::DECIMAL32.is_nan(&0x7c000000u32.into()));
+    /// assert!(!DecimalFormat::DECIMAL32.is_nan(&0x78000000u32.into())); // infinity
+    /// ```
+    pub fn is_nan(&self, bits: &UBig) -> bool {
+        match u128::try_from(bits) {
+            Ok(bits) => self.combination(bits) == DECIMAL_NAN,
+            Err(_) => false,
+        }
+    }
+
+    /// The number of the exponent continuation bits in the combination field
+    #[inline]
+    const fn exp_cont_bits(&self) -> usize {
+        self.total_bits() / 16 + 4
+    }
+
+    /// The number of the trailing significand bits
+    #[inline]
+    const fn trailing_bits(&self) -> usize {
+        self.total_bits() * 15 / 16 - 10
+    }
+
+    /// The bias of the exponent of the integral coefficient
+    #[inline]
+    const fn bias(&self) -> isize {
+        self.emax() + self.precision() as isize - 2
+    }
+
+    /// Get the leading 5 bits of the combination field
+    #[inline]
+    fn combination(&self, bits: u128) -> u128 {
+        (bits >> (self.total_bits() - 6)) & 0b11111
+    }
+
+    /// Encode the number `(-1)^sign * coeff * 10^(biased_exp - bias)`
+    fn encode(&self, sign: Sign, coeff: u128, biased_exp: u128, encoding: DecimalEncoding) -> u128 {
+        let (w, t) = (self.exp_cont_bits(), self.trailing_bits());
+        let bits = match encoding {
+            DecimalEncoding::Bid => {
+                if coeff >> (t + 3) == 0 {
+                    biased_exp << (t + 3) | coeff
+                } else {
+                    // the coefficient has the form 0b100xxx.., and the leading bits are implicit
+                    0b11 << (w + t + 3) | biased_exp << (t + 1) | coeff & low_mask(t + 1)
+                }
+            }
+            DecimalEncoding::Dpd => {
+                let mut rest = coeff;
+                let mut trailing = 0;
+                for i in 0..t / 10 {
+                    trailing |= (encode_declet((rest % 1000) as u16) as u128) << (10 * i);
+                    rest /= 1000;
+                }
+                let (exp_hi, exp_lo) = (biased_exp >> w, biased_exp & low_mask(w));
+                let combination = if rest < 8 {
+                    exp_hi << 3 | rest
+                } else {
+                    0b11000 | exp_hi << 1 | (rest & 1)
+                };
+                combination << (w + t) | exp_lo << t | trailing
+            }
+        };
+        self.encode_sign(sign, bits)
+    }
+
+    /// Decode the coefficient and the biased exponent of a finite number
+    fn decode(&self, bits: u128, encoding: DecimalEncoding) -> (u128, u128) {
+        let (w, t) = (self.exp_cont_bits(), self.trailing_bits());
+        let combination = self.combination(bits);
+        match encoding {
+            DecimalEncoding::Bid => {
+                let (coeff, biased_exp) = if combination >> 3 == 0b11 {
+                    (bits & low_mask(t + 1) | 0b100 << (t + 1), (bits >> (t + 1)) & low_mask(w + 2))
+                } else {
+                    (bits & low_mask(t + 3), (bits >> (t + 3)) & low_mask(w + 2))
+                };
+                // non-canonical coefficients are interpreted as zero
+                match coeff < 10u128.pow(self.precision() as u32) {
+                    true => (coeff, biased_exp),
+                    false => (0, biased_exp),
+                }
+            }
+            DecimalEncoding::Dpd => {
+                let (exp_hi, mut coeff) = if combination >> 3 == 0b11 {
+                    ((combination >> 1) & 0b11, 8 | (combination & 1))
+                } else {
+                    (combination >> 3, combination & 0b111)
+                };
+                for i in (0..t / 10).rev() {
+                    let declet = (bits >> (10 * i)) & 0x3ff;
+                    coeff = coeff * 1000 + decode_declet(declet as u16) as u128;
+                }
+                (coeff, exp_hi << w | (bits >> t) & low_mask(w))
+            }
+        }
+    }
+
+    /// Encode the sign bit into the bits
+    #[inline]
+    fn encode_sign(&self, sign: Sign, bits: u128) -> u128 {
+        match sign {
+            Sign::Positive => bits,
+            Sign::Negative => bits | 1 << (self.total_bits() - 1),
+        }
+    }
+
+    /// Encode the infinity with the given sign
+    #[inline]
+    fn encode_inf(&self, sign: Sign) -> u128 {
+        self.encode_sign(sign, DECIMAL_INF << (self.total_bits() - 6))
+    }
+}
+
+/// Get a mask of the lowest `n` bits
+#[inline]
+const fn low_mask(n: usize) -> u128 {
+    (1 << n) - 1
+}
+
+/// Encode three decimal digits (`value` < 1000) into a declet of the densely packed decimal
+fn encode_declet(value: u16) -> u16 {
+    let (d2, d1, d0) = (value / 100, value / 10 % 10, value % 10);
+    let (d2_lo, d1_lo, d0_lo) = (d2 & 1, d1 & 1, d0 & 1);
+    match (d2 >= 8, d1 >= 8, d0 >= 8) {
+        (false, false, false) => d2 << 7 | d1 << 4 | d0,
+        (false, false, true) => d2 << 7 | d1 << 4 | 0b1000 | d0_lo,
+        (false, true, false) => d2 << 7 | (d0 & 0b110) << 4 | d1_lo << 4 | 0b1010 | d0_lo,
+        (true, false, false) => (d0 & 0b110) << 7 | d2_lo << 7 | d1 << 4 | 0b1100 | d0_lo,
+        (false, true, true) => d2 << 7 | 0b10 << 5 | d1_lo << 4 | 0b1110 | d0_lo,
+        (true, false, true) => {
+            (d1 & 0b110) << 7 | d2_lo << 7 | 0b01 << 5 | d1_lo << 4 | 0b1110 | d0_lo
+        }
+        (true, true, false) => (d0 & 0b110) << 7 | d2_lo << 7 | d1_lo << 4 | 0b1110 | d0_lo,
+        (true, true, true) => d2_lo << 7 | 0b11 << 5 | d1_lo << 4 | 0b1110 | d0_lo,
+    }
+}
+
+/// Decode a declet of the densely packed decimal encoding into three decimal digits
+fn decode_declet(declet: u16) -> u16 {
+    let (pqr, st, u, y) = (declet >> 7, (declet >> 5) & 0b11, (declet >> 4) & 1, declet & 1);
+    let (pq, r) = (pqr >> 1, pqr & 1);
+    let (stu, pq0) = (st << 1 | u, pq << 1);
+    let (d2, d1, d0) = if declet & 0b1000 == 0 {
+        (pqr, stu, declet & 0b111)
+    } else {
+        match ((declet >> 1) & 0b11, st) {
+            (0b00, _) => (pqr, stu, 8 | y),
+            (0b01, _) => (pqr, 8 | u, st << 1 | y),
+            (0b10, _) => (8 | r, stu, pq0 | y),
+            (_, 0b00) => (8 | r, 8 | u, pq0 | y),
+            (_, 0b01) => (8 | r, pq0 | u, 8 | y),
+            (_, 0b10) => (pqr, 8 | u, 8 | y),
+            (_, _) => (8 | r, 8 | u, 8 | y),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+impl<R: Round> FBig<R, 10> {
+    /// Encode the float number in an IEEE 754 decimal interchange format.
+    ///
+    /// The number is rounded to the precision and the exponent range of the format with the
+    /// rounding mode of this number, and the subnormal numbers are rounded to their actual
+    /// precision. If the number overflows, the result is either the infinity or the largest
+    /// finite number, depending on the rounding mode. Zero is encoded as the positive zero
+    /// with exponent zero, unless a negative number underflows to zero.
+    ///
+    /// Since [FBig] doesn't preserve the trailing zeros, the coefficient is encoded without
+    /// trailing zeros (i.e. with the largest possible exponent), unless the exponent would
+    /// exceed the maximum exponent of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{DecimalEncoding::*, DecimalFormat, round::Rounding::*};
+    ///
+    /// let a = DBig::from_str_native("-7.50")?;
+    /// assert_eq!(a.to_decimal_bits(DecimalFormat::DECIMAL32, Bid), Exact(0xb200004bu32.into()));
+    /// assert_eq!(a.to_decimal_bits(DecimalFormat::DECIMAL32, Dpd), Exact(0xa2400075u32.into()));
+    ///
+    /// // 1.23456789 is rounded to 7 digits in decimal32
+    /// let b = DBig::from_str_native("1.23456789")?;
+    /// assert_eq!(
+    ///     b.to_decimal_bits(DecimalFormat::DECIMAL32, Bid),
+    ///     Inexact(0x2f92d688u32.into(), AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn to_decimal_bits(
+        &self,
+        format: DecimalFormat,
+        encoding: DecimalEncoding,
+    ) -> Rounded<UBig> {
+        if self.repr.is_infinite() {
+            return Exact(format.encode_inf(self.repr.sign()).into());
+        }
+
+        let context = format.context::<R>();
+        context.repr_round_ref(&self.repr).map(|v| {
+            if v.is_infinite() {
+                return format.encode_inf(v.sign()).into();
+            }
+
+            let (sign, coeff, mut exponent) = if v.is_zero() {
+                (self.repr.sign(), 0, 0)
+            } else {
+                let (sign, coeff) = v.significand.into_parts();
+                (sign, coeff.try_into().unwrap(), v.exponent)
+            };
+            let mut coeff: u128 = coeff;
+            let exp_max = format.emax() - format.precision() as isize + 1;
+            if exponent > exp_max {
+                // the coefficient fits in the precision because the value is rounded
+                coeff *= 10u128.pow((exponent - exp_max) as u32);
+                exponent = exp_max;
+            }
+            let biased_exp = (exponent + format.bias()) as u128;
+            format.encode(sign, coeff, biased_exp, encoding).into()
+        })
+    }
+
+    /// Decode a float number from an IEEE 754 decimal interchange format.
+    ///
+    /// The decoding is lossless, and the precision of the result is the precision of the format.
+    /// The negative zero is decoded as zero. Following the standard, the non-canonical
+    /// coefficients in the BID encoding are decoded as zero, and the non-canonical declets
+    /// in the DPD encoding are decoded as their canonical counterparts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [OutOfBoundsError] if the bits encode a NaN value (use [DecimalFormat::is_nan] to
+    /// detect this case), or the bits are wider than the total bits of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::{DecimalEncoding::*, DecimalFormat};
+    ///
+    /// let d64 = DecimalFormat::DECIMAL64;
+    /// let one = DBig::from_decimal_bits(&0x31c0000000000001u64.into(), d64, Bid);
+    /// assert_eq!(one, Ok(DBig::ONE));
+    /// let one = DBig::from_decimal_bits(&0x2238000000000001u64.into(), d64, Dpd);
+    /// assert_eq!(one, Ok(DBig::ONE));
+    ///
+    /// let max = DBig::from_decimal_bits(&0x77fcff3fcff3fcffu64.into(), d64, Dpd);
+    /// assert_eq!(max.unwrap(), DBig::from_str_native("9.999999999999999e384")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn from_decimal_bits(
+        bits: &UBig,
+        format: DecimalFormat,
+        encoding: DecimalEncoding,
+    ) -> Result<Self, OutOfBoundsError> {
+        if bits.bit_len() > format.total_bits() {
+            return Err(OutOfBoundsError);
+        }
+        let bits: u128 = bits.try_into().unwrap();
+        let sign = match bits >> (format.total_bits() - 1) {
+            0 => Sign::Positive,
+            _ => Sign::Negative,
+        };
+
+        let repr = match format.combination(bits) {
+            DECIMAL_NAN => return Err(OutOfBoundsError),
+            DECIMAL_INF => match sign {
+                Sign::Positive => Repr::infinity(),
+                Sign::Negative => Repr::neg_infinity(),
+            },
+            _ => {
+                let (coeff, biased_exp) = format.decode(bits, encoding);
+                let exponent = biased_exp as isize - format.bias();
+                Repr::new(IBig::from_parts(sign, coeff.into()), exponent)
+            }
+        };
+        Ok(Self::new(repr, Context::new(format.precision())))
+    }
+}
@@ -0,0 +1,666 @@
+use crate::{
+    error::{
+        check_precision_limited, panic_divide_by_0, panic_invalid_interval, panic_log_non_positive,
+        panic_root_negative,
+    },
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{mode, Round, Rounded},
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use dashu_base::{Approximation::*, Sign};
+use dashu_int::IBig;
+
+type Lower<const B: Word> = FBig<mode::Down, B>;
+type Upper<const B: Word> = FBig<mode::Up, B>;
+
+/// A closed interval of real numbers with [FBig] endpoints, used for verified computation.
+///
+/// The lower endpoint is always rounded toward -∞ ([mode::Down]) and the upper endpoint is always
+/// rounded toward +∞ ([mode::Up]), so that the result of an operation always encloses all the
+/// possible exact results from the numbers in the operands. The precision of the result is the
+/// highest precision among the endpoints of the operands.
+///
+/// The endpoints can be infinite, which represents an unbounded interval. The lower endpoint is
+/// never +∞ and the upper endpoint is never -∞, and the intervals are never empty.
+///
+/// # Examples
+///
+/// ```
+/// # use dashu_int::error::ParseError;
+/// # use dashu_float::{DBig, FBig};
+/// use dashu_float::Interval;
+///
+/// let third = Interval::point(DBig::from_str_native("1.000")?) / Interval::point(DBig::from_str_native("3")?);
+/// assert_eq!(third.lo(), &DBig::from_str_native("0.3333")?);
+/// assert_eq!(third.hi(), &DBig::from_str_native("0.3334")?);
+/// assert!(third.contains(&DBig::from_str_native("0.33333333")?));
+///
+/// // the interval grows as the error accumulates
+/// let one = &third * Interval::point(DBig::from_str_native("3")?);
+/// assert_eq!(one.to_string(), "[0.9999, 1.001]");
+/// # Ok::<(), ParseError>(())
+/// ```
+///
+/// # Transcendental functions
+///
+/// The exponential and logarithm functions (and the integer power) are not guaranteed to be
+/// correctly rounded, so their inexact results are widened by one unit in the last place
+/// to keep the enclosure rigorous.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interval<const BASE: Word = 2> {
+    lo: Lower<BASE>,
+    hi: Upper<BASE>,
+}
+
+impl<const B: Word> Interval<B> {
+    /// Create an interval from the lower and upper endpoints.
+    ///
+    /// The endpoints are converted to the directed rounding modes without changing the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("-1.5")?, DBig::from_str_native("2")?);
+    /// assert_eq!(a.to_string(), "[-1.5, 2]");
+    /// assert!(a.contains_zero());
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`, or `lo` is +∞, or `hi` is -∞.
+    pub fn new<R1: Round, R2: Round>(lo: FBig<R1, B>, hi: FBig<R2, B>) -> Self {
+        let invalid_inf = (lo.repr.is_infinite() && lo.repr.sign() == Sign::Positive)
+            || (hi.repr.is_infinite() && hi.repr.sign() == Sign::Negative);
+        if invalid_inf || lo > hi {
+            panic_invalid_interval()
+        }
+        Self {
+            lo: lo.with_rounding(),
+            hi: hi.with_rounding(),
+        }
+    }
+
+    /// Create an interval containing a single number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is infinite.
+    #[inline]
+    pub fn point<R: Round>(x: FBig<R, B>) -> Self {
+        if x.repr.is_infinite() {
+            panic_invalid_interval()
+        }
+        Self {
+            lo: x.clone().with_rounding(),
+            hi: x.with_rounding(),
+        }
+    }
+
+    /// The interval containing all real numbers, i.e. `[-∞, +∞]`
+    #[inline]
+    pub const fn entire() -> Self {
+        Self {
+            lo: FBig::NEG_INFINITY,
+            hi: FBig::INFINITY,
+        }
+    }
+
+    /// Get the lower endpoint of the interval
+    #[inline]
+    pub const fn lo(&self) -> &FBig<mode::Down, B> {
+        &self.lo
+    }
+
+    /// Get the upper endpoint of the interval
+    #[inline]
+    pub const fn hi(&self) -> &FBig<mode::Up, B> {
+        &self.hi
+    }
+
+    /// Get the endpoints of the interval
+    #[inline]
+    pub fn into_parts(self) -> (FBig<mode::Down, B>, FBig<mode::Up, B>) {
+        (self.lo, self.hi)
+    }
+
+    /// Get the highest precision of the two endpoints
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.lo.precision().max(self.hi.precision())
+    }
+
+    /// Check whether both endpoints are finite
+    #[inline]
+    pub const fn is_bounded(&self) -> bool {
+        self.lo.repr.is_finite() && self.hi.repr.is_finite()
+    }
+
+    /// Check whether the interval contains exactly one number
+    #[inline]
+    pub fn is_point(&self) -> bool {
+        self.lo.repr == self.hi.repr
+    }
+
+    /// Check whether the number is in the interval
+    #[inline]
+    pub fn contains<R: Round>(&self, x: &FBig<R, B>) -> bool {
+        &self.lo <= x && x <= &self.hi
+    }
+
+    /// Check whether zero is in the interval
+    #[inline]
+    pub fn contains_zero(&self) -> bool {
+        let lo_le_0 = self.lo.repr.sign() == Sign::Negative || self.lo.repr.is_zero();
+        lo_le_0 && self.hi.repr.sign() == Sign::Positive
+    }
+
+    /// Check whether this interval is a subset of the other interval
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Get the width `hi - lo` of the interval, rounded toward +∞.
+    ///
+    /// The width is infinite if the interval is unbounded.
+    pub fn width(&self) -> FBig<mode::Up, B> {
+        if !self.is_bounded() {
+            return FBig::INFINITY;
+        }
+        let context = Context::<mode::Up>::new(self.precision());
+        context.sub(&self.hi.repr, &self.lo.repr).value()
+    }
+
+    /// Get the smallest interval containing both intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1")?, DBig::from_str_native("2")?);
+    /// let b = Interval::new(DBig::from_str_native("3")?, DBig::from_str_native("4")?);
+    /// assert_eq!(a.hull(&b).to_string(), "[1, 4]");
+    /// assert_eq!(a.intersection(&b), None);
+    /// assert_eq!(a.intersection(&a.hull(&b)), Some(a));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            lo: (&self.lo).min(&other.lo).clone(),
+            hi: (&self.hi).max(&other.hi).clone(),
+        }
+    }
+
+    /// Get the intersection of two intervals, returns [None] if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = (&self.lo).max(&other.lo);
+        let hi = (&self.hi).min(&other.hi);
+        if lo > hi {
+            None
+        } else {
+            Some(Self {
+                lo: lo.clone(),
+                hi: hi.clone(),
+            })
+        }
+    }
+
+    /// The contexts for the lower and upper endpoints of the result with the given operands
+    #[inline]
+    fn contexts(operands: &[&Self]) -> (Context<mode::Down>, Context<mode::Up>) {
+        let precision = operands.iter().map(|x| x.precision()).max().unwrap_or(0);
+        (Context::new(precision), Context::new(precision))
+    }
+
+    /// Divide the interval by an interval containing zero. The result can be
+    /// two disjoint intervals, which are returned in ascending order.
+    fn div_by_zero_interval(&self, rhs: &Self) -> (Self, Option<Self>) {
+        debug_assert!(rhs.contains_zero());
+        if rhs.lo.repr.is_zero() && rhs.hi.repr.is_zero() {
+            panic_divide_by_0()
+        }
+        if self.contains_zero() {
+            return (Self::entire(), None);
+        }
+
+        let (down, up) = Self::contexts(&[self, rhs]);
+        // the endpoint of self that is closest to zero
+        let near = if self.lo.repr.sign() == Sign::Positive {
+            &self.lo.repr
+        } else {
+            &self.hi.repr
+        };
+        let positive = near.sign() == Sign::Positive;
+
+        // the quotient near/rhs.lo bounds the part with negative divisors,
+        // and the quotient near/rhs.hi bounds the part with positive divisors
+        let neg_part = (!rhs.lo.repr.is_zero()).then(|| match positive {
+            true => Self {
+                lo: FBig::NEG_INFINITY,
+                hi: div_endpoint(&up, near, &rhs.lo.repr),
+            },
+            false => Self {
+                lo: div_endpoint(&down, near, &rhs.lo.repr),
+                hi: FBig::INFINITY,
+            },
+        });
+        let pos_part = (!rhs.hi.repr.is_zero()).then(|| match positive {
+            true => Self {
+                lo: div_endpoint(&down, near, &rhs.hi.repr),
+                hi: FBig::INFINITY,
+            },
+            false => Self {
+                lo: FBig::NEG_INFINITY,
+                hi: div_endpoint(&up, near, &rhs.hi.repr),
+            },
+        });
+
+        match (neg_part, pos_part) {
+            (Some(part), None) | (None, Some(part)) => (part, None),
+            (Some(neg), Some(pos)) if positive => (neg, Some(pos)),
+            (Some(neg), Some(pos)) => (pos, Some(neg)),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// Divide two intervals, and return the result as two disjoint intervals if the divisor
+    /// contains zero in its interior.
+    ///
+    /// The division operator `/` returns the hull of the two parts in this case, which can
+    /// be much wider than necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("1")?, DBig::from_str_native("2")?);
+    /// let b = Interval::new(DBig::from_str_native("-4")?, DBig::from_str_native("2")?);
+    /// let (lo, hi) = a.div_extended(&b);
+    /// assert_eq!(lo.to_string(), "[-inf, -0.2]");
+    /// assert_eq!(hi.unwrap().to_string(), "[0.5, inf]");
+    /// assert_eq!((&a / &b).to_string(), "[-inf, inf]");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is exactly zero, or the precision is unlimited.
+    pub fn div_extended(&self, rhs: &Self) -> (Self, Option<Self>) {
+        if rhs.contains_zero() {
+            return self.div_by_zero_interval(rhs);
+        }
+
+        let (down, up) = Self::contexts(&[self, rhs]);
+        check_precision_limited(down.precision);
+        let ends = [
+            (&self.lo.repr, &rhs.lo.repr),
+            (&self.lo.repr, &rhs.hi.repr),
+            (&self.hi.repr, &rhs.lo.repr),
+            (&self.hi.repr, &rhs.hi.repr),
+        ];
+        let lo = ends.iter().map(|(a, b)| div_endpoint(&down, a, b)).min();
+        let hi = ends.iter().map(|(a, b)| div_endpoint(&up, a, b)).max();
+        let result = Self {
+            lo: lo.unwrap(),
+            hi: hi.unwrap(),
+        };
+        (result, None)
+    }
+
+    /// Calculate the square root of the interval.
+    ///
+    /// The negative part of the interval is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is entirely negative, or the precision is unlimited.
+    pub fn sqrt(&self) -> Self {
+        if self.hi.repr.sign() == Sign::Negative && !self.hi.repr.is_zero() {
+            panic_root_negative()
+        }
+        let (down, up) = Self::contexts(&[self]);
+        let lo = if self.lo.repr.sign() == Sign::Negative || self.lo.repr.is_zero() {
+            FBig::new(Repr::zero(), down)
+        } else {
+            down.sqrt(&self.lo.repr).value()
+        };
+        let hi = if self.hi.repr.is_infinite() {
+            FBig::INFINITY
+        } else {
+            up.sqrt(&self.hi.repr).value()
+        };
+        Self { lo, hi }
+    }
+
+    /// Calculate the exponential function on the interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn exp(&self) -> Self {
+        let (down, up) = Self::contexts(&[self]);
+        check_precision_limited(down.precision);
+        let lo = if self.lo.repr.is_infinite() {
+            FBig::new(Repr::zero(), down)
+        } else {
+            widen_down(down.exp(&self.lo.repr))
+        };
+        let hi = if self.hi.repr.is_infinite() {
+            FBig::INFINITY
+        } else {
+            widen_up(up.exp(&self.hi.repr))
+        };
+        Self { lo, hi }
+    }
+
+    /// Calculate the natural logarithm on the interval.
+    ///
+    /// The non-positive part of the interval is ignored, so the lower endpoint is -∞
+    /// if the interval contains zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is entirely non-positive, or the precision is unlimited.
+    pub fn ln(&self) -> Self {
+        if self.hi.repr.sign() == Sign::Negative || self.hi.repr.is_zero() {
+            panic_log_non_positive()
+        }
+        let (down, up) = Self::contexts(&[self]);
+        check_precision_limited(down.precision);
+        let lo = if self.contains_zero() {
+            FBig::NEG_INFINITY
+        } else {
+            widen_down(down.ln(&self.lo.repr))
+        };
+        let hi = if self.hi.repr.is_infinite() {
+            FBig::INFINITY
+        } else {
+            widen_up(up.ln(&self.hi.repr))
+        };
+        Self { lo, hi }
+    }
+
+    /// Raise the interval to an integer power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_float::Interval;
+    ///
+    /// let a = Interval::new(DBig::from_str_native("-2.0")?, DBig::from_str_native("1.0")?);
+    /// assert_eq!(a.powi(2.into()).to_string(), "[0, 4]");
+    /// assert_eq!(a.powi(3.into()).to_string(), "[-8, 1]");
+    /// assert_eq!(a.powi((-2).into()).to_string(), "[0.25, inf]");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is negative and the interval is zero, or the precision is
+    /// unlimited when the exponent is negative.
+    pub fn powi(&self, exp: IBig) -> Self {
+        if exp.is_zero() {
+            return Self::point(FBig::<mode::Down, B>::ONE);
+        }
+        if exp.sign() == Sign::Negative {
+            let pow = self.powi(-exp);
+            let one = Self::point(FBig::<mode::Down, B>::ONE);
+            return one / pow;
+        }
+
+        let (down, up) = Self::contexts(&[self]);
+        let pow_down = |x: &Repr<B>| -> Lower<B> {
+            debug_assert!(x.sign() == Sign::Positive);
+            if x.is_infinite() {
+                FBig::INFINITY
+            } else {
+                widen_down(down.powi(x, exp.clone()))
+            }
+        };
+        let pow_up = |x: &Repr<B>| -> Upper<B> {
+            debug_assert!(x.sign() == Sign::Positive);
+            if x.is_infinite() {
+                FBig::INFINITY
+            } else {
+                widen_up(up.powi(x, exp.clone()))
+            }
+        };
+
+        let lo_neg = self.lo.repr.sign() == Sign::Negative;
+        let hi_neg = self.hi.repr.sign() == Sign::Negative;
+        let (lo_abs, hi_abs) = (self.lo.repr.clone().neg(), self.hi.repr.clone().neg());
+        if &exp & 1 == 1 {
+            // odd power is monotonically increasing
+            let lo = match lo_neg {
+                false => pow_down(&self.lo.repr),
+                true => (-pow_up(&lo_abs)).with_rounding(),
+            };
+            let hi = match hi_neg {
+                false => pow_up(&self.hi.repr),
+                true => (-pow_down(&hi_abs)).with_rounding(),
+            };
+            Self { lo, hi }
+        } else {
+            match (lo_neg, hi_neg) {
+                (false, _) => Self {
+                    lo: pow_down(&self.lo.repr),
+                    hi: pow_up(&self.hi.repr),
+                },
+                (true, true) => Self {
+                    lo: pow_down(&hi_abs),
+                    hi: pow_up(&lo_abs),
+                },
+                (true, false) => {
+                    let max_abs = if lo_abs > self.hi.repr {
+                        &lo_abs
+                    } else {
+                        &self.hi.repr
+                    };
+                    Self {
+                        lo: FBig::new(Repr::zero(), down),
+                        hi: pow_up(max_abs),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Widen an inexact result downward by one ulp
+fn widen_down<const B: Word>(x: Rounded<FBig<mode::Down, B>>) -> FBig<mode::Down, B> {
+    match x {
+        Exact(v) => v,
+        Inexact(v, _) => {
+            let ulp = v.ulp();
+            v - ulp
+        }
+    }
+}
+
+/// Widen an inexact result upward by one ulp
+pub(crate) fn widen_up<const B: Word>(x: Rounded<FBig<mode::Up, B>>) -> FBig<mode::Up, B> {
+    match x {
+        Exact(v) => v,
+        Inexact(v, _) => {
+            let ulp = v.ulp();
+            v + ulp
+        }
+    }
+}
+
+#[inline]
+fn infinity<const B: Word>(sign: Sign) -> Repr<B> {
+    match sign {
+        Sign::Positive => Repr::infinity(),
+        Sign::Negative => Repr::neg_infinity(),
+    }
+}
+
+/// Add two endpoints, they can't be infinities with different signs
+fn add_endpoint<R: Round, const B: Word>(
+    context: &Context<R>,
+    lhs: &Repr<B>,
+    rhs: &Repr<B>,
+) -> FBig<R, B> {
+    if lhs.is_infinite() {
+        FBig::new(lhs.clone(), *context)
+    } else if rhs.is_infinite() {
+        FBig::new(rhs.clone(), *context)
+    } else {
+        context.add(lhs, rhs).value()
+    }
+}
+
+/// Multiply two endpoints, zero times infinity is regarded as zero.
+fn mul_endpoint<R: Round, const B: Word>(
+    context: &Context<R>,
+    lhs: &Repr<B>,
+    rhs: &Repr<B>,
+) -> FBig<R, B> {
+    if lhs.is_zero() || rhs.is_zero() {
+        FBig::new(Repr::zero(), *context)
+    } else if lhs.is_infinite() || rhs.is_infinite() {
+        FBig::new(infinity(lhs.sign() * rhs.sign()), *context)
+    } else {
+        context.mul(lhs, rhs).value()
+    }
+}
+
+/// Divide two endpoints with a nonzero divisor, infinity divided by infinity is regarded as zero.
+fn div_endpoint<R: Round, const B: Word>(
+    context: &Context<R>,
+    lhs: &Repr<B>,
+    rhs: &Repr<B>,
+) -> FBig<R, B> {
+    debug_assert!(!rhs.is_zero());
+    if lhs.is_zero() || rhs.is_infinite() {
+        FBig::new(Repr::zero(), *context)
+    } else if lhs.is_infinite() {
+        FBig::new(infinity(lhs.sign() * rhs.sign()), *context)
+    } else {
+        context.div(lhs, rhs).value()
+    }
+}
+
+impl<const B: Word> Display for Interval<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl<const B: Word> Neg for Interval<B> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            lo: (-self.hi).with_rounding(),
+            hi: (-self.lo).with_rounding(),
+        }
+    }
+}
+
+impl<const B: Word> Neg for &Interval<B> {
+    type Output = Interval<B>;
+    #[inline]
+    fn neg(self) -> Interval<B> {
+        self.clone().neg()
+    }
+}
+
+fn add_ref_ref<const B: Word>(lhs: &Interval<B>, rhs: &Interval<B>) -> Interval<B> {
+    let (down, up) = Interval::contexts(&[lhs, rhs]);
+    Interval {
+        lo: add_endpoint(&down, &lhs.lo.repr, &rhs.lo.repr),
+        hi: add_endpoint(&up, &lhs.hi.repr, &rhs.hi.repr),
+    }
+}
+
+fn sub_ref_ref<const B: Word>(lhs: &Interval<B>, rhs: &Interval<B>) -> Interval<B> {
+    let (down, up) = Interval::contexts(&[lhs, rhs]);
+    let (rhs_lo, rhs_hi) = (rhs.hi.repr.clone().neg(), rhs.lo.repr.clone().neg());
+    Interval {
+        lo: add_endpoint(&down, &lhs.lo.repr, &rhs_lo),
+        hi: add_endpoint(&up, &lhs.hi.repr, &rhs_hi),
+    }
+}
+
+fn mul_ref_ref<const B: Word>(lhs: &Interval<B>, rhs: &Interval<B>) -> Interval<B> {
+    let (down, up) = Interval::contexts(&[lhs, rhs]);
+    let ends = [
+        (&lhs.lo.repr, &rhs.lo.repr),
+        (&lhs.lo.repr, &rhs.hi.repr),
+        (&lhs.hi.repr, &rhs.lo.repr),
+        (&lhs.hi.repr, &rhs.hi.repr),
+    ];
+    let lo = ends.iter().map(|(a, b)| mul_endpoint(&down, a, b)).min();
+    let hi = ends.iter().map(|(a, b)| mul_endpoint(&up, a, b)).max();
+    Interval {
+        lo: lo.unwrap(),
+        hi: hi.unwrap(),
+    }
+}
+
+fn div_ref_ref<const B: Word>(lhs: &Interval<B>, rhs: &Interval<B>) -> Interval<B> {
+    match lhs.div_extended(rhs) {
+        (result, None) => result,
+        (lo, Some(hi)) => lo.hull(&hi),
+    }
+}
+
+macro_rules! impl_interval_binop {
+    ($trait:ident, $method:ident, $impl:ident) => {
+        impl<const B: Word> $trait for Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: Interval<B>) -> Interval<B> {
+                $impl(&self, &rhs)
+            }
+        }
+
+        impl<'r, const B: Word> $trait<&'r Interval<B>> for Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: &Interval<B>) -> Interval<B> {
+                $impl(&self, rhs)
+            }
+        }
+
+        impl<'l, const B: Word> $trait<Interval<B>> for &'l Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: Interval<B>) -> Interval<B> {
+                $impl(self, &rhs)
+            }
+        }
+
+        impl<'l, 'r, const B: Word> $trait<&'r Interval<B>> for &'l Interval<B> {
+            type Output = Interval<B>;
+            #[inline]
+            fn $method(self, rhs: &Interval<B>) -> Interval<B> {
+                $impl(self, rhs)
+            }
+        }
+    };
+}
+
+impl_interval_binop!(Add, add, add_ref_ref);
+impl_interval_binop!(Sub, sub, sub_ref_ref);
+impl_interval_binop!(Mul, mul, mul_ref_ref);
+impl_interval_binop!(Div, div, div_ref_ref);
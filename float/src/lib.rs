@@ -59,29 +59,55 @@
 //! # Optional dependencies
 //!
 //! * `std` (*default*): enable `std` for dependencies.
+//! * `rand`: support the stochastic rounding mode `round::mode::Stochastic` based on crate `rand` (requires `std`).
+//! * `serde`: support serialization and deserialization based on crate `serde`. The numbers are serialized
+//!   as strings in the native base for human-readable formats, and as tuples of the significand, the exponent
+//!   and the precision for compact formats.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 mod add;
+mod ball;
 mod cmp;
+mod complex;
 mod convert;
+mod decimal;
 mod div;
+pub mod dynamic;
 mod error;
 mod exp;
 mod fbig;
+mod fixed;
 mod fmt;
+pub mod gda;
 mod helper_macros;
+mod interchange;
+mod interval;
 mod log;
 mod mul;
 mod parse;
 mod repr;
+mod root;
 pub mod round;
+#[cfg(feature = "serde")]
+mod serde;
 mod shift;
 mod sign;
+pub mod status;
+mod trig;
 mod utils;
 
+pub use ball::Ball;
+pub use complex::CBig;
+pub use decimal::Decimal;
 pub use fbig::FBig;
-pub use repr::{Context, Repr};
+pub use fixed::FixedI;
+pub use fmt::EngineeringNotation;
+pub use interchange::{BinaryFormat, DecimalEncoding, DecimalFormat};
+pub use interval::Interval;
+pub use repr::{Context, RangeStatus, Repr};
 
 /// Multi-precision float number with decimal exponent and [HalfAway][round::mode::HalfAway] rounding mode
 pub type DBig = FBig<round::mode::HalfAway, 10>;
@@ -157,10 +157,11 @@ impl<R: Round> Context<R> {
         let mut k: usize = 3;
         loop {
             pow *= &inv2;
-            let next = &sum + &pow / work_context.convert_int::<B>(k.into()).value();
+            let term = &pow / work_context.convert_int::<B>(k.into()).value();
+            let next = &sum + &term;
 
-            if next == sum {
-                return sum;
+            if next == sum || term.is_below_ulp_of(&next) {
+                return next;
             }
             sum = next;
             k += 2;
@@ -210,6 +211,10 @@ impl<R: Round> Context<R> {
     fn ln_internal<const B: Word>(&self, x: &Repr<B>, one_plus: bool) -> Rounded<FBig<R, B>> {
         check_inf(x);
         check_precision_limited(self.precision);
+        if self.exponent_range().is_some() {
+            // evaluate with unbounded exponent, then fit the result into the range
+            return self.eval_unbounded(|ctx| ctx.ln_internal(x, one_plus));
+        }
 
         if (one_plus && x.is_zero()) || (!one_plus && x.is_one()) {
             return Exact(FBig::ZERO);
@@ -269,9 +274,11 @@ impl<R: Round> Context<R> {
         let mut k: usize = 3;
         loop {
             pow *= &z2;
-            let next = &sum + &pow / work_context.convert_int::<B>(k.into()).value();
+            let term = &pow / work_context.convert_int::<B>(k.into()).value();
+            let next = &sum + &term;
 
-            if next == sum {
+            if next == sum || term.is_below_ulp_of(&next) {
+                sum = next;
                 break;
             }
             sum = next;
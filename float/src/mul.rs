@@ -1,13 +1,19 @@
+use dashu_base::Approximation::Inexact;
 use dashu_int::{IBig, UBig};
 
 use crate::{
-    error::{check_inf, check_inf_operands},
+    add::ExactSum,
+    error::{check_inf, check_inf_operands, panic_length_mismatch},
     fbig::FBig,
     helper_macros,
     repr::{Context, Repr, Word},
     round::{Round, Rounded},
+    utils::split_digits_ref,
+};
+use core::{
+    iter::Product,
+    ops::{Mul, MulAssign},
 };
-use core::ops::{Mul, MulAssign};
 
 impl<'l, 'r, R: Round, const B: Word> Mul<&'r FBig<R, B>> for &'l FBig<R, B> {
     type Output = FBig<R, B>;
@@ -83,11 +89,45 @@ macro_rules! impl_add_sub_primitive_with_fbig {
 }
 impl_add_sub_primitive_with_fbig!(u8 u16 u32 u64 u128 usize UBig i8 i16 i32 i64 i128 isize IBig);
 
+impl<R: Round, const B: Word> Product for FBig<R, B> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(FBig::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, R: Round, const B: Word> Product<&'a FBig<R, B>> for FBig<R, B> {
+    fn product<I: Iterator<Item = &'a FBig<R, B>>>(iter: I) -> Self {
+        iter.fold(FBig::ONE, |acc, x| acc * x)
+    }
+}
+
 impl<R: Round, const B: Word> FBig<R, B> {
     #[inline]
     pub fn square(&self) -> Self {
         self.context.square(&self.repr).value()
     }
+
+    /// Calculate the fused multiply-add `self * a + b` with only one rounding.
+    ///
+    /// The result has the highest precision among the three operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.11")?;
+    /// let b = DBig::from_str_native("-1.23")?;
+    /// // 1.11 * 1.11 - 1.23 = 0.0021, which is rounded to 0 by a separate multiplication
+    /// assert_eq!(&a * &a + &b, DBig::ZERO);
+    /// assert_eq!(a.mul_add(&a, &b), DBig::from_str_native("0.0021")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn mul_add(&self, a: &Self, b: &Self) -> Self {
+        let context = Context::max(Context::max(self.context, a.context), b.context);
+        context.fma(&self.repr, &a.repr, &b.repr).value()
+    }
 }
 
 impl<R: Round> Context<R> {
@@ -113,35 +153,84 @@ impl<R: Round> Context<R> {
     pub fn mul<const B: Word>(&self, lhs: &Repr<B>, rhs: &Repr<B>) -> Rounded<FBig<R, B>> {
         check_inf_operands(lhs, rhs);
 
-        // at most double the precision is required to get a correct result
-        // shrink the input operands if necessary
-        let max_precision = if self.is_limited() {
-            self.precision * 2
-        } else {
-            usize::MAX
-        };
-
-        let lhs_shrink;
-        let lhs_repr = if lhs.digits() > max_precision {
-            lhs_shrink = Context::<R>::new(max_precision).repr_round_ref(lhs).value();
-            &lhs_shrink
-        } else {
-            lhs
-        };
-
-        let rhs_shrink;
-        let rhs_repr = if rhs.digits() > max_precision {
-            rhs_shrink = Context::<R>::new(max_precision).repr_round_ref(rhs).value();
-            &rhs_shrink
-        } else {
-            rhs
-        };
+        let repr = self.repr_round_mul(lhs, rhs, |l, r| &l.significand * &r.significand);
+        repr.map(|v| FBig::new(v, *self))
+    }
 
-        let repr = Repr::new(
-            &lhs_repr.significand * &rhs_repr.significand,
-            lhs_repr.exponent + rhs_repr.exponent,
-        );
-        self.repr_round(repr).map(|v| FBig::new(v, *self))
+    /// Calculate the fused multiply-add `a * b + c` under this context.
+    ///
+    /// The product is evaluated exactly and only the final sum is rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// let b = DBig::from_str_native("-1.5")?;
+    /// assert_eq!(
+    ///     context.fma(&a.repr(), &a.repr(), &b.repr()),
+    ///     Inexact(DBig::from_str_native("0.023")?, AddOne)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn fma<const B: Word>(&self, a: &Repr<B>, b: &Repr<B>, c: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf_operands(a, b);
+        check_inf(c);
+
+        let product = Repr::new(&a.significand * &b.significand, a.exponent + b.exponent);
+        let sum = self.repr_add_sticky(&product, c);
+        self.repr_round(sum).map(|v| FBig::new(v, *self))
+    }
+
+    /// Calculate the dot product of two sequences of floating point numbers under this context.
+    ///
+    /// The products and their sum are evaluated exactly and the result is rounded only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = [DBig::from_str_native("1.11")?, DBig::from_str_native("1.23")?];
+    /// let b = [DBig::from_str_native("1.11")?, DBig::from_str_native("-1")?];
+    /// assert_eq!(
+    ///     context.dot(a.iter().map(DBig::repr), b.iter().map(DBig::repr)),
+    ///     Exact(DBig::from_str_native("0.0021")?)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two sequences have different lengths or any of the numbers is infinite.
+    pub fn dot<'a, 'b, const B: Word, L, T>(&self, lhs: L, rhs: T) -> Rounded<FBig<R, B>>
+    where
+        L: IntoIterator<Item = &'a Repr<B>>,
+        T: IntoIterator<Item = &'b Repr<B>>,
+    {
+        let mut acc = ExactSum::new();
+        let mut lhs = lhs.into_iter();
+        let mut rhs = rhs.into_iter();
+        loop {
+            match (lhs.next(), rhs.next()) {
+                (Some(a), Some(b)) => {
+                    check_inf_operands(a, b);
+                    acc.add_parts(&a.significand * &b.significand, a.exponent + b.exponent);
+                }
+                (None, None) => break,
+                _ => panic_length_mismatch(),
+            }
+        }
+        acc.round(self).map(|v| FBig::new(v, *self))
     }
 
     /// Calculate the square of the floating point number under this context.
@@ -162,22 +251,71 @@ impl<R: Round> Context<R> {
     pub fn square<const B: Word>(&self, f: &Repr<B>) -> Rounded<FBig<R, B>> {
         check_inf(f);
 
-        // shrink the input operands if necessary
-        let max_precision = if self.is_limited() {
-            self.precision * 2
-        } else {
-            usize::MAX
-        };
-
-        let f_shrink;
-        let f_repr = if f.digits() > max_precision {
-            f_shrink = Context::<R>::new(max_precision).repr_round_ref(f).value();
-            &f_shrink
-        } else {
-            f
-        };
-
-        let repr = Repr::new(f_repr.significand.square(), 2 * f_repr.exponent);
-        self.repr_round(repr).map(|v| FBig::new(v, *self))
+        let repr = self.repr_round_mul(f, f, |f, _| f.significand.square());
+        repr.map(|v| FBig::new(v, *self))
+    }
+
+    /// Round the product of two floating point numbers to this context, where the exact
+    /// product of the significands is calculated by `exact_mul`.
+    ///
+    /// When the operands are much longer than the precision, they are truncated to a few more
+    /// digits than the precision first. The product of the truncated operands and the product
+    /// of the truncated operands incremented by one unit bound the exact product from both sides,
+    /// so if both of them are rounded to the same result, it's also the rounding of the exact
+    /// product. Otherwise the truncation is retried with more digits. This avoids both the cost
+    /// of the full product and the double rounding caused by rounding the operands.
+    fn repr_round_mul<const B: Word, F>(
+        &self,
+        lhs: &Repr<B>,
+        rhs: &Repr<B>,
+        exact_mul: F,
+    ) -> Rounded<Repr<B>>
+    where
+        F: FnOnce(&Repr<B>, &Repr<B>) -> IBig,
+    {
+        if self.is_limited() && !lhs.is_zero() && !rhs.is_zero() {
+            let (ldigits, rdigits) = (lhs.digits(), rhs.digits());
+            let mut keep = self.precision + 2 + Word::BITS as usize;
+            while keep < ldigits.max(rdigits) {
+                let (lsig, lexp, ltrunc) = truncate_digits(lhs, ldigits, keep);
+                let (rsig, rexp, rtrunc) = truncate_digits(rhs, rdigits, keep);
+                let lower = Repr::new(&lsig * &rsig, lexp + rexp);
+                let lsig = if ltrunc {
+                    lsig + lhs.significand.signum()
+                } else {
+                    lsig
+                };
+                let rsig = if rtrunc {
+                    rsig + rhs.significand.signum()
+                } else {
+                    rsig
+                };
+                let upper = Repr::new(lsig * rsig, lexp + rexp);
+
+                let lower = self.repr_round(lower);
+                if matches!(lower, Inexact(..)) && lower == self.repr_round(upper) {
+                    return lower;
+                }
+                keep *= 2;
+            }
+        }
+
+        let repr = Repr::new(exact_mul(lhs, rhs), lhs.exponent + rhs.exponent);
+        self.repr_round(repr)
+    }
+}
+
+/// Truncate the significand of the repr (with `digits` digits) to at most `keep` digits.
+/// Return the truncated significand, its exponent, and whether any nonzero digits are removed.
+fn truncate_digits<const B: Word>(
+    repr: &Repr<B>,
+    digits: usize,
+    keep: usize,
+) -> (IBig, isize, bool) {
+    if digits <= keep {
+        return (repr.significand.clone(), repr.exponent, false);
     }
+    let shift = digits - keep;
+    let (hi, lo) = split_digits_ref::<B>(&repr.significand, shift);
+    (hi, repr.exponent + shift as isize, !lo.is_zero())
 }
@@ -1,14 +1,14 @@
 use crate::{
     fbig::FBig,
     repr::{Context, Repr, Word},
-    round::Round,
+    round::{mode, Round, Rounded, Rounding},
 };
 use core::{num::IntErrorKind, str::FromStr};
-use dashu_base::Sign;
+use dashu_base::{Approximation::Inexact, BitTest, EstimatedLog2, Sign, UnsignedAbs};
 use dashu_int::{
     error::ParseError,
     fmt::{MAX_RADIX, MIN_RADIX},
-    UBig,
+    IBig, UBig,
 };
 
 impl<R: Round, const B: Word> FBig<R, B> {
@@ -35,18 +35,22 @@ impl<R: Round, const B: Word> FBig<R, B> {
     /// 1. `aaa.bbbEcc` = `aaabbb * 10 ^ (cc - len(bbb))`
     ///     * `E` could be lower case, base `B` must be 10
     ///     * `aaa` and `bbb` are all represented in decimal
-    /// 1. `0xaaa` or `0xaaa`
+    /// 1. `0xaaa` or `0xaaa.`
     /// 1. `0xaaa.bbb` = `0xaaabbb / 16 ^ len(bbb)`
     /// 1. `0xaaa.bbbPcc` = `0xaaabbb / 16 ^ len(bbb) * 2 ^ cc`
     ///     * `P` could be lower case, base `B` must be 2 (not 16!)
     ///     * `aaa` and `bbb` are represented in hexadecimal
-    ///     * This is consistent with the [C++ hexadecimal literals](https://en.cppreference.com/w/cpp/language/floating_literal).
+    ///     * This is consistent with the [C++ hexadecimal literals](https://en.cppreference.com/w/cpp/language/floating_literal),
+    ///       and it's the format printed by the [LowerHex][core::fmt::LowerHex] and [UpperHex][core::fmt::UpperHex]
+    ///       implementations of [FBig] with base 2.
     /// 1. `aaa.bbbBcc` = `aaabbb * 2 ^ (cc - len(bbb))`
     /// 1. `aaa.bbbOcc` = `aaabbb * 8 ^ (cc - len(bbb))`
     /// 1. `aaa.bbbHcc` = `aaabbb * 16 ^ (cc - len(bbb))`
     ///     * `B`/`O`/`H` could be lower case, and base `B` must be consistent with the marker.
     ///     * `aaa` and `bbb` are represented in binary/octal/hexadecimal correspondingly without prefix.
-    ///     * This is consistent with some scientific notations described in [Wikipedia](https://en.wikipedia.org/wiki/Scientific_notation#Other_bases).
+    ///     * This is consistent with some scientific notations described in [Wikipedia](https://en.wikipedia.org/wiki/Scientific_notation#Other_bases),
+    ///       and it's the format printed by the [Binary][core::fmt::Binary] and [Octal][core::fmt::Octal]
+    ///       implementations of [FBig] with base 2 and 8 respectively.
     ///
     /// Digits 10-35 are represented by `a-z` or `A-Z`.
     ///
@@ -82,131 +86,417 @@ impl<R: Round, const B: Word> FBig<R, B> {
     ///
     /// Panics if the base `B` is not between [MIN_RADIX] and [MAX_RADIX] inclusive.
     ///
-    pub fn from_str_native(mut src: &str) -> Result<Self, ParseError> {
+    pub fn from_str_native(src: &str) -> Result<Self, ParseError> {
         assert!(MIN_RADIX as Word <= B && B <= MAX_RADIX as Word);
+        let (significand, exponent, ndigits) = parse_parts(src, B)?;
+        let repr = Repr::new(significand, exponent);
+        Ok(Self {
+            repr,
+            context: Context::new(ndigits),
+        })
+    }
 
-        // parse and remove the sign
-        let sign = match src.strip_prefix('-') {
-            Some(s) => {
-                src = s;
-                Sign::Negative
-            }
-            None => {
-                src = src.strip_prefix('+').unwrap_or(src);
-                Sign::Positive
+    /// Convert a string in the given radix to [FBig], with a single rounding.
+    ///
+    /// The accepted formats are the same as [from_str_native][Self::from_str_native] with `B`
+    /// replaced by `radix`. For example, the scale marker `e` is used for radix 10, and `@`
+    /// is accepted for any radix. If the radix is the same as `B`, this function is equivalent
+    /// to [from_str_native][Self::from_str_native].
+    ///
+    /// Otherwise the precision of the result is the minimal precision that is able to
+    /// hold the same number of significant digits as the input string (under radix `radix`),
+    /// and the parsed number is correctly rounded to that precision. To parse with a specific
+    /// precision, use [Context::parse_radix].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{FBig, DBig};
+    /// type FBin = FBig;
+    ///
+    /// let a = FBin::from_str_radix("0.1", 10)?;
+    /// assert_eq!(a.precision(), 7); // 2 decimal digits need 7 bits
+    /// assert_eq!(a, FBin::from_str_native("0x66p-10")?); // rounded toward zero
+    ///
+    /// let b = FBin::from_str_radix("-12.5", 10)?;
+    /// assert_eq!(b, FBin::from_str_native("-0x19p-1")?);
+    ///
+    /// let c = DBig::from_str_radix("101.1", 2)?;
+    /// assert_eq!(c, DBig::from_str_native("5.5")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base `B` or the radix is not between [MIN_RADIX] and [MAX_RADIX] inclusive.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        if radix == B as u32 {
+            return Self::from_str_native(src);
+        }
+        assert!(MIN_RADIX as Word <= B && B <= MAX_RADIX as Word);
+        assert!((MIN_RADIX..=MAX_RADIX).contains(&radix));
+
+        let radix = radix as Word;
+        let (significand, exponent, ndigits) = parse_parts(src, radix)?;
+        // precision = ceil(ndigits * log(radix) / log(B))
+        let digits = ndigits as f32 * radix.log2_bounds().1 / B.log2_bounds().0;
+        let mut precision = digits as usize;
+        if (precision as f32) < digits {
+            precision += 1;
+        }
+        let context = Context::new(precision);
+        Ok(context.convert_parts(significand, exponent, radix).value())
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Parse a decimal string into a [FBig] with base `B`, rounded once to this context.
+    ///
+    /// This is equivalent to `self.parse_radix(src, 10)`. See [parse_radix][Self::parse_radix]
+    /// for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{Context, FBig, round::mode::HalfEven};
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let context = Context::<HalfEven>::new(53);
+    /// let a: FBig<HalfEven> = context.parse("0.1")?.value();
+    /// assert_eq!(a, FBig::<HalfEven>::try_from(0.1f64).unwrap());
+    /// assert_eq!(a.precision(), 53);
+    ///
+    /// let b = context.parse::<2>("1.5")?;
+    /// assert_eq!(b, Exact(FBig::from_parts(3.into(), -1)));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn parse<const B: Word>(&self, src: &str) -> Result<Rounded<FBig<R, B>>, ParseError> {
+        self.parse_radix(src, 10)
+    }
+
+    /// Parse a string in the given radix into a [FBig] with base `B`, rounded once to this context.
+    ///
+    /// The accepted formats are the same as [FBig::from_str_native] with `B` replaced by `radix`.
+    /// The parsed number is correctly rounded to the precision (and the exponent range) of this
+    /// context, regardless of the length of the input, so it avoids the double rounding when the
+    /// string is parsed in its own radix first and then converted with [FBig::with_base].
+    ///
+    /// A large exponent in the string doesn't require computing the exact power of the radix,
+    /// unless the number is extremely close to a rounding boundary. If the number is beyond the
+    /// exponent range of this context (or the range of [isize] when the exponent is not limited),
+    /// it's rounded as an overflowed or underflowed number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::{Context, DBig, FBig, round::mode::HalfEven};
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::round::Rounding::*;
+    ///
+    /// let context = Context::<HalfEven>::new(4);
+    /// assert_eq!(
+    ///     context.parse_radix::<10>("0.101b-2", 2)?,
+    ///     Inexact(DBig::from_str_native("0.1562")?.with_rounding(), NoOp) // 0.15625
+    /// );
+    ///
+    /// // a long input is still rounded correctly
+    /// let long = format!("1.{}1", "0".repeat(1000));
+    /// let context = Context::<HalfEven>::new(10);
+    /// assert_eq!(context.parse::<2>(&long)?, Inexact(FBig::ONE, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base `B` or the radix is not between [MIN_RADIX] and [MAX_RADIX] inclusive,
+    /// or if the precision is unlimited and the number can't be represented exactly in base `B`.
+    pub fn parse_radix<const B: Word>(
+        &self,
+        src: &str,
+        radix: u32,
+    ) -> Result<Rounded<FBig<R, B>>, ParseError> {
+        assert!(MIN_RADIX as Word <= B && B <= MAX_RADIX as Word);
+        assert!((MIN_RADIX..=MAX_RADIX).contains(&radix));
+
+        let radix = radix as Word;
+        let (significand, exponent, _) = parse_parts(src, radix)?;
+        Ok(self.convert_parts(significand, exponent, radix))
+    }
+
+    /// Convert `significand * radix ^ exponent` to a float number with base `B`
+    fn convert_parts<const B: Word>(
+        &self,
+        significand: IBig,
+        exponent: isize,
+        radix: Word,
+    ) -> Rounded<FBig<R, B>> {
+        if radix == B || significand.is_zero() || exponent == 0 {
+            let repr = self.repr_round(Repr::new(significand, exponent));
+            return repr.map(|v| FBig::new(v, *self));
+        }
+
+        if self.is_limited() {
+            if let Some(repr) = self.convert_parts_bounded(&significand, exponent, radix) {
+                return repr.map(|v| FBig::new(v, *self));
             }
+        }
+
+        let repr = if exponent >= 0 {
+            let scale = UBig::from_word(radix).pow(exponent as usize);
+            self.repr_round(Repr::new(significand * scale, 0))
+        } else {
+            let den = IBig::from(UBig::from_word(radix).pow(-exponent as usize));
+            self.repr_round_ratio(significand, &den, 0)
         };
+        repr.map(|v| FBig::new(v, *self))
+    }
 
-        // determine the position of scale markers
-        let has_prefix = src.starts_with("0x") || src.starts_with("0X");
-        let scale_pos = match B {
-            10 => src.rfind(&['e', 'E', '@']),
-            2 => {
-                if has_prefix {
-                    src.rfind(&['p', 'P', '@'])
-                } else {
-                    src.rfind(&['b', 'B', '@'])
-                }
-            }
-            8 => src.rfind(&['o', 'O', '@']),
-            16 => src.rfind(&['h', 'H', '@']),
-            _ => src.rfind('@'),
+    /// Convert `significand * radix ^ exponent` (with nonzero significand and exponent) to a float
+    /// number with base `B`, without calculating the exact power of the radix.
+    ///
+    /// The magnitude of the number is estimated first, so that an overflowed or underflowed number is
+    /// decided immediately. Otherwise, the power is evaluated with a few guard digits and directed
+    /// roundings, which gives a lower bound and an upper bound of the number. If both bounds are rounded
+    /// to the same result, it's the correct rounding, otherwise the guard digits are increased.
+    ///
+    /// Returns [None] if the power is not long enough to benefit from this method, or the number
+    /// is too close to a rounding boundary and the exact evaluation is necessary.
+    fn convert_parts_bounded<const B: Word>(
+        &self,
+        significand: &IBig,
+        exponent: isize,
+        radix: Word,
+    ) -> Option<Rounded<Repr<B>>> {
+        let sign = significand.sign();
+
+        // estimate log_B(|significand * radix ^ exponent|) with a safety margin
+        let (sig_lb, sig_ub) = significand.log2_bounds();
+        let (radix_lb, radix_ub) = radix.log2_bounds();
+        let (base_lb, base_ub) = B.log2_bounds();
+        let exp = exponent as f64;
+        let (exp_lb, exp_ub) = if exponent > 0 {
+            (exp * radix_lb as f64, exp * radix_ub as f64)
+        } else {
+            (exp * radix_ub as f64, exp * radix_lb as f64)
         };
+        let margin = 2. + exp.abs() * 1e-9;
+        let (mut log_lb, mut log_ub) = (sig_lb as f64 + exp_lb, sig_ub as f64 + exp_ub);
+        log_lb = if log_lb > 0. {
+            log_lb / base_ub as f64
+        } else {
+            log_lb / base_lb as f64
+        } - margin;
+        log_ub = if log_ub > 0. {
+            log_ub / base_lb as f64
+        } else {
+            log_ub / base_ub as f64
+        } + margin;
 
-        // parse scale and remove the scale part from the str
-        let (scale, pmarker) = if let Some(pos) = scale_pos {
-            let value = match (&src[pos + 1..]).parse::<isize>() {
-                Err(e) => match e.kind() {
-                    IntErrorKind::Empty => return Err(ParseError::NoDigits),
-                    _ => return Err(ParseError::InvalidDigit),
+        // the number is far beyond the exponent range (or the range of the exponent type),
+        // then any value in the same direction gives the same result
+        let (emin, emax) = self
+            .exponent_range()
+            .unwrap_or((isize::MIN / 4, isize::MAX / 4));
+        let qmin = emin - self.precision as isize + 1;
+        if log_lb > emax as f64 + 1. {
+            return Some(match self.exponent_range() {
+                Some(_) => self.repr_round(Repr::new(sign * IBig::ONE, emax + 1)),
+                None => match sign {
+                    Sign::Positive => Inexact(Repr::infinity(), Rounding::AddOne),
+                    Sign::Negative => Inexact(Repr::neg_infinity(), Rounding::SubOne),
                 },
-                Ok(v) => v,
-            };
-            let use_p = if B == 2 {
-                src.as_bytes().get(pos) == Some(&b'p') || src.as_bytes().get(pos) == Some(&b'P')
-            } else {
-                false
-            };
-            src = &src[..pos];
-            (value, use_p)
+            });
+        }
+        if log_ub < qmin as f64 - 1. {
+            return Some(match self.exponent_range() {
+                Some(_) => self.repr_round(Repr::new(sign * IBig::ONE, qmin - 2)),
+                None => Inexact(Repr::zero(), Rounding::NoOp),
+            });
+        }
+
+        // the number of digits of the power in base B
+        let n = exponent.unsigned_abs();
+        let pow_digits = (exp_ub.abs() / base_lb as f64) as usize;
+        let sig_digits = (sig_ub / base_lb) as usize + 1;
+        let exact_cost = if exponent > 0 {
+            pow_digits + sig_digits
         } else {
-            (0, false)
+            pow_digits
         };
 
-        // parse the body of the float number
-        let mut exponent = scale;
-        let ndigits;
-        let significand = if let Some(dot) = src.find('.') {
-            // check whether both integral part and fractional part are empty
-            if src.len() == 1 {
-                return Err(ParseError::NoDigits);
-            }
+        let radix = Repr::<B>::new(IBig::from(radix), 0);
+        let magnitude = Repr::<B>::new(significand.unsigned_abs().into(), 0);
+        let mut guard = n.bit_len() + Word::BITS as usize;
+        while self.precision + guard < exact_cost {
+            let down = Context::<mode::Down>::new(self.precision + guard);
+            let up = Context::<mode::Up>::new(self.precision + guard);
+            let (pow_lo, pow_hi) = (pow_bound(&down, &radix, n), pow_bound(&up, &radix, n));
 
-            // parse integral part
-            let (int, int_digits, base) = if dot != 0 {
-                let int_str = &src[..dot];
-                if B == 2 && has_prefix {
-                    // only base 2 float is allowed using prefix
-                    let int_str = &int_str[2..];
-                    let digits = 4 * (int_str.len() - int_str.matches('_').count());
-                    if int_str.is_empty() {
-                        (UBig::ZERO, digits, 16)
-                    } else {
-                        (UBig::from_str_radix(int_str, 16)?, digits, 16)
-                    }
-                } else if B == 2 && pmarker && !has_prefix {
-                    return Err(ParseError::UnsupportedRadix);
-                } else {
-                    let digits = int_str.len() - int_str.matches('_').count();
-                    (UBig::from_str_radix(&src[..dot], B as u32)?, digits, B as u32)
-                }
+            // bound the magnitude of the number, then apply the sign to the bounds
+            let (lo, hi) = if exponent > 0 {
+                (down.mul(&magnitude, &pow_lo), up.mul(&magnitude, &pow_hi))
             } else {
-                if pmarker {
-                    // prefix is required for using `p` as scale marker
-                    return Err(ParseError::UnsupportedRadix);
-                }
-                (UBig::ZERO, 0, B as u32)
+                (down.div(&magnitude, &pow_hi), up.div(&magnitude, &pow_lo))
             };
+            let (mut lo, mut hi) = (lo.value().repr, hi.value().repr);
+            if sign == Sign::Negative {
+                lo = -lo;
+                hi = -hi;
+            }
 
-            // parse fractional part
-            src = &src[dot + 1..];
-            let (fract, fract_digits) = if !src.is_empty() {
-                let mut digits = src.len() - src.matches('_').count();
-                if B == 2 && base == 16 {
-                    digits *= 4;
-                }
-                (UBig::from_str_radix(src, base)?, digits)
-            } else {
-                (UBig::ZERO, 0)
-            };
-            ndigits = int_digits + fract_digits;
+            let lo = self.repr_round(lo);
+            if matches!(lo, Inexact(..)) && lo == self.repr_round(hi) {
+                return Some(lo);
+            }
+            guard *= 2;
+        }
+        None
+    }
+}
+
+/// Calculate `base ^ n` under the context with binary exponentiation. If the base is positive
+/// and the rounding mode is directed, the result is a bound of the exact power in that direction.
+fn pow_bound<R: Round, const B: Word>(context: &Context<R>, base: &Repr<B>, n: usize) -> Repr<B> {
+    debug_assert!(n > 0);
+    let mut pow = base.clone();
+    for i in (0..n.bit_len() - 1).rev() {
+        pow = context.square(&pow).value().repr;
+        if n & (1 << i) != 0 {
+            pow = context.mul(&pow, base).value().repr;
+        }
+    }
+    pow
+}
 
-            if fract.is_zero() {
-                int
+/// Parse the string as `significand * radix ^ exponent`, and return the significand, the exponent
+/// and the number of digits in the string. See [FBig::from_str_native] for the accepted formats.
+fn parse_parts(mut src: &str, radix: Word) -> Result<(IBig, isize, usize), ParseError> {
+    // parse and remove the sign
+    let sign = match src.strip_prefix('-') {
+        Some(s) => {
+            src = s;
+            Sign::Negative
+        }
+        None => {
+            src = src.strip_prefix('+').unwrap_or(src);
+            Sign::Positive
+        }
+    };
+
+    // determine the position of scale markers
+    let has_prefix = src.starts_with("0x") || src.starts_with("0X");
+    let scale_pos = match radix {
+        10 => src.rfind(&['e', 'E', '@']),
+        2 => {
+            if has_prefix {
+                src.rfind(&['p', 'P', '@'])
             } else {
-                exponent -= fract_digits as isize;
-                int * UBig::from_word(B).pow(fract_digits) + fract
+                src.rfind(&['b', 'B', '@'])
             }
+        }
+        8 => src.rfind(&['o', 'O', '@']),
+        16 => src.rfind(&['h', 'H', '@']),
+        _ => src.rfind('@'),
+    };
+
+    // parse scale and remove the scale part from the str
+    let (scale, pmarker) = if let Some(pos) = scale_pos {
+        let value = match (&src[pos + 1..]).parse::<isize>() {
+            Err(e) => match e.kind() {
+                IntErrorKind::Empty => return Err(ParseError::NoDigits),
+                _ => return Err(ParseError::InvalidDigit),
+            },
+            Ok(v) => v,
+        };
+        let use_p = if radix == 2 {
+            src.as_bytes().get(pos) == Some(&b'p') || src.as_bytes().get(pos) == Some(&b'P')
         } else {
-            let has_prefix = src.starts_with("0x") || src.starts_with("0X");
-            if B == 2 && has_prefix {
-                src = &src[2..];
-                ndigits = 4 * (src.len() - src.matches('_').count());
-                UBig::from_str_radix(src, 16)?
-            } else if B == 2 && pmarker && !has_prefix {
+            false
+        };
+        src = &src[..pos];
+        (value, use_p)
+    } else {
+        (0, false)
+    };
+
+    // parse the body of the float number
+    let mut exponent = scale;
+    let ndigits;
+    let significand = if let Some(dot) = src.find('.') {
+        // check whether both integral part and fractional part are empty
+        if src.len() == 1 {
+            return Err(ParseError::NoDigits);
+        }
+
+        // parse integral part
+        let (int, int_digits, base) = if dot != 0 {
+            let int_str = &src[..dot];
+            if radix == 2 && has_prefix {
+                // only base 2 float is allowed using prefix
+                let int_str = &int_str[2..];
+                let digits = 4 * (int_str.len() - int_str.matches('_').count());
+                if int_str.is_empty() {
+                    (UBig::ZERO, digits, 16)
+                } else {
+                    (UBig::from_str_radix(int_str, 16)?, digits, 16)
+                }
+            } else if radix == 2 && pmarker && !has_prefix {
                 return Err(ParseError::UnsupportedRadix);
             } else {
-                ndigits = src.len() - src.matches('_').count();
-                UBig::from_str_radix(src, B as u32)?
+                let digits = int_str.len() - int_str.matches('_').count();
+                (UBig::from_str_radix(&src[..dot], radix as u32)?, digits, radix as u32)
+            }
+        } else {
+            if pmarker {
+                // prefix is required for using `p` as scale marker
+                return Err(ParseError::UnsupportedRadix);
             }
+            (UBig::ZERO, 0, radix as u32)
         };
 
-        let repr = Repr::new(sign * significand, exponent);
-        Ok(Self {
-            repr,
-            context: Context::new(ndigits),
-        })
-    }
+        // parse fractional part
+        src = &src[dot + 1..];
+        let (fract, fract_digits) = if !src.is_empty() {
+            let mut digits = src.len() - src.matches('_').count();
+            if radix == 2 && base == 16 {
+                digits *= 4;
+            }
+            (UBig::from_str_radix(src, base)?, digits)
+        } else {
+            (UBig::ZERO, 0)
+        };
+        ndigits = int_digits + fract_digits;
+        if ndigits == 0 {
+            // the digits are missing after the prefix (e.g. `0x.`)
+            return Err(ParseError::NoDigits);
+        }
+
+        if fract.is_zero() {
+            int
+        } else {
+            exponent -= fract_digits as isize;
+            int * UBig::from_word(radix).pow(fract_digits) + fract
+        }
+    } else {
+        let has_prefix = src.starts_with("0x") || src.starts_with("0X");
+        if radix == 2 && has_prefix {
+            src = &src[2..];
+            ndigits = 4 * (src.len() - src.matches('_').count());
+            UBig::from_str_radix(src, 16)?
+        } else if radix == 2 && pmarker && !has_prefix {
+            return Err(ParseError::UnsupportedRadix);
+        } else {
+            ndigits = src.len() - src.matches('_').count();
+            UBig::from_str_radix(src, radix as u32)?
+        }
+    };
+
+    Ok((sign * significand, exponent, ndigits))
 }
 
 impl<R: Round, const B: Word> FromStr for FBig<R, B> {
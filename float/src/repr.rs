@@ -1,10 +1,11 @@
 use crate::{
-    error::panic_operate_with_inf,
-    round::{Round, Rounded},
-    utils::{base_as_ibig, digit_len, split_digits, split_digits_ref},
+    error::{panic_operate_with_inf, panic_unlimited_precision},
+    fbig::FBig,
+    round::{Round, Rounded, Rounding},
+    utils::{base_as_ibig, digit_len, shl_digits_in_place, split_digits, split_digits_ref},
 };
 use core::marker::PhantomData;
-use dashu_base::{Approximation::*, EstimatedLog2, Sign};
+use dashu_base::{Approximation::*, DivRem, EstimatedLog2, Sign};
 pub use dashu_int::Word;
 use dashu_int::{IBig, UBig};
 
@@ -24,9 +25,10 @@ use dashu_int::{IBig, UBig};
 /// This struct supports representing the infinity, but the infinity is only supposed to be used
 /// as sentinels. That is, only equality test and comparison are implemented for the infinity.
 /// Any other operations on the infinity will lead to panic. If an operation result is too large
-/// or too small, the operation will **panic** instead of returning an infinity.
+/// or too small, the operation will **panic** instead of returning an infinity, unless the exponent
+/// range is limited in the [Context].
 ///
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
 pub struct Repr<const BASE: Word> {
     /// The significand of the floating point number. If the significand is zero, then the number is:
     /// - Zero, if exponent = 0
@@ -67,14 +69,41 @@ pub struct Repr<const BASE: Word> {
 ///
 /// For binary operations, the two oprands must have the same rounding mode.
 ///
+/// # Exponent Range
+///
+/// By default the exponent is unbounded. The exponent range can be limited using
+/// [with_exponent_range()][Context::with_exponent_range], so that the float numbers behave like
+/// an IEEE 754 format with the given precision: the results that are too large overflow to the
+/// infinity (or the largest finite number, depending on the rounding mode), and the results that
+/// are too small become subnormal or underflow to zero.
+///
 #[derive(Clone, Copy)]
 pub struct Context<RoundingMode: Round> {
     /// The precision of the floating point number.
     /// If set to zero, then the precision is unlimited.
     pub(crate) precision: usize,
+
+    /// The range [emin, emax] of the exponent when the number is written in the scientific
+    /// notation (with one digit before the radix point). If emin is set to isize::MIN (and emax
+    /// is set to isize::MAX), then the exponent is unbounded.
+    pub(crate) emin: isize,
+    pub(crate) emax: isize,
     _marker: PhantomData<RoundingMode>,
 }
 
+/// The status of an operation result with regard to the exponent range of a [Context].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeStatus {
+    /// The result is zero or a normal number, it's not affected by the exponent range.
+    Normal,
+    /// The result is an exact subnormal number.
+    Subnormal,
+    /// The result is tiny and inexact, it's rounded to a subnormal number or zero.
+    Underflow,
+    /// The result is too large, it's replaced by the infinity or the largest finite number.
+    Overflow,
+}
+
 impl<const B: Word> Repr<B> {
     /// The base of the representation. It's exposed as an [IBig] constant.
     pub const BASE: IBig = base_as_ibig::<B>();
@@ -392,12 +421,103 @@ impl<R: Round> Context<R> {
     pub const fn new(precision: usize) -> Self {
         Self {
             precision,
+            emin: isize::MIN,
+            emax: isize::MAX,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Limit the exponent of the float numbers in this context to the range `[emin, emax]`,
+    /// where the exponent is the one when the number is written in the scientific notation
+    /// (`d.ddd * base ^ exponent`).
+    ///
+    /// The smallest positive subnormal number is `base ^ (emin - precision + 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(3).with_exponent_range(-10, 10);
+    /// assert_eq!(context.emin(), Some(-10));
+    ///
+    /// let a = DBig::from_str_native("1.23e-11")?;
+    /// let b = DBig::from_str_native("0.5")?;
+    /// // the result is subnormal, so it has fewer significant digits
+    /// assert_eq!(context.mul(a.repr(), b.repr()), Inexact(DBig::from_str_native("6e-12")?, NoOp));
+    /// // the result underflows to zero
+    /// assert_eq!(context.mul(a.repr(), a.repr()), Inexact(DBig::ZERO, NoOp));
+    ///
+    /// // the result overflows
+    /// let c = DBig::from_str_native("5e10")?;
+    /// assert_eq!(context.mul(c.repr(), c.repr()), Inexact(DBig::INFINITY, AddOne));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited, `emin > emax` or `emin` is `isize::MIN`.
+    #[inline]
+    pub const fn with_exponent_range(self, emin: isize, emax: isize) -> Self {
+        if self.precision == 0 {
+            panic_unlimited_precision()
+        }
+        assert!(emin != isize::MIN && emin <= emax);
+        Self {
+            precision: self.precision,
+            emin,
+            emax,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the minimum exponent of normal numbers in this context, None if the exponent is unbounded.
+    #[inline]
+    pub const fn emin(&self) -> Option<isize> {
+        match self.exponent_range() {
+            Some((emin, _)) => Some(emin),
+            None => None,
+        }
+    }
+
+    /// Get the maximum exponent of finite numbers in this context, None if the exponent is unbounded.
+    #[inline]
+    pub const fn emax(&self) -> Option<isize> {
+        match self.exponent_range() {
+            Some((_, emax)) => Some(emax),
+            None => None,
+        }
+    }
+
+    /// Get the exponent range (emin, emax) of this context, None if the exponent is unbounded.
+    #[inline]
+    pub(crate) const fn exponent_range(&self) -> Option<(isize, isize)> {
+        if self.emin == isize::MIN {
+            None
+        } else {
+            Some((self.emin, self.emax))
+        }
+    }
+
+    /// Create a context with the same precision and exponent range but a different rounding mode
+    #[inline]
+    pub(crate) const fn with_rounding<NewR: Round>(self) -> Context<NewR> {
+        Context {
+            precision: self.precision,
+            emin: self.emin,
+            emax: self.emax,
             _marker: PhantomData,
         }
     }
 
     /// Create a float operation context with the higher precision from the two context inputs.
     ///
+    /// If both contexts have limited exponent ranges, the result will have the union of the two
+    /// ranges, otherwise the exponent of the result is unbounded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -415,6 +535,17 @@ impl<R: Round> Context<R> {
             } else {
                 rhs.precision
             },
+            // the union is unbounded if either of the ranges is unbounded
+            emin: if lhs.emin < rhs.emin {
+                lhs.emin
+            } else {
+                rhs.emin
+            },
+            emax: if lhs.emax > rhs.emax {
+                lhs.emax
+            } else {
+                rhs.emax
+            },
             _marker: PhantomData,
         }
     }
@@ -431,6 +562,23 @@ impl<R: Round> Context<R> {
         self.precision
     }
 
+    /// Get the minimum exponent of the lowest digit of subnormal numbers, if the exponent is bounded
+    #[inline]
+    pub(crate) fn min_quantum(&self) -> Option<isize> {
+        self.emin().map(|emin| emin - self.precision as isize + 1)
+    }
+
+    /// Get the number of low digits to be removed from a repr with the given
+    /// exponent and number of digits when rounding to this context
+    #[inline]
+    pub(crate) fn round_shift(&self, exponent: isize, digits: usize) -> usize {
+        let shift = digits.saturating_sub(self.precision);
+        match self.min_quantum() {
+            Some(qmin) if exponent < qmin => shift.max((qmin - exponent) as usize),
+            _ => shift,
+        }
+    }
+
     /// Round the repr to the desired precision
     pub(crate) fn repr_round<const B: Word>(&self, repr: Repr<B>) -> Rounded<Repr<B>> {
         assert!(repr.is_finite());
@@ -438,15 +586,15 @@ impl<R: Round> Context<R> {
             return Exact(repr);
         }
 
-        let digits = repr.digits();
-        if digits > self.precision {
-            let shift = digits - self.precision;
+        let shift = self.round_shift(repr.exponent, repr.digits());
+        let rounded = if shift > 0 && !repr.is_zero() {
             let (signif_hi, signif_lo) = split_digits::<B>(repr.significand, shift);
             let adjust = R::round_fract::<B>(&signif_hi, signif_lo, shift);
             Inexact(Repr::new(signif_hi + adjust, repr.exponent + shift as isize), adjust)
         } else {
             Exact(repr)
-        }
+        };
+        self.repr_fit_range(rounded).0
     }
 
     /// Round the repr to the desired precision
@@ -456,14 +604,192 @@ impl<R: Round> Context<R> {
             return Exact(repr.clone());
         }
 
-        let digits = repr.digits();
-        if digits > self.precision {
-            let shift = digits - self.precision;
+        let shift = self.round_shift(repr.exponent, repr.digits());
+        let rounded = if shift > 0 && !repr.is_zero() {
             let (signif_hi, signif_lo) = split_digits_ref::<B>(&repr.significand, shift);
             let adjust = R::round_fract::<B>(&signif_hi, signif_lo, shift);
             Inexact(Repr::new(signif_hi + adjust, repr.exponent + shift as isize), adjust)
         } else {
             Exact(repr.clone())
+        };
+        self.repr_fit_range(rounded).0
+    }
+
+    /// Fit a value that is already rounded to this context into the exponent range of this
+    /// context, and report the status of the result.
+    ///
+    /// An inexact value must have been rounded with the subnormal precision (see [Self::round_shift])
+    /// to prevent double rounding, while an exact value in the subnormal range is rounded here.
+    pub(crate) fn repr_fit_range<const B: Word>(
+        &self,
+        value: Rounded<Repr<B>>,
+    ) -> (Rounded<Repr<B>>, RangeStatus) {
+        let (emin, emax) = match self.exponent_range() {
+            Some(range) => range,
+            None => return (value, RangeStatus::Normal),
+        };
+        let qmin = emin - self.precision as isize + 1;
+
+        let value = match value {
+            Exact(repr) if repr.is_finite() && !repr.is_zero() && repr.exponent < qmin => {
+                self.repr_round_at(repr.significand, repr.exponent, qmin)
+            }
+            v => {
+                debug_assert!(!matches!(&v, Inexact(repr, _)
+                    if repr.is_finite() && !repr.is_zero() && repr.exponent < qmin));
+                v
+            }
+        };
+
+        let repr = match &value {
+            Exact(v) | Inexact(v, _) => v,
+        };
+        if repr.is_infinite() || repr.is_zero() {
+            return match value {
+                Inexact(..) if repr.is_zero() => (value, RangeStatus::Underflow),
+                _ => (value, RangeStatus::Normal),
+            };
+        }
+
+        let top = repr.exponent + repr.digits() as isize - 1;
+        if top > emax {
+            // the overflowed result depends on whether the rounding mode rounds a value
+            // that is much larger than the largest finite number away from zero
+            let sign = repr.sign();
+            let max_signif = sign * (Repr::<B>::BASE.pow(self.precision) - IBig::ONE);
+            let fract = sign * (Repr::<B>::BASE.pow(2) - IBig::ONE);
+            let adjust = R::round_fract::<B>(&max_signif, fract, 2);
+            let result = match (adjust, sign) {
+                (Rounding::NoOp, _) => Repr::new(max_signif, emax - self.precision as isize + 1),
+                (_, Sign::Positive) => Repr::infinity(),
+                (_, Sign::Negative) => Repr::neg_infinity(),
+            };
+            (Inexact(result, adjust), RangeStatus::Overflow)
+        } else if top < emin {
+            match value {
+                Exact(_) => (value, RangeStatus::Subnormal),
+                Inexact(..) => (value, RangeStatus::Underflow),
+            }
+        } else {
+            (value, RangeStatus::Normal)
+        }
+    }
+
+    /// Round `num / den * B ^ exponent` to this context with a single rounding, where `den` is positive.
+    ///
+    /// Unlike `repr_div`, there's no restriction on the sizes of the numerator and the denominator. This method panics if the precision is unlimited and the ratio can't
+    /// be represented exactly.
+    pub(crate) fn repr_round_ratio<const B: Word>(
+        &self,
+        num: IBig,
+        den: &IBig,
+        exponent: isize,
+    ) -> Rounded<Repr<B>> {
+        debug_assert!(den.sign() == Sign::Positive);
+
+        // make sure that the quotient has more digits than the precision
+        let shift = (self.precision + 1 + digit_len::<B>(den)).saturating_sub(digit_len::<B>(&num));
+        let mut num = num;
+        shl_digits_in_place::<B>(&mut num, shift);
+        let (q, r) = num.div_rem(den);
+        let exponent = exponent - shift as isize;
+        if r.is_zero() {
+            return self.repr_round(Repr::new(q, exponent));
+        }
+        if !self.is_limited() {
+            panic_unlimited_precision();
         }
+
+        // round the quotient with the fraction (q_lo + r / den) / B ^ shift
+        let shift = self.round_shift(exponent, digit_len::<B>(&q));
+        let (hi, lo) = split_digits::<B>(q, shift);
+        let mut frac_den = den.clone();
+        shl_digits_in_place::<B>(&mut frac_den, shift);
+        let adjust = R::round_ratio(&hi, lo * den + r, &frac_den);
+        let rounded = Inexact(Repr::new(hi + adjust, exponent + shift as isize), adjust);
+        self.repr_fit_range(rounded).0
+    }
+
+    /// Round `significand * B ^ exponent` so that the exponent of the result is not less than `min_exp`
+    fn repr_round_at<const B: Word>(
+        &self,
+        significand: IBig,
+        exponent: isize,
+        min_exp: isize,
+    ) -> Rounded<Repr<B>> {
+        debug_assert!(exponent < min_exp);
+        let shift = (min_exp - exponent) as usize;
+        let (hi, lo) = split_digits::<B>(significand, shift);
+        let adjust = R::round_fract::<B>(&hi, lo, shift);
+        Inexact(Repr::new(hi + adjust, min_exp), adjust)
+    }
+
+    /// Evaluate an operation that is not correctly rounded with a context that has the same
+    /// precision but an unbounded exponent range, and then round the result again to this context.
+    pub(crate) fn eval_unbounded<const B: Word, F>(&self, f: F) -> Rounded<FBig<R, B>>
+    where
+        F: FnOnce(&Self) -> Rounded<FBig<R, B>>,
+    {
+        let unbounded = Self::new(self.precision);
+        let value = match f(&unbounded) {
+            Exact(v) => self.repr_round(v.repr),
+            Inexact(v, adjust) => match self.repr_round(v.repr) {
+                Exact(v) => Inexact(v, adjust),
+                inexact => inexact,
+            },
+        };
+        value.map(|v| FBig::new(v, *self))
+    }
+
+    /// Evaluate an operation with this context, and report the status of the result with regard
+    /// to the exponent range of this context.
+    ///
+    /// The operation `f` is evaluated with a context that has the same precision but an unbounded
+    /// exponent range, and then the result is fitted into the exponent range of this context.
+    /// If the result is in the subnormal range, `f` is evaluated again with this context, so that
+    /// the result is rounded only once to the subnormal precision. Therefore `f` should round its
+    /// result with the context passed to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, RangeStatus, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(3).with_exponent_range(-10, 10);
+    /// let a = DBig::from_str_native("5e10")?;
+    /// assert_eq!(
+    ///     context.with_range_status(|ctx| ctx.mul(a.repr(), a.repr())),
+    ///     (Inexact(DBig::INFINITY, AddOne), RangeStatus::Overflow)
+    /// );
+    ///
+    /// let b = DBig::from_str_native("1e-11")?;
+    /// assert_eq!(
+    ///     context.with_range_status(|ctx| ctx.add(b.repr(), b.repr())),
+    ///     (Exact(DBig::from_str_native("2e-11")?), RangeStatus::Subnormal)
+    /// );
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn with_range_status<const B: Word, F>(&self, f: F) -> (Rounded<FBig<R, B>>, RangeStatus)
+    where
+        F: Fn(&Self) -> Rounded<FBig<R, B>>,
+    {
+        let unbounded = Self::new(self.precision);
+        let value = f(&unbounded).map(|v| v.repr);
+        let is_subnormal = match (&value, self.min_quantum()) {
+            (Exact(v) | Inexact(v, _), Some(qmin)) => {
+                v.is_finite() && !v.is_zero() && v.exponent < qmin
+            }
+            _ => false,
+        };
+        let value = if is_subnormal {
+            f(self).map(|v| v.repr)
+        } else {
+            value
+        };
+        let (value, status) = self.repr_fit_range(value);
+        (value.map(|v| FBig::new(v, *self)), status)
     }
 }
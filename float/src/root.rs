@@ -0,0 +1,166 @@
+use crate::{
+    error::{check_inf, check_inf_operands, check_precision_limited, panic_root_negative},
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{Round, Rounded},
+    utils::{digit_len, shl_digits_in_place, split_digits, sqrt_rem},
+};
+use dashu_base::{Approximation::*, Sign};
+use dashu_int::IBig;
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Calculate the square root of the floating point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.23")?;
+    /// assert_eq!(a.sqrt(), DBig::from_str_native("1.11")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative or the precision is unlimited.
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        self.context.sqrt(&self.repr).value()
+    }
+
+    /// Calculate the Euclidean norm `sqrt(self² + other²)` with a single rounding.
+    ///
+    /// The result has the higher one between the precisions of two operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("3.0")?;
+    /// let b = DBig::from_str_native("4.0")?;
+    /// assert_eq!(a.hypot(&b), DBig::from_str_native("5")?);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    #[inline]
+    pub fn hypot(&self, other: &Self) -> Self {
+        let context = Context::max(self.context, other.context);
+        context.hypot(&self.repr, &other.repr).value()
+    }
+}
+
+impl<R: Round> Context<R> {
+    /// Calculate the square root of the floating point number under this context.
+    ///
+    /// The result is correctly rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(2);
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(context.sqrt(&a.repr()), Inexact(DBig::from_str_native("1.1")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative or the precision is unlimited.
+    pub fn sqrt<const B: Word>(&self, x: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf(x);
+        check_precision_limited(self.precision);
+
+        if x.is_zero() {
+            return Exact(FBig::new(Repr::zero(), *self));
+        }
+        if x.sign() == Sign::Negative {
+            panic_root_negative()
+        }
+        self.repr_sqrt(x).map(|v| FBig::new(v, *self))
+    }
+
+    /// Calculate the square root of a positive representation, assuming the precision is limited
+    fn repr_sqrt<const B: Word>(&self, x: &Repr<B>) -> Rounded<Repr<B>> {
+        debug_assert!(self.is_limited() && x.sign() == Sign::Positive && !x.is_zero());
+
+        // scale the significand so that it has at least 2*precision digits and
+        // the exponent is even, then the integer root has at least `precision` digits.
+        let digits = x.digits();
+        let mut shift = (2 * self.precision).saturating_sub(digits);
+        if (x.exponent - shift as isize) % 2 != 0 {
+            shift += 1;
+        }
+        let mut signif = x.significand.clone();
+        shl_digits_in_place::<B>(&mut signif, shift);
+        let exponent = (x.exponent - shift as isize) / 2;
+
+        let (_, signif) = signif.into_parts();
+        let (root, rem) = sqrt_rem(&signif);
+        let root = IBig::from(root);
+        if rem.is_zero() {
+            return self.repr_round(Repr::new(root, exponent));
+        }
+
+        // the exact root is root + fract where rem / (2root + 1) < fract < rem / 2root. The
+        // fract is replaced by the lower bound, which is greater than 1/2 if and only if fract
+        // is, so the rounding is still exact for the deterministic modes, and the error of the
+        // probability in the stochastic rounding is less than 1 / 2root.
+        let root_digits = digit_len::<B>(&root);
+        let low_digits = self.round_shift(exponent, root_digits);
+        let den = IBig::from(2u8) * &root + IBig::ONE;
+        let (hi, lo) = split_digits::<B>(root, low_digits);
+        let num = lo * &den + IBig::from(rem);
+        let den = den * Repr::<B>::BASE.pow(low_digits);
+        let adjust = R::round_ratio(&hi, num, &den);
+        let rounded = Inexact(Repr::new(hi + adjust, exponent + low_digits as isize), adjust);
+        self.repr_fit_range(rounded).0
+    }
+
+    /// Calculate the Euclidean norm `sqrt(a² + b²)` under this context.
+    ///
+    /// The intermediate values are computed without rounding, so the result is correctly rounded
+    /// and won't suffer from the overflow or precision loss of the intermediate squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// use dashu_base::Approximation::*;
+    /// use dashu_float::{Context, round::{mode::HalfAway, Rounding::*}};
+    ///
+    /// let context = Context::<HalfAway>::new(3);
+    /// let a = DBig::from_str_native("1")?;
+    /// let b = DBig::from_str_native("1e-1000")?;
+    /// assert_eq!(context.hypot(&a.repr(), &b.repr()), Inexact(DBig::from_str_native("1.00")?, NoOp));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is unlimited.
+    pub fn hypot<const B: Word>(&self, a: &Repr<B>, b: &Repr<B>) -> Rounded<FBig<R, B>> {
+        check_inf_operands(a, b);
+        check_precision_limited(self.precision);
+
+        if a.is_zero() && b.is_zero() {
+            return Exact(FBig::new(Repr::zero(), *self));
+        }
+
+        // the digits of the sum beyond twice the precision only affect the rounding as sticky digits
+        let a2 = Repr::<B>::new(a.significand.square(), 2 * a.exponent);
+        let b2 = Repr::<B>::new(b.significand.square(), 2 * b.exponent);
+        let sum = Context::<R>::new(2 * self.precision + 4).repr_add_sticky(&a2, &b2);
+        self.repr_sqrt(&sum).map(|v| FBig::new(v, *self))
+    }
+}
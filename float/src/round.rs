@@ -5,6 +5,9 @@ use core::ops::{Add, AddAssign};
 use dashu_base::{Approximation, EstimatedLog2, Sign, UnsignedAbs};
 use dashu_int::{IBig, UBig, Word};
 
+#[cfg(all(feature = "rand", feature = "std"))]
+use rand::Rng;
+
 /// Built-in rounding modes of the floating numbers.
 ///
 /// # Rounding Error
@@ -26,6 +29,18 @@ use dashu_int::{IBig, UBig, Word};
 /// | HalfEven | AddOne   | `[-1/2 ulp, 0)`                  |
 /// | HalfEven | NoOp     | `[-1/2 ulp, 1/2 ulp]`            |
 /// | HalfEven | SubOne   | `(0, 1/2 ulp]`                   |
+/// | HalfDown | AddOne   | `[-1/2 ulp, 0)`                  |
+/// | HalfDown | NoOp     | `[-1/2 ulp, 1/2 ulp]`            |
+/// | HalfDown | SubOne   | `(0, 1/2 ulp]`                   |
+/// | HalfOdd  | AddOne   | `[-1/2 ulp, 0)`                  |
+/// | HalfOdd  | NoOp     | `[-1/2 ulp, 1/2 ulp]`            |
+/// | HalfOdd  | SubOne   | `(0, 1/2 ulp]`                   |
+/// | ToOdd    | AddOne   | `(-1 ulp, 0)`                    |
+/// | ToOdd    | NoOp     | `(-1 ulp, 1 ulp)`                |
+/// | ToOdd    | SubOne   | `(0, 1 ulp)`                     |
+/// | Stochastic | AddOne | `(-1 ulp, 0)`                    |
+/// | Stochastic | NoOp   | `(-1 ulp, 1 ulp)`                |
+/// | Stochastic | SubOne | `(0, 1 ulp)`                     |
 ///
 /// *: Dependends on the sign of the result
 ///
@@ -53,6 +68,35 @@ pub mod mode {
     /// Round to the nearest value, ties away from zero
     #[derive(Clone, Copy)]
     pub struct HalfAway;
+
+    /// Round to the nearest value, ties toward zero
+    #[derive(Clone, Copy)]
+    pub struct HalfDown;
+
+    /// Round to the nearest value, ties are rounded to an odd value.
+    #[derive(Clone, Copy)]
+    pub struct HalfOdd;
+
+    /// Round to the adjacent odd value if the result is inexact (also known as sticky rounding).
+    ///
+    /// The inexactness is kept in the last digit, so rounding a binary result to odd with at
+    /// least two extra bits and then rounding it again to the target precision gives the same
+    /// result as rounding directly. This makes it useful for intermediate results.
+    #[derive(Clone, Copy)]
+    pub struct ToOdd;
+
+    /// Round to one of the two adjacent values randomly, with the probability of rounding away
+    /// from the truncated value proportional to the discarded fraction.
+    ///
+    /// The random numbers are drawn from [rand::thread_rng]. When only the comparison between the
+    /// discarded fraction and one half is available (which is the case for `sqrt`), the probability
+    /// is 1/4, 1/2 or 3/4 depending on whether the fraction is less than, equal to or greater
+    /// than one half.
+    ///
+    /// This mode is available with the `rand` feature (and the `std` feature).
+    #[cfg(all(feature = "rand", feature = "std"))]
+    #[derive(Clone, Copy)]
+    pub struct Stochastic;
 }
 
 /// The adjustment of a rounding operation
@@ -83,6 +127,10 @@ pub trait Round: Copy {
 
     /// Calculate the rounding of the number (integer + rem), assuming rem != 0 and |rem| < 1.
     /// `low_half_test` should tell |rem|.cmp(0.5)
+    ///
+    /// Only the comparison with 0.5 is known here, so the rounding modes that depend on the
+    /// exact value of `rem` (such as `mode::Stochastic`) can only approximate the result.
+    /// Prefer [Round::round_fract] or [Round::round_ratio] when the exact fraction is available.
     fn round_low_part<F: FnOnce() -> Ordering>(
         integer: &IBig,
         low_sign: Sign,
@@ -293,6 +341,149 @@ impl Round for mode::HalfEven {
     }
 }
 
+impl Round for mode::HalfDown {
+    type Reverse = Self;
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        integer: &IBig,
+        low_sign: Sign,
+        low_half_test: F,
+    ) -> Rounding {
+        match low_half_test() {
+            // |rem| < 1/2
+            Ordering::Less => Rounding::NoOp,
+            // |rem| = 1/2, round toward zero
+            Ordering::Equal => mode::Zero::round_low_part(integer, low_sign, || Ordering::Equal),
+            // |rem| > 1/2
+            Ordering::Greater => {
+                // +1 if rem > 0, -1 if rem < 0
+                match low_sign {
+                    Sign::Positive => Rounding::AddOne,
+                    Sign::Negative => Rounding::SubOne,
+                }
+            }
+        }
+    }
+}
+
+impl Round for mode::HalfOdd {
+    type Reverse = Self;
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        integer: &IBig,
+        low_sign: Sign,
+        low_half_test: F,
+    ) -> Rounding {
+        match low_half_test() {
+            // |rem| < 1/2
+            Ordering::Less => Rounding::NoOp,
+            // |rem| = 1/2
+            Ordering::Equal => {
+                // if integer is even, +1 if rem > 0, -1 if rem < 0
+                if integer & 1 == 0 {
+                    match low_sign {
+                        Sign::Positive => Rounding::AddOne,
+                        Sign::Negative => Rounding::SubOne,
+                    }
+                } else {
+                    Rounding::NoOp
+                }
+            }
+            // |rem| > 1/2
+            Ordering::Greater => {
+                // +1 if rem > 0, -1 if rem < 0
+                match low_sign {
+                    Sign::Positive => Rounding::AddOne,
+                    Sign::Negative => Rounding::SubOne,
+                }
+            }
+        }
+    }
+}
+
+impl Round for mode::ToOdd {
+    type Reverse = Self;
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        integer: &IBig,
+        low_sign: Sign,
+        _low_half_test: F,
+    ) -> Rounding {
+        // if integer is even, +1 if rem > 0, -1 if rem < 0
+        if integer & 1 == 0 {
+            match low_sign {
+                Sign::Positive => Rounding::AddOne,
+                Sign::Negative => Rounding::SubOne,
+            }
+        } else {
+            Rounding::NoOp
+        }
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl mode::Stochastic {
+    /// Round away from the integer (toward the direction of rem) if `below` is true
+    #[inline]
+    fn adjust(low_sign: Sign, below: bool) -> Rounding {
+        match (below, low_sign) {
+            (false, _) => Rounding::NoOp,
+            (true, Sign::Positive) => Rounding::AddOne,
+            (true, Sign::Negative) => Rounding::SubOne,
+        }
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl Round for mode::Stochastic {
+    type Reverse = Self;
+
+    #[inline]
+    fn round_low_part<F: FnOnce() -> Ordering>(
+        _integer: &IBig,
+        low_sign: Sign,
+        low_half_test: F,
+    ) -> Rounding {
+        // the exact fraction is unknown, so this is only an approximation that uses 1/4, 1/2 or 3/4
+        // as the probability. The arithmetic operations in this crate always provide the exact
+        // fraction through `round_fract` or `round_ratio` instead.
+        let quarters = match low_half_test() {
+            Ordering::Less => 1,
+            Ordering::Equal => 2,
+            Ordering::Greater => 3,
+        };
+        Self::adjust(low_sign, rand::thread_rng().gen_range(0u8..4) < quarters)
+    }
+
+    fn round_fract<const B: Word>(_integer: &IBig, fract: IBig, precision: usize) -> Rounding {
+        debug_assert!(fract.clone().unsigned_abs() < UBig::from_word(B).pow(precision));
+
+        if fract.is_zero() {
+            return Rounding::NoOp;
+        }
+        let (fsign, fmag) = fract.into_parts();
+        let unit = UBig::from_word(B).pow(precision);
+        let sample = rand::thread_rng().gen_range(UBig::ZERO..unit);
+        Self::adjust(fsign, sample < fmag)
+    }
+
+    fn round_ratio(_integer: &IBig, num: IBig, den: &IBig) -> Rounding {
+        assert!(!den.is_zero());
+        debug_assert!(num.clone().unsigned_abs() < den.clone().unsigned_abs());
+
+        if num.is_zero() {
+            return Rounding::NoOp;
+        }
+        let (nsign, nmag) = num.into_parts();
+        let dmag = den.clone().unsigned_abs();
+        let sample = rand::thread_rng().gen_range(UBig::ZERO..dmag);
+        Self::adjust(nsign * den.sign(), sample < nmag)
+    }
+}
+
 impl Add<Rounding> for IBig {
     type Output = IBig;
 
@@ -503,4 +694,99 @@ mod tests {
         ];
         test_cases.iter().for_each(test_all_rounding);
     }
+
+    #[test]
+    fn test_additional_modes() {
+        #[rustfmt::skip]
+        fn test_fract<const B: Word, const D: usize>(input: &(i32, i32, Rounding, Rounding, Rounding)) {
+            let (value, fract, rnd_halfdown, rnd_halfodd, rnd_toodd) = *input;
+            let (value, fract) = (IBig::from(value), IBig::from(fract));
+            assert_eq!(HalfDown::round_fract::<B>(&value, fract.clone(), D), rnd_halfdown);
+            assert_eq!(HalfOdd::round_fract::<B>(&value, fract.clone(), D), rnd_halfodd);
+            assert_eq!(ToOdd::round_fract::<B>(&value, fract.clone(), D), rnd_toodd);
+        }
+
+        // cases for radix = 2, 2 digit fraction
+        #[rustfmt::skip]
+        let binary_cases = [
+            // (integer value, fraction part, roundings...)
+            // Mode: HDown , HOdd  , ToOdd
+            ( 0,  3, AddOne, AddOne, AddOne),
+            ( 0,  2, NoOp  , AddOne, AddOne),
+            ( 0,  1, NoOp  , NoOp  , AddOne),
+            ( 0,  0, NoOp  , NoOp  , NoOp  ),
+            ( 0, -2, NoOp  , SubOne, SubOne),
+            ( 1,  2, NoOp  , NoOp  , NoOp  ),
+            ( 1, -1, NoOp  , NoOp  , NoOp  ),
+            ( 1, -2, SubOne, NoOp  , NoOp  ),
+            ( 1, -3, SubOne, SubOne, NoOp  ),
+            ( 2,  1, NoOp  , NoOp  , AddOne),
+            ( 2, -1, NoOp  , NoOp  , SubOne),
+            (-1,  2, AddOne, NoOp  , NoOp  ),
+            (-1, -2, NoOp  , NoOp  , NoOp  ),
+            (-2,  1, NoOp  , NoOp  , AddOne),
+            (-2, -2, NoOp  , SubOne, SubOne),
+        ];
+        binary_cases.iter().for_each(test_fract::<2, 2>);
+
+        // cases for radix = 10, 1 digit fraction
+        #[rustfmt::skip]
+        let decimal_cases = [
+            // (integer value, fraction part, roundings...)
+            // Mode: HDown , HOdd  , ToOdd
+            ( 0,  5, NoOp  , AddOne, AddOne),
+            ( 1,  5, NoOp  , NoOp  , NoOp  ),
+            ( 1,  7, AddOne, AddOne, NoOp  ),
+            ( 2,  5, NoOp  , AddOne, AddOne),
+            ( 2, -5, SubOne, SubOne, SubOne),
+            (-2,  5, AddOne, AddOne, AddOne),
+            (-2, -5, NoOp  , SubOne, SubOne),
+            (-2, -2, NoOp  , NoOp  , SubOne),
+        ];
+        decimal_cases.iter().for_each(test_fract::<10, 1>);
+
+        #[rustfmt::skip]
+        let ratio_cases = [
+            // (integer value, mumerator, denominator, roundings...)
+            // Mode:     HDown , HOdd  , ToOdd
+            ( 0,  1,  2, NoOp  , AddOne, AddOne),
+            ( 1,  1, -2, SubOne, NoOp  , NoOp  ),
+            ( 2, -1,  3, NoOp  , NoOp  , SubOne),
+            ( 2,  2, -3, SubOne, SubOne, SubOne),
+            (-2,  2,  3, AddOne, AddOne, AddOne),
+        ];
+        for &(value, num, den, rnd_halfdown, rnd_halfodd, rnd_toodd) in ratio_cases.iter() {
+            let (value, num, den) = (IBig::from(value), IBig::from(num), IBig::from(den));
+            assert_eq!(HalfDown::round_ratio(&value, num.clone(), &den), rnd_halfdown);
+            assert_eq!(HalfOdd::round_ratio(&value, num.clone(), &den), rnd_halfodd);
+            assert_eq!(ToOdd::round_ratio(&value, num.clone(), &den), rnd_toodd);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "std"))]
+    fn test_stochastic() {
+        assert_eq!(Stochastic::round_fract::<10>(&IBig::ONE, IBig::ZERO, 2), NoOp);
+        assert_eq!(Stochastic::round_ratio(&IBig::ONE, IBig::ZERO, &IBig::from(3)), NoOp);
+
+        // 1.25 is rounded to 2 with probability 1/4
+        let count = (0..4000)
+            .map(|_| Stochastic::round_fract::<10>(&IBig::ONE, IBig::from(25), 2))
+            .filter(|&r| {
+                assert_ne!(r, SubOne);
+                r == AddOne
+            })
+            .count();
+        assert!((700..1300).contains(&count));
+
+        // -1 - 2/3 is rounded to -2 with probability 2/3
+        let count = (0..3000)
+            .map(|_| Stochastic::round_ratio(&IBig::NEG_ONE, IBig::from(2), &IBig::from(-3)))
+            .filter(|&r| {
+                assert_ne!(r, AddOne);
+                r == SubOne
+            })
+            .count();
+        assert!((1700..2300).contains(&count));
+    }
 }
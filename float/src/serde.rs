@@ -0,0 +1,158 @@
+//! Implementations of the serde traits for [FBig] and [Repr].
+//!
+//! For the human-readable formats (e.g. JSON, YAML), the numbers are serialized as strings in
+//! the scientific notation of the native base (the format of [LowerExp][core::fmt::LowerExp]),
+//! which can be parsed back by [FBig::from_str_native] losslessly. For the compact formats
+//! (e.g. bincode), the numbers are serialized as tuples of the significand, the exponent and
+//! (for [FBig]) the precision.
+
+use crate::{
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::{mode, Round},
+};
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+use dashu_int::IBig;
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Construct the representation from the serialized parts, the infinities are represented by
+/// a zero significand with exponent 1 or -1.
+fn repr_from_parts<const B: Word>(significand: IBig, exponent: isize) -> Repr<B> {
+    if significand.is_zero() {
+        match exponent {
+            1 => return Repr::infinity(),
+            -1 => return Repr::neg_infinity(),
+            _ => {}
+        }
+    }
+    Repr::new(significand, exponent)
+}
+
+/// Parse the string in the native base, including the infinities printed by the formatter
+fn parse_native<R: Round, E: de::Error, const B: Word>(s: &str) -> Result<FBig<R, B>, E> {
+    match s {
+        "inf" | "+inf" => Ok(FBig::INFINITY),
+        "-inf" => Ok(FBig::NEG_INFINITY),
+        _ => FBig::from_str_native(s).map_err(E::custom),
+    }
+}
+
+impl<const B: Word> Serialize for Repr<B> {
+    /// Serialize the number as a string in the scientific notation of base `B` (e.g. `-1.25e3`
+    /// in base 10 or `1.01@-4` in base 2) for human-readable formats, and as a tuple
+    /// `(significand, exponent)` for compact formats.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let f = FBig::<mode::Zero, B>::from_repr(self.clone(), Context::new(0));
+            serializer.collect_str(&format_args!("{:e}", f))
+        } else {
+            let mut tup = serializer.serialize_tuple(2)?;
+            tup.serialize_element(self.significand())?;
+            tup.serialize_element(&self.exponent())?;
+            tup.end()
+        }
+    }
+}
+
+impl<'de, const B: Word> Deserialize<'de> for Repr<B> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let f = deserializer.deserialize_str(NativeStrVisitor::<mode::Zero, B>(PhantomData))?;
+            Ok(f.into_repr())
+        } else {
+            let (significand, exponent): (IBig, isize) = Deserialize::deserialize(deserializer)?;
+            Ok(repr_from_parts(significand, exponent))
+        }
+    }
+}
+
+impl<R: Round, const B: Word> Serialize for FBig<R, B> {
+    /// Serialize the number as a string in the scientific notation of base `B` for human-readable
+    /// formats, where the significand is padded with zeros to have as many digits as the precision
+    /// (e.g. `1.500e0` for `1.5` with precision 4), so that both the value and the precision are
+    /// restored by deserialization. For compact formats, the number is serialized as a tuple
+    /// `(significand, exponent, precision)`.
+    ///
+    /// The rounding mode is not serialized, it's determined by the type. The string of a number
+    /// with unlimited precision is not padded, so it's deserialized with the precision equal to
+    /// its number of digits.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let precision = self.precision();
+            if precision == 0 || self.repr().is_infinite() {
+                serializer.collect_str(&format_args!("{:e}", self))
+            } else {
+                serializer.collect_str(&format_args!("{:.*e}", precision - 1, self))
+            }
+        } else {
+            let mut tup = serializer.serialize_tuple(3)?;
+            tup.serialize_element(self.repr().significand())?;
+            tup.serialize_element(&self.repr().exponent())?;
+            tup.serialize_element(&self.precision())?;
+            tup.end()
+        }
+    }
+}
+
+impl<'de, R: Round, const B: Word> Deserialize<'de> for FBig<R, B> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NativeStrVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(3, FBigPartsVisitor(PhantomData))
+        }
+    }
+}
+
+/// Visitor of the strings in the native base
+struct NativeStrVisitor<R, const B: Word>(PhantomData<R>);
+
+impl<'de, R: Round, const B: Word> Visitor<'de> for NativeStrVisitor<R, B> {
+    type Value = FBig<R, B>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a float number string in base {}", B)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<FBig<R, B>, E> {
+        parse_native(v)
+    }
+}
+
+/// Visitor of the tuple `(significand, exponent, precision)`
+struct FBigPartsVisitor<R, const B: Word>(PhantomData<R>);
+
+impl<'de, R: Round, const B: Word> Visitor<'de> for FBigPartsVisitor<R, B> {
+    type Value = FBig<R, B>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a tuple of the significand, the exponent and the precision")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<FBig<R, B>, A::Error> {
+        let significand: IBig = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let exponent: isize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let precision: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+        let repr = repr_from_parts(significand, exponent);
+        if precision != 0 && !repr.is_infinite() && repr.digits() > precision {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(precision as u64),
+                &"a precision not less than the number of digits in the significand",
+            ));
+        }
+        Ok(FBig::from_repr(repr, Context::new(precision)))
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{error::check_inf, fbig::FBig, repr::Word, round::Round};
+use crate::{
+    error::check_inf,
+    fbig::FBig,
+    repr::{Repr, Word},
+    round::Round,
+};
 use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
 
 impl<R: Round, const B: Word> Shl<isize> for FBig<R, B> {
@@ -42,6 +47,38 @@ impl<R: Round, const B: Word> ShrAssign<isize> for FBig<R, B> {
         if !self.repr.is_zero() {
             self.repr.exponent -= rhs;
         }
-        self.repr.exponent -= rhs;
+    }
+}
+
+impl<R: Round, const B: Word> FBig<R, B> {
+    /// Multiply the number by `B ^ exp`, rounded to the context of this number.
+    ///
+    /// Unlike the shift operators, the result is rounded when the exponent range
+    /// of the context is limited, which might lead to a subnormal number, a zero
+    /// or an infinity. The infinities are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.234")?;
+    /// assert_eq!(a.ldexp(3), DBig::from_str_native("1234")?);
+    /// assert_eq!(a.ldexp(-2), DBig::from_str_native("0.01234")?);
+    /// assert_eq!(DBig::INFINITY.ldexp(3), DBig::INFINITY);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[doc(alias = "scale_b")]
+    pub fn ldexp(&self, exp: isize) -> Self {
+        if self.repr.is_infinite() || self.repr.is_zero() {
+            return self.clone();
+        }
+
+        let repr = Repr {
+            significand: self.repr.significand.clone(),
+            exponent: self.repr.exponent + exp,
+        };
+        let repr = self.context.repr_round(repr).value();
+        Self::new(repr, self.context)
     }
 }
@@ -24,13 +24,44 @@ impl<R: Round, const B: Word> FBig<R, B> {
         };
         Self::new(repr, Context::new(1))
     }
+
+    /// Get a number with the magnitude of this number and the sign of `sign_source`.
+    ///
+    /// The zero is always positive, so the result is positive if `sign_source` is zero,
+    /// and the result is zero if this number is zero. The infinities are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dashu_int::error::ParseError;
+    /// # use dashu_float::DBig;
+    /// let a = DBig::from_str_native("1.23")?;
+    /// let b = DBig::from_str_native("-4")?;
+    /// assert_eq!(a.copysign(&b), DBig::from_str_native("-1.23")?);
+    /// assert_eq!(b.copysign(&a), DBig::from_str_native("4")?);
+    /// assert_eq!(DBig::INFINITY.copysign(&b), DBig::NEG_INFINITY);
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    #[inline]
+    pub fn copysign<R2: Round>(&self, sign_source: &FBig<R2, B>) -> Self {
+        if self.repr.sign() == sign_source.repr.sign() {
+            self.clone()
+        } else {
+            -self
+        }
+    }
 }
 
 impl<const B: Word> Neg for Repr<B> {
     type Output = Self;
     #[inline]
     fn neg(mut self) -> Self::Output {
-        self.significand = -self.significand;
+        if self.is_infinite() {
+            // the sign of infinities is stored in the exponent
+            self.exponent = -self.exponent;
+        } else {
+            self.significand = -self.significand;
+        }
         self
     }
 }
@@ -39,7 +70,7 @@ impl<R: Round, const B: Word> Neg for FBig<R, B> {
     type Output = Self;
     #[inline]
     fn neg(mut self) -> Self::Output {
-        self.repr.significand = -self.repr.significand;
+        self.repr = -self.repr;
         self
     }
 }
@@ -0,0 +1,483 @@
+//! Exception flags and traps for the floating point operations.
+//!
+//! The operations associated with [Context] either panic on invalid inputs or silently round the
+//! results. The [StatusContext] in this module wraps a [Context] and reports the exceptional
+//! conditions in the style of the General Decimal Arithmetic (and the Python `decimal` module):
+//!
+//! * Each operation records the conditions it raised in the sticky [flags][StatusContext::flags],
+//!   which are only reset by [clear_flags()][StatusContext::clear_flags].
+//! * If a raised condition is enabled in the [traps][StatusContext::traps], the operation returns
+//!   an [Err] with the condition instead of a result.
+//!
+//! # Examples
+//!
+//! ```
+//! # use dashu_int::error::ParseError;
+//! # use dashu_float::DBig;
+//! use dashu_float::{Context, round::mode::HalfAway};
+//! use dashu_float::status::{Exception, Flags, StatusContext};
+//!
+//! let mut ctxt = StatusContext::new(Context::<HalfAway>::new(3));
+//! let a = DBig::from_str_native("1")?;
+//! let b = DBig::from_str_native("3")?;
+//! let c = ctxt.div(a.repr(), b.repr()).unwrap();
+//! assert_eq!(c.value(), DBig::from_str_native("0.333")?);
+//! assert!(ctxt.flags().contains(Flags::INEXACT));
+//!
+//! // division by zero is trapped by default
+//! assert_eq!(ctxt.div(a.repr(), DBig::ZERO.repr()), Err(Exception::DivisionByZero));
+//! assert!(ctxt.flags().contains(Flags::DIVISION_BY_ZERO));
+//! # Ok::<(), ParseError>(())
+//! ```
+
+use crate::{
+    fbig::FBig,
+    repr::{Context, RangeStatus, Repr, Word},
+    round::{Round, Rounded},
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{BitAnd, BitOr, BitOrAssign},
+};
+use dashu_base::{Approximation::*, Sign};
+use dashu_int::IBig;
+
+/// The exceptional conditions that can be raised by the floating point operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Exception {
+    /// The result is not exact.
+    Inexact,
+    /// The result is rounded. Since the representation of the float numbers is always normalized,
+    /// this condition is raised if and only if [Exception::Inexact] is raised.
+    Rounded,
+    /// The result is too large for the exponent range.
+    Overflow,
+    /// The result is tiny and inexact.
+    Underflow,
+    /// A finite nonzero number is divided by zero, or the logarithm of zero is taken.
+    DivisionByZero,
+    /// The operation is invalid, such as operating on infinities, taking the square root of
+    /// a negative number, or requiring unlimited precision for an inexact result.
+    InvalidOperation,
+    /// The result is subnormal (its adjusted exponent is less than the minimum exponent) before
+    /// rounding. It's raised along with [Exception::Underflow] if the result is also inexact.
+    Subnormal,
+    /// The exponent of the result is altered to fit the representation. It's only raised by the
+    /// operations in the [gda][crate::gda] module.
+    Clamped,
+}
+
+impl Exception {
+    const ALL: [Exception; 8] = [
+        Exception::InvalidOperation,
+        Exception::DivisionByZero,
+        Exception::Overflow,
+        Exception::Underflow,
+        Exception::Subnormal,
+        Exception::Inexact,
+        Exception::Rounded,
+        Exception::Clamped,
+    ];
+}
+
+impl Display for Exception {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Exception::Inexact => f.write_str("inexact result"),
+            Exception::Rounded => f.write_str("rounded result"),
+            Exception::Overflow => f.write_str("exponent overflow"),
+            Exception::Underflow => f.write_str("exponent underflow"),
+            Exception::DivisionByZero => f.write_str("division by zero"),
+            Exception::InvalidOperation => f.write_str("invalid operation"),
+            Exception::Subnormal => f.write_str("subnormal result"),
+            Exception::Clamped => f.write_str("clamped exponent"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Exception {}
+
+/// A set of [Exception]s, used as the status flags and the traps.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// The empty set
+    pub const EMPTY: Self = Flags(0);
+    /// The set with only [Exception::Inexact]
+    pub const INEXACT: Self = Self::from_exception(Exception::Inexact);
+    /// The set with only [Exception::Rounded]
+    pub const ROUNDED: Self = Self::from_exception(Exception::Rounded);
+    /// The set with only [Exception::Overflow]
+    pub const OVERFLOW: Self = Self::from_exception(Exception::Overflow);
+    /// The set with only [Exception::Underflow]
+    pub const UNDERFLOW: Self = Self::from_exception(Exception::Underflow);
+    /// The set with only [Exception::DivisionByZero]
+    pub const DIVISION_BY_ZERO: Self = Self::from_exception(Exception::DivisionByZero);
+    /// The set with only [Exception::InvalidOperation]
+    pub const INVALID_OPERATION: Self = Self::from_exception(Exception::InvalidOperation);
+    /// The set with only [Exception::Subnormal]
+    pub const SUBNORMAL: Self = Self::from_exception(Exception::Subnormal);
+    /// The set with only [Exception::Clamped]
+    pub const CLAMPED: Self = Self::from_exception(Exception::Clamped);
+    /// The set of all exceptions
+    pub const ALL: Self = Flags(0b11111111);
+
+    #[inline]
+    const fn from_exception(e: Exception) -> Self {
+        Flags(1 << e as u8)
+    }
+
+    /// Check whether all the exceptions in `other` are in this set
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check whether this set is empty
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Get the union of two sets
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Flags(self.0 | other.0)
+    }
+
+    /// Get the intersection of two sets
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Flags(self.0 & other.0)
+    }
+
+    /// Get the set with the exceptions in `other` removed
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Flags(self.0 & !other.0)
+    }
+
+    /// Iterate through the exceptions in this set, ordered by severity
+    /// (from [Exception::InvalidOperation] to [Exception::Clamped])
+    pub fn iter(self) -> impl Iterator<Item = Exception> {
+        Exception::ALL
+            .into_iter()
+            .filter(move |&e| self.contains(e.into()))
+    }
+}
+
+impl From<Exception> for Flags {
+    #[inline]
+    fn from(e: Exception) -> Self {
+        Self::from_exception(e)
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitor(self, rhs: Flags) -> Flags {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Flags) {
+        *self = self.union(rhs)
+    }
+}
+
+impl BitAnd for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitand(self, rhs: Flags) -> Flags {
+        self.intersection(rhs)
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A float operation context that records the exceptional conditions in sticky flags and
+/// converts the trapped conditions into errors.
+///
+/// The operations have the same semantics as the corresponding methods of [Context], except
+/// that the conditions that lead to panics in [Context] are reported as
+/// [Exception::InvalidOperation] or [Exception::DivisionByZero]. When not trapped, a division
+/// by zero results in an infinity. There's no NaN value for [FBig] to substitute the result of
+/// an invalid operation, so [Exception::InvalidOperation] is always trapped.
+///
+/// By default, [Exception::InvalidOperation], [Exception::DivisionByZero] and
+/// [Exception::Overflow] are trapped.
+#[derive(Clone, Copy, Debug)]
+pub struct StatusContext<R: Round> {
+    context: Context<R>,
+    flags: Flags,
+    traps: Flags,
+}
+
+/// The result of an operation in [StatusContext]
+pub type StatusResult<T> = Result<Rounded<T>, Exception>;
+
+impl<R: Round> StatusContext<R> {
+    /// The default traps
+    pub const DEFAULT_TRAPS: Flags = Flags::INVALID_OPERATION
+        .union(Flags::DIVISION_BY_ZERO)
+        .union(Flags::OVERFLOW);
+
+    /// Create a status context with the given operation context and the default traps.
+    #[inline]
+    pub const fn new(context: Context<R>) -> Self {
+        Self {
+            context,
+            flags: Flags::EMPTY,
+            traps: Self::DEFAULT_TRAPS,
+        }
+    }
+
+    /// Set the traps of the context.
+    ///
+    /// [Exception::InvalidOperation] is always trapped, regardless of whether it's in `traps`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dashu_float::{Context, round::mode::HalfAway};
+    /// use dashu_float::status::{Flags, StatusContext};
+    ///
+    /// let ctxt = StatusContext::new(Context::<HalfAway>::new(3)).with_traps(Flags::INEXACT);
+    /// assert_eq!(ctxt.traps(), Flags::INEXACT | Flags::INVALID_OPERATION);
+    /// ```
+    #[inline]
+    pub const fn with_traps(self, traps: Flags) -> Self {
+        Self {
+            context: self.context,
+            flags: self.flags,
+            traps: traps.union(Flags::INVALID_OPERATION),
+        }
+    }
+
+    /// Get the underlying operation context
+    #[inline]
+    pub const fn context(&self) -> Context<R> {
+        self.context
+    }
+
+    /// Get the sticky flags raised by the operations since the last call of [clear_flags()][Self::clear_flags]
+    #[inline]
+    pub const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Get the traps of the context
+    #[inline]
+    pub const fn traps(&self) -> Flags {
+        self.traps
+    }
+
+    /// Reset the sticky flags
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = Flags::EMPTY;
+    }
+
+    /// Record the conditions, and return the most severe one if it's trapped.
+    fn raise(&mut self, flags: Flags) -> Result<(), Exception> {
+        self.flags |= flags;
+        match (flags & self.traps).iter().next() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Raise an invalid operation. It's always trapped since the result is undefined.
+    fn invalid<T>(&mut self) -> Result<T, Exception> {
+        debug_assert!(self.traps.contains(Flags::INVALID_OPERATION));
+        self.flags |= Flags::INVALID_OPERATION;
+        Err(Exception::InvalidOperation)
+    }
+
+    /// Raise a division by zero, the result is an infinity with the given sign if not trapped.
+    fn divide_by_zero<const B: Word>(&mut self, sign: Sign) -> StatusResult<FBig<R, B>> {
+        self.raise(Flags::DIVISION_BY_ZERO)?;
+        let inf = match sign {
+            Sign::Positive => Repr::infinity(),
+            Sign::Negative => Repr::neg_infinity(),
+        };
+        Ok(Exact(FBig::from_repr(inf, self.context)))
+    }
+
+    /// Evaluate the operation, record the conditions of the result.
+    fn eval<const B: Word, F>(&mut self, f: F) -> StatusResult<FBig<R, B>>
+    where
+        F: Fn(&Context<R>) -> Rounded<FBig<R, B>>,
+    {
+        let (value, status) = self.context.with_range_status(f);
+        let mut flags = match value {
+            Exact(_) => Flags::EMPTY,
+            Inexact(..) => Flags::INEXACT | Flags::ROUNDED,
+        };
+        match status {
+            RangeStatus::Normal => {}
+            RangeStatus::Subnormal => flags |= Flags::SUBNORMAL,
+            RangeStatus::Underflow => flags |= Flags::UNDERFLOW | Flags::SUBNORMAL,
+            RangeStatus::Overflow => flags |= Flags::OVERFLOW,
+        }
+        self.raise(flags)?;
+        Ok(value)
+    }
+
+    /// Add two floating point numbers. See [Context::add].
+    pub fn add<const B: Word>(&mut self, lhs: &Repr<B>, rhs: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if lhs.is_infinite() || rhs.is_infinite() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.add(lhs, rhs))
+    }
+
+    /// Subtract two floating point numbers. See [Context::sub].
+    pub fn sub<const B: Word>(&mut self, lhs: &Repr<B>, rhs: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if lhs.is_infinite() || rhs.is_infinite() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.sub(lhs, rhs))
+    }
+
+    /// Multiply two floating point numbers. See [Context::mul].
+    pub fn mul<const B: Word>(&mut self, lhs: &Repr<B>, rhs: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if lhs.is_infinite() || rhs.is_infinite() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.mul(lhs, rhs))
+    }
+
+    /// Calculate the square of the floating point number. See [Context::square].
+    pub fn square<const B: Word>(&mut self, f: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if f.is_infinite() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.square(f))
+    }
+
+    /// Calculate the fused multiply-add `a * b + c`. See [Context::fma].
+    pub fn fma<const B: Word>(
+        &mut self,
+        a: &Repr<B>,
+        b: &Repr<B>,
+        c: &Repr<B>,
+    ) -> StatusResult<FBig<R, B>> {
+        if a.is_infinite() || b.is_infinite() || c.is_infinite() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.fma(a, b, c))
+    }
+
+    /// Divide two floating point numbers. See [Context::div].
+    ///
+    /// Dividing a nonzero number by zero raises [Exception::DivisionByZero], and dividing zero by
+    /// zero raises [Exception::InvalidOperation].
+    pub fn div<const B: Word>(&mut self, lhs: &Repr<B>, rhs: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if lhs.is_infinite() || rhs.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        if rhs.is_zero() {
+            return match lhs.is_zero() {
+                true => self.invalid(),
+                false => self.divide_by_zero(lhs.sign()),
+            };
+        }
+        self.eval(|ctxt| ctxt.div(lhs, rhs))
+    }
+
+    /// Calculate the square root of the floating point number. See [Context::sqrt].
+    pub fn sqrt<const B: Word>(&mut self, x: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if x.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        if x.sign() == Sign::Negative && !x.is_zero() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.sqrt(x))
+    }
+
+    /// Calculate the Euclidean norm `sqrt(a² + b²)`. See [Context::hypot].
+    pub fn hypot<const B: Word>(&mut self, a: &Repr<B>, b: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if a.is_infinite() || b.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.hypot(a, b))
+    }
+
+    /// Raise the floating point number to an integer power. See [Context::powi].
+    ///
+    /// Raising zero to a negative power raises [Exception::DivisionByZero].
+    pub fn powi<const B: Word>(&mut self, base: &Repr<B>, exp: IBig) -> StatusResult<FBig<R, B>> {
+        if base.is_infinite() {
+            return self.invalid();
+        }
+        if exp.sign() == Sign::Negative {
+            if !self.context.is_limited() {
+                return self.invalid();
+            }
+            if base.is_zero() {
+                return self.divide_by_zero(Sign::Positive);
+            }
+        }
+        self.eval(|ctxt| ctxt.powi(base, exp.clone()))
+    }
+
+    /// Calculate the exponential function of the floating point number. See [Context::exp].
+    pub fn exp<const B: Word>(&mut self, x: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if x.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.exp(x))
+    }
+
+    /// Calculate the exponential minus one of the floating point number. See [Context::exp_m1].
+    pub fn exp_m1<const B: Word>(&mut self, x: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if x.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.exp_m1(x))
+    }
+
+    /// Calculate the natural logarithm of the floating point number. See [Context::ln].
+    ///
+    /// The logarithm of zero raises [Exception::DivisionByZero], and the logarithm of a
+    /// negative number raises [Exception::InvalidOperation].
+    pub fn ln<const B: Word>(&mut self, x: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if x.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        if x.is_zero() {
+            return self.divide_by_zero(Sign::Negative);
+        }
+        if x.sign() == Sign::Negative {
+            return self.invalid();
+        }
+        self.eval(|ctxt| ctxt.ln(x))
+    }
+
+    /// Calculate the natural logarithm of one plus the floating point number. See [Context::ln_1p].
+    ///
+    /// The input `-1` raises [Exception::DivisionByZero], and the inputs less than `-1` raise
+    /// [Exception::InvalidOperation].
+    pub fn ln_1p<const B: Word>(&mut self, x: &Repr<B>) -> StatusResult<FBig<R, B>> {
+        if x.is_infinite() || !self.context.is_limited() {
+            return self.invalid();
+        }
+        match x.cmp(&Repr::neg_one()) {
+            Ordering::Less => self.invalid(),
+            Ordering::Equal => self.divide_by_zero(Sign::Negative),
+            Ordering::Greater => self.eval(|ctxt| ctxt.ln_1p(x)),
+        }
+    }
+}
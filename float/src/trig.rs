@@ -0,0 +1,214 @@
+//! Trigonometric functions for internal use (mainly for the complex numbers)
+
+use dashu_base::{Abs, DivRemEuclid, EstimatedLog2, RootRem, Sign};
+use dashu_int::IBig;
+
+use crate::{
+    fbig::FBig,
+    repr::{Context, Repr, Word},
+    round::Round,
+};
+
+impl<R: Round> Context<R> {
+    /// Number of guard digits used in the trigonometric functions
+    #[inline]
+    fn trig_guard_digits<const B: Word>(&self) -> usize {
+        2 * (self.precision.log2_est() / B.log2_est()) as usize + 4
+    }
+
+    /// Calculate π
+    ///
+    /// The precision of the output will be larger than self.precision
+    pub(crate) fn pi<const B: Word>(&self) -> FBig<R, B> {
+        // Machin's formula: π = 16 atan(1/5) - 4 atan(1/239)
+        16 * self.iacot(5.into()) - 4 * self.iacot(239.into())
+    }
+
+    /// Calculate atan(1/n)
+    ///
+    /// This method is intended to be used in the calculation of π,
+    /// so the precision of the output will be larger than desired precision.
+    fn iacot<const B: Word>(&self, n: IBig) -> FBig<R, B> {
+        // use Maclaurin series: atan(1/n) = Σ (-1)ⁱ / (n²ⁱ⁺¹(2i+1)), similar to iacoth
+        let guard_digits = (self.precision.log2_est() / B.log2_est()) as usize;
+        let work_context = Self::new(self.precision + guard_digits + 2);
+
+        let n = work_context.convert_int(n).value();
+        let inv = FBig::ONE / n;
+        let inv2 = inv.square();
+        let mut sum = inv.clone();
+        let mut pow = inv;
+
+        let mut k: usize = 3;
+        loop {
+            pow *= &inv2;
+            let term = &pow / work_context.convert_int::<B>(k.into()).value();
+            let next = if k % 4 == 3 {
+                &sum - &term
+            } else {
+                &sum + &term
+            };
+
+            if next == sum || term.is_below_ulp_of(&next) {
+                return next;
+            }
+            sum = next;
+            k += 2;
+        }
+    }
+
+    /// Calculate atan(x)
+    ///
+    /// The precision of the output will be larger than self.precision
+    pub(crate) fn atan<const B: Word>(&self, x: &Repr<B>) -> FBig<R, B> {
+        if x.is_zero() {
+            return FBig::new(Repr::zero(), *self);
+        }
+        if x.is_infinite() {
+            let half_pi = self.pi::<B>() / 2;
+            return match x.sign() {
+                Sign::Positive => half_pi,
+                Sign::Negative => -half_pi,
+            };
+        }
+
+        let work_context = Self::new(self.precision + self.trig_guard_digits::<B>());
+        let x = FBig::new(work_context.repr_round_ref(x).value(), work_context);
+        let sign = x.repr.sign();
+        let mut x = x.abs();
+
+        // use atan(x) = π/2 - atan(1/x) to make sure that x <= 1
+        let invert = x > FBig::<R, B>::ONE;
+        if invert {
+            x = FBig::ONE / x;
+        }
+
+        // reduce the argument with atan(x) = 2atan(x / (1 + √(1 + x²))) until x < 2^-√p,
+        // where p is the precision in bits
+        let bits = (self.precision as f32 * B.log2_est()) as u64;
+        let threshold = -(bits.sqrt_rem().0.max(4) as f32);
+        let mut doublings = 0;
+        while !x.repr.is_zero() && x.log2_est() > threshold {
+            let d = FBig::ONE + (FBig::ONE + x.square()).sqrt();
+            x /= d;
+            doublings += 1;
+        }
+
+        // Maclaurin series: atan(x) = Σ (-1)ⁱ x²ⁱ⁺¹ / (2i+1)
+        let x2 = x.square();
+        let mut pow = x.clone();
+        let mut sum = x;
+        let mut k: usize = 3;
+        loop {
+            pow *= &x2;
+            let term = &pow / work_context.convert_int::<B>(k.into()).value();
+            let next = if k % 4 == 3 {
+                &sum - &term
+            } else {
+                &sum + &term
+            };
+
+            if next == sum || term.is_below_ulp_of(&next) {
+                sum = next;
+                break;
+            }
+            sum = next;
+            k += 2;
+        }
+
+        let mut result = sum * (IBig::ONE << doublings);
+        if invert {
+            result = work_context.pi::<B>() / 2 - result;
+        }
+        match sign {
+            Sign::Positive => result,
+            Sign::Negative => -result,
+        }
+    }
+
+    /// Calculate atan2(y, x), i.e. the angle of the point (x, y) in (-π, π].
+    ///
+    /// The precision of the output will be larger than self.precision
+    pub(crate) fn atan2<const B: Word>(&self, y: &Repr<B>, x: &Repr<B>) -> FBig<R, B> {
+        if x.is_zero() {
+            return match (y.is_zero(), y.sign()) {
+                (true, _) => FBig::new(Repr::zero(), *self),
+                (false, Sign::Positive) => self.pi::<B>() / 2,
+                (false, Sign::Negative) => -self.pi::<B>() / 2,
+            };
+        }
+
+        let work_context = Self::new(self.precision + self.trig_guard_digits::<B>());
+        let angle = work_context.atan(work_context.div(y, x).value().repr());
+        match (x.sign(), y.sign()) {
+            (Sign::Positive, _) => angle,
+            (Sign::Negative, Sign::Positive) => angle + work_context.pi::<B>(),
+            (Sign::Negative, Sign::Negative) => angle - work_context.pi::<B>(),
+        }
+    }
+
+    /// Calculate (sin(x), cos(x))
+    ///
+    /// The precision of the outputs will be larger than self.precision
+    pub(crate) fn sin_cos<const B: Word>(&self, x: &Repr<B>) -> (FBig<R, B>, FBig<R, B>) {
+        if x.is_zero() {
+            return (FBig::new(Repr::zero(), *self), FBig::new(Repr::one(), *self));
+        }
+
+        // The digits of the quotient x / (π/2) are lost during the argument reduction, and more
+        // digits are lost if x is close to a multiple of π/2. The working precision is increased
+        // until the reduced argument has enough significant digits.
+        let guard_digits = self.trig_guard_digits::<B>();
+        let int_digits = (x.exponent + x.digits() as isize).max(0) as usize;
+        let mut extra_digits = int_digits;
+        let (q, r, work_context) = loop {
+            let work_context = Self::new(self.precision + guard_digits + extra_digits);
+            let x = FBig::new(work_context.repr_round_ref(x).value(), work_context);
+            let half_pi = work_context.pi::<B>() / 2;
+            let (mut q, mut r) = x.div_rem_euclid(&half_pi);
+
+            // make sure that r is in [-π/4, π/4]
+            if 2 * &r > half_pi {
+                r -= half_pi;
+                q += IBig::ONE;
+            }
+
+            let lost_digits = if r.repr.is_zero() {
+                extra_digits + guard_digits
+            } else {
+                (-r.log2_est() / B.log2_est()).max(0.) as usize
+            };
+            if int_digits + lost_digits <= extra_digits {
+                break (q, r, work_context);
+            }
+            extra_digits = int_digits + lost_digits + 1;
+        };
+
+        // Maclaurin series: sin(r) = Σ (-1)ⁱ r²ⁱ⁺¹ / (2i+1)!, cos(r) = Σ (-1)ⁱ r²ⁱ / (2i)!
+        let mut sin = r.clone();
+        let mut cos = FBig::new(Repr::one(), work_context);
+        let mut term = r.clone();
+        let mut k: usize = 1;
+        while !term.repr.is_zero() {
+            k += 1;
+            term = term * &r / k;
+            match k % 4 {
+                0 => cos += &term,
+                1 => sin += &term,
+                2 => cos -= &term,
+                _ => sin -= &term,
+            }
+            if term.is_below_ulp_of(&sin) && term.is_below_ulp_of(&cos) {
+                break;
+            }
+        }
+
+        let (_, quadrant) = q.div_rem_euclid(IBig::from(4));
+        match u8::try_from(quadrant).unwrap() {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+}
@@ -90,6 +90,10 @@ fn split_bits_ref(value: &IBig, n: usize) -> (IBig, IBig) {
 
     let (sign, words) = value.as_sign_words();
     let n_words = n / Word::BITS as usize;
+    if n_words >= words.len() {
+        // all bits are in the low part
+        return (IBig::ZERO, value.clone());
+    }
 
     let mut hi = UBig::from_words(&words[n_words..]);
     hi >>= n % Word::BITS as usize;
@@ -142,6 +146,27 @@ pub fn split_digits<const B: Word>(value: IBig, pos: usize) -> (IBig, IBig) {
     }
 }
 
+/// Calculate the integer square root and the remainder, i.e. `(s, r)` such that
+/// `s = floor(sqrt(n))` and `r = n - s^2`.
+pub fn sqrt_rem(n: &UBig) -> (UBig, UBig) {
+    if n.is_zero() {
+        return (UBig::ZERO, UBig::ZERO);
+    }
+
+    // Newton's iteration x' = (x + n/x) / 2 starting from an over-estimation
+    // converges monotonically from above to floor(sqrt(n))
+    let mut x = UBig::ONE << ((n.bit_len() + 1) / 2);
+    loop {
+        let y = (&x + n / &x) >> 1;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    let rem = n - x.square();
+    (x, rem)
+}
+
 /// If n is a power of base, then return the exponent,
 /// otherwise return 0.
 pub const fn ilog_exact(n: Word, base: Word) -> u32 {
@@ -181,6 +206,22 @@ mod tests {
         assert_eq!(-shr_ref(&a, 1000), (&a).unsigned_abs() >> 1000);
     }
 
+    #[test]
+    fn test_sqrt_rem() {
+        assert_eq!(sqrt_rem(&UBig::ZERO), (UBig::ZERO, UBig::ZERO));
+        assert_eq!(sqrt_rem(&UBig::ONE), (UBig::ONE, UBig::ZERO));
+        assert_eq!(sqrt_rem(&UBig::from(99u8)), (UBig::from(9u8), UBig::from(18u8)));
+        assert_eq!(sqrt_rem(&UBig::from(100u8)), (UBig::from(10u8), UBig::ZERO));
+
+        let a = UBig::from(0x1234567890abcdefu64).pow(12);
+        let (s, r) = sqrt_rem(&a);
+        assert_eq!(s, UBig::from(0x1234567890abcdefu64).pow(6));
+        assert!(r.is_zero());
+        let (s, r) = sqrt_rem(&(&a - UBig::ONE));
+        assert_eq!(&s + UBig::ONE, UBig::from(0x1234567890abcdefu64).pow(6));
+        assert_eq!(r, &s << 1);
+    }
+
     #[test]
     fn test_split_bits_ref() {
         let a = IBig::from(0x1234567890abcdefu64).pow(12);
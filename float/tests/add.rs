@@ -303,3 +303,104 @@ fn test_add_sub_unlimited_precision() {
     test_add(&a, &dbig!(1e-4), &a);
     test_add(&a, &b, &dbig!(100000001e-4));
 }
+
+#[test]
+fn test_add_rounding_modes() {
+    use dashu_float::round::mode::{HalfDown, HalfOdd, ToOdd, Up};
+
+    let (a, b) = (dbig!(100), dbig!(0.5));
+    assert_eq!(
+        Context::<HalfDown>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(100).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(101).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(3).sub((-&a).repr(), b.repr()),
+        Inexact(dbig!(-101).with_rounding(), SubOne)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(3).add(dbig!(101).repr(), b.repr()),
+        Inexact(dbig!(101).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(101).with_rounding(), AddOne)
+    );
+
+    // the operands are far apart
+    let b = dbig!(1e-20);
+    assert_eq!(
+        Context::<HalfDown>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(100).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<HalfDown>::new(3).sub(a.repr(), b.repr()),
+        Inexact(dbig!(100).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<Up>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(101).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(3).add(a.repr(), b.repr()),
+        Inexact(dbig!(101).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(3).sub(a.repr(), b.repr()),
+        Inexact(dbig!(99.9).with_rounding(), SubOne)
+    );
+}
+
+#[test]
+fn test_sum() {
+    let empty: [dashu_float::DBig; 0] = [];
+    assert_eq!(empty.iter().sum::<dashu_float::DBig>(), dbig!(0));
+
+    // the terms are accumulated exactly before rounding
+    let terms = [dbig!(1e10), dbig!(123e-2), dbig!(-1e10)];
+    assert_eq!(&terms[0] + &terms[1] + &terms[2], dbig!(0));
+    assert_eq!(terms.iter().sum::<dashu_float::DBig>(), dbig!(123e-2));
+    assert_eq!(terms.into_iter().sum::<dashu_float::DBig>(), dbig!(123e-2));
+
+    let terms = [
+        fbig!(0x1p100),
+        fbig!(0x1),
+        fbig!(-0x1p100),
+        fbig!(0x1p-100),
+    ];
+    let sum = terms.iter().sum::<dashu_float::FBig>();
+    assert_eq!(sum, fbig!(0x1));
+    assert_eq!(sum.precision(), fbig!(0x1p100).precision());
+
+    let context = Context::new(2);
+    let terms = [dbig!(1e10), dbig!(1234e-3), dbig!(-1e10)];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Inexact(dbig!(12e-1), NoOp));
+    let context = Context::new(1);
+    let terms = [dbig!(1e-1000), dbig!(15e-1)];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Inexact(dbig!(2), AddOne));
+    let terms = [dbig!(-1e-1000), dbig!(15e-1)];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Inexact(dbig!(1), NoOp));
+    assert_eq!(context.sum([dbig!(1).repr(), dbig!(-1).repr()]), Exact(dbig!(0)));
+
+    // the memory doesn't depend on the spread of the exponents
+    let huge = dbig!(1e100000000);
+    let tiny = dbig!(1e-100000000);
+    let context = Context::new(5);
+    let terms = [huge.clone(), tiny.clone(), dbig!(3), -huge.clone()];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Inexact(dbig!(3), NoOp));
+    let terms = [huge.clone(), -tiny.clone(), -huge.clone(), dbig!(3)];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Inexact(dbig!(3), AddOne));
+    let terms = [huge.clone(), tiny.clone(), -huge];
+    assert_eq!(context.sum(terms.iter().map(|t| t.repr())), Exact(tiny));
+}
+
+#[test]
+#[should_panic]
+fn test_sum_with_inf() {
+    let _ = [dashu_float::DBig::ONE, dashu_float::DBig::INFINITY]
+        .iter()
+        .sum::<dashu_float::DBig>();
+}
@@ -0,0 +1,166 @@
+use dashu_float::{Ball, DBig, Interval};
+
+mod helper_macros;
+
+fn ball(mid: DBig, rad: DBig) -> Ball<10> {
+    Ball::new(mid, rad)
+}
+
+fn assert_ball(x: &Ball<10>, mid: DBig, rad: DBig) {
+    assert_eq!(x.mid(), &mid, "midpoint of {}", x);
+    assert_eq!(x.rad(), &rad, "radius of {}", x);
+}
+
+/// Check that the images of the endpoints of the ball under the function are enclosed
+fn assert_encloses(x: &Ball<10>, y: &Ball<10>, f: impl Fn(DBig) -> DBig) {
+    let mid: DBig = x.mid().clone().with_rounding().with_precision(60).value();
+    let rad: DBig = x.rad().clone().with_rounding();
+    for v in [&mid - &rad, mid.clone(), &mid + &rad] {
+        let image = f(v);
+        assert!(y.contains(&image), "{} is not in {}", image, y);
+    }
+}
+
+#[test]
+fn test_construct() {
+    let a = ball(dbig!(1.5), dbig!(0.5));
+    assert_eq!(a.to_string(), "[1.5 +/- 0.5]");
+    assert!(!a.is_exact() && !a.contains_zero());
+    assert!(a.contains(&dbig!(1)) && a.contains(&dbig!(2)));
+    assert!(!a.contains(&dbig!(0.99)) && !a.contains(&DBig::INFINITY));
+    assert!(ball(dbig!(-1), dbig!(1)).contains_zero());
+
+    let b = Ball::exact(dbig!(-2));
+    assert!(b.is_exact() && b.contains(&dbig!(-2)));
+    assert_eq!(b.precision(), 1);
+    assert_eq!(-&b, Ball::exact(dbig!(2)));
+
+    // the radius is rounded up to the radius precision
+    assert_eq!(Ball::<10>::RADIUS_PRECISION, 10);
+    assert_eq!(Ball::<2>::RADIUS_PRECISION, 30);
+    assert_eq!(Ball::<16>::RADIUS_PRECISION, 8);
+    let c = ball(dbig!(1), dbig!(0.123456789012));
+    assert_eq!(c.rad(), &dbig!(0.1234567891));
+
+    let (mid, rad) = c.into_parts();
+    assert_eq!((mid, rad), (dbig!(1).with_rounding(), dbig!(0.1234567891).with_rounding()));
+}
+
+#[test]
+#[should_panic]
+fn test_negative_radius() {
+    let _ = ball(dbig!(1), dbig!(-1));
+}
+
+#[test]
+#[should_panic]
+fn test_infinite_ball() {
+    let _ = Ball::exact(DBig::INFINITY);
+}
+
+#[test]
+fn test_add_sub() {
+    let a = ball(dbig!(1.0), dbig!(0.1));
+    let b = ball(dbig!(2.0), dbig!(0.2));
+    assert_ball(&(&a + &b), dbig!(3.0), dbig!(0.3));
+    assert_ball(&(&a - &b), dbig!(-1.0), dbig!(0.3));
+
+    // the rounding error of the midpoint is added to the radius
+    let c = Ball::exact(dbig!(1.0));
+    let d = Ball::exact(dbig!(1e-2));
+    assert_ball(&(&c + &d), dbig!(1.0), dbig!(0.1));
+    assert_ball(&(&c - &d), dbig!(0.99), dbig!(0));
+}
+
+#[test]
+fn test_mul() {
+    let a = ball(dbig!(2), dbig!(0.1));
+    let b = ball(dbig!(-3), dbig!(0.2));
+    assert_ball(&(&a * &b), dbig!(-6), dbig!(0.72));
+    assert_ball(&(&a * Ball::exact(dbig!(0))), dbig!(0), dbig!(0));
+
+    let c = Ball::exact(dbig!(1.1));
+    assert_ball(&(&c * &c), dbig!(1.2), dbig!(0.1));
+}
+
+#[test]
+fn test_div() {
+    assert_ball(&(Ball::exact(dbig!(6)) / Ball::exact(dbig!(3))), dbig!(2), dbig!(0));
+
+    let a = ball(dbig!(1), dbig!(0.1));
+    let b = ball(dbig!(2), dbig!(0.5));
+    let c = &a / &b;
+    assert_ball(&c, dbig!(0.5), dbig!(0.2333333334));
+    for (x, y) in [(dbig!(0.9), dbig!(2.5)), (dbig!(1.1), dbig!(1.5))] {
+        assert!(c.contains(&(x.with_precision(10).value() / y)));
+    }
+
+    let third = Ball::exact(dbig!(1.0000)) / Ball::exact(dbig!(3));
+    assert_ball(&third, dbig!(0.33333), dbig!(0.00001));
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = Ball::exact(dbig!(1)) / ball(dbig!(1), dbig!(1));
+}
+
+#[test]
+fn test_functions() {
+    let a = ball(dbig!(1.2345678901234567890), dbig!(1e-18));
+    let small = dbig!(1e-16);
+
+    let sqrt = a.sqrt();
+    assert_encloses(&a, &sqrt, |v| v.sqrt());
+    assert!(sqrt.rad() < &small);
+
+    let exp = a.exp();
+    assert_encloses(&a, &exp, |v| v.exp());
+    assert!(exp.rad() < &small);
+
+    let ln = a.ln();
+    assert_encloses(&a, &ln, |v| v.ln());
+    assert!(ln.rad() < &small);
+
+    for n in [1, 2, 7, -3] {
+        let pow = a.powi(n.into());
+        assert_encloses(&a, &pow, |v| v.powi(n.into()));
+        assert!(pow.rad() < &small);
+    }
+    assert_ball(&a.powi(0.into()), dbig!(1), dbig!(0));
+
+    // exact results have zero radius
+    assert_ball(&Ball::exact(dbig!(0.25)).sqrt(), dbig!(0.5), dbig!(0));
+    assert_ball(&Ball::exact(dbig!(1.50)).powi(2.into()), dbig!(2.25), dbig!(0));
+    assert_ball(&Ball::exact(dbig!(1.0)).ln(), dbig!(0), dbig!(0));
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = ball(dbig!(1), dbig!(2)).sqrt();
+}
+
+#[test]
+#[should_panic]
+fn test_ln_non_positive() {
+    let _ = ball(dbig!(1), dbig!(1)).ln();
+}
+
+#[test]
+fn test_to_interval() {
+    let a = Interval::from(ball(dbig!(1.00), dbig!(0.001)));
+    assert_eq!(a.to_string(), "[0.999, 1.01]");
+
+    let b = Interval::from(Ball::exact(dbig!(-1.5)));
+    assert!(b.is_point());
+}
+
+#[test]
+fn test_binary() {
+    let third = Ball::exact(fbig!(0x1p-1)) / Ball::exact(fbig!(0x3));
+    assert!(!third.is_exact());
+    assert!(third.rad() <= &fbig!(0x1p-4));
+    let one = &third * Ball::exact(fbig!(0x6));
+    assert!(one.contains(&fbig!(0x1)));
+}
@@ -1,6 +1,11 @@
-use core::cmp::Ordering;
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use std::collections::{hash_map::DefaultHasher, HashMap};
 
 use dashu_float::DBig;
+use dashu_int::{IBig, UBig};
 type FBig = dashu_float::FBig;
 
 mod helper_macros;
@@ -20,6 +25,8 @@ fn test_eq_binary() {
     assert_ne!(FBig::INFINITY, fbig!(0x1));
     assert_ne!(FBig::NEG_INFINITY, fbig!(-0x1));
     assert_ne!(FBig::INFINITY, FBig::NEG_INFINITY);
+    assert_eq!(-FBig::INFINITY, FBig::NEG_INFINITY);
+    assert_eq!(-FBig::NEG_INFINITY, FBig::INFINITY);
 }
 
 #[test]
@@ -132,3 +139,180 @@ fn test_cmp_decimal() {
     assert!(dbig!(1234e4) < dbig!(12345678));
     assert!(dbig!(-1234e-4) > dbig!(-12345678e-8));
 }
+
+#[test]
+fn test_total_cmp() {
+    assert_eq!(dbig!(1.2).total_cmp(&dbig!(1.20)), Ordering::Less);
+    assert_eq!(dbig!(1.20).total_cmp(&dbig!(1.2)), Ordering::Greater);
+    assert_eq!(dbig!(1.2).total_cmp(&dbig!(1.2)), Ordering::Equal);
+    assert_eq!(dbig!(-1.2).total_cmp(&dbig!(1.2)), Ordering::Less);
+    assert_eq!(DBig::INFINITY.total_cmp(&dbig!(1e100)), Ordering::Greater);
+    assert_eq!(DBig::NEG_INFINITY.total_cmp(&DBig::NEG_INFINITY), Ordering::Equal);
+    assert_eq!(DBig::ZERO.total_cmp(&dbig!(0.0)), Ordering::Less);
+
+    let mut values = [
+        dbig!(2),
+        dbig!(1.50),
+        DBig::NEG_INFINITY,
+        dbig!(1.5),
+        DBig::INFINITY,
+    ];
+    values.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(values[1].precision(), 2);
+    assert_eq!(values[2].precision(), 3);
+    assert_eq!(
+        values,
+        [
+            DBig::NEG_INFINITY,
+            dbig!(1.5),
+            dbig!(1.5),
+            dbig!(2),
+            DBig::INFINITY
+        ]
+    );
+}
+
+#[test]
+fn test_min_max_clamp() {
+    assert_eq!(dbig!(1).min(dbig!(2)), dbig!(1));
+    assert_eq!(dbig!(1).max(DBig::INFINITY), DBig::INFINITY);
+    assert_eq!(dbig!(1).min(DBig::NEG_INFINITY), DBig::NEG_INFINITY);
+    assert_eq!(DBig::INFINITY.clamp(dbig!(-1), dbig!(1)), dbig!(1));
+    assert_eq!(DBig::NEG_INFINITY.clamp(dbig!(-1), dbig!(1)), dbig!(-1));
+    assert_eq!(dbig!(0.5).clamp(DBig::NEG_INFINITY, DBig::INFINITY), dbig!(0.5));
+}
+
+#[test]
+fn test_copysign() {
+    assert_eq!(dbig!(1.5).copysign(&dbig!(-2)), dbig!(-1.5));
+    assert_eq!(dbig!(-1.5).copysign(&dbig!(2)), dbig!(1.5));
+    assert_eq!(dbig!(-1.5).copysign(&dbig!(-2)), dbig!(-1.5));
+    assert_eq!(dbig!(-1.5).copysign(&DBig::ZERO), dbig!(1.5));
+    assert_eq!(DBig::ZERO.copysign(&dbig!(-1)), DBig::ZERO);
+    assert_eq!(DBig::INFINITY.copysign(&DBig::NEG_INFINITY), DBig::NEG_INFINITY);
+    assert_eq!(fbig!(0x3).copysign(&FBig::NEG_INFINITY), fbig!(-0x3));
+}
+
+#[test]
+fn test_cmp_int() {
+    assert_eq!(fbig!(0x1p12), 4096);
+    assert_eq!(4096u16, fbig!(0x1p12));
+    assert_ne!(fbig!(0x1001), 4096);
+    assert_eq!(dbig!(-12e2), IBig::from(-1200));
+    assert_eq!(UBig::from(1200u16), dbig!(12e2));
+    assert_eq!(DBig::ZERO, 0i8);
+    assert_ne!(DBig::INFINITY, u128::MAX);
+
+    assert!(dbig!(1.5) > 1);
+    assert!(dbig!(1.5) < 2u8);
+    assert!(dbig!(-1.5) < -1);
+    let (neg_two, ten) = (IBig::from(-2), UBig::from(10u8));
+    assert!(dbig!(-1.5) > neg_two);
+    assert!(-1 > dbig!(-1.5));
+    assert!(ten < dbig!(1.00001e1));
+    assert!(dbig!(1e-100) > 0);
+    assert!(dbig!(-1e-100) < 0usize);
+    assert!(DBig::INFINITY > i128::MAX);
+    assert!(DBig::NEG_INFINITY < i128::MIN);
+
+    // comparison is exact regardless of the precision
+    let a = DBig::from_parts(IBig::from(u128::MAX) * 10 + 1, -1);
+    assert!(a > u128::MAX);
+    assert!(a < IBig::from(u128::MAX) + 1);
+    let a = a.with_precision(5).value();
+    assert_eq!(a, dbig!(3.4028e38));
+    assert!(a < u128::MAX);
+}
+
+#[test]
+fn test_cmp_float() {
+    assert_eq!(fbig!(0x3p-1), 1.5f32);
+    assert_eq!(1.5f64, dbig!(1.5));
+    assert_ne!(dbig!(0.1), 0.1f64);
+    assert!(dbig!(0.1) < 0.1f64);
+    assert!(dbig!(0.3) < 0.3f32);
+    assert!(dbig!(0.7) > 0.7f64);
+    assert!(0.1f32 > dbig!(0.1));
+    assert!(dbig!(1e400) > f64::MAX);
+    assert!(dbig!(-1e400) < f64::MIN);
+    assert!(dbig!(1e-400) > 0f64);
+    assert!(dbig!(1e-400) < f64::from_bits(1));
+    assert_eq!(DBig::ZERO, -0f64);
+
+    assert_eq!(DBig::INFINITY, f64::INFINITY);
+    assert_eq!(DBig::NEG_INFINITY, f32::NEG_INFINITY);
+    assert!(DBig::INFINITY > f64::MAX);
+    assert!(f64::INFINITY > dbig!(1e400));
+
+    // NaN is not comparable
+    assert_ne!(dbig!(1), f64::NAN);
+    assert_ne!(f32::NAN, DBig::INFINITY);
+    assert_eq!(dbig!(1).partial_cmp(&f64::NAN), None);
+    assert_eq!(f64::NAN.partial_cmp(&DBig::ZERO), None);
+}
+
+#[test]
+fn test_cmp_any_base() {
+    assert_eq!(fbig!(0x1p-2), dbig!(0.25));
+    assert_eq!(dbig!(-1.25e2), fbig!(-0x7dp0));
+    assert!(fbig!(0x1p-1) > dbig!(0.4999999999999999999));
+    assert!(dbig!(1e100) > fbig!(0x1p332));
+    assert!(dbig!(1e100) < fbig!(0x1p333));
+    assert!(dbig!(-1e-100) < fbig!(-0x1p-333));
+    assert!(dbig!(-1e-100) > fbig!(-0x1p-332));
+    assert!(FBig::NEG_INFINITY < dbig!(-1e1000));
+    assert!(DBig::INFINITY > fbig!(0x1p10000));
+    assert_eq!(FBig::INFINITY, DBig::INFINITY);
+
+    // values that can only be distinguished by the exact comparison
+    let third = dbig!(0.3333333333333333333333333333333333333333);
+    let bin = FBig::from_parts(IBig::from(0x5555555555555555u64), -64);
+    assert!(bin < third);
+    assert!(bin.clone() + FBig::from_parts(IBig::ONE, -64) > third);
+
+    type OBig = dashu_float::FBig<dashu_float::round::mode::Zero, 8>;
+    let oct = OBig::from_parts(IBig::from(5), -1); // 0.625
+    assert_eq!(oct.cmp_any_base(&dbig!(0.625)), Ordering::Equal);
+    assert_eq!(oct.cmp_any_base(&fbig!(0x5p-3)), Ordering::Equal);
+    assert_eq!(oct.cmp_any_base(&oct), Ordering::Equal);
+    assert_eq!(oct.cmp_any_base(&dbig!(0.6251)), Ordering::Less);
+    assert_eq!(oct.cmp_any_base(&fbig!(-0x1p10)), Ordering::Greater);
+    assert_eq!(oct.cmp_any_base(&DBig::ZERO), Ordering::Greater);
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_hash() {
+    // the hash is independent of the precision
+    let (a, b, c) = (dbig!(1.0), dbig!(1.00), dbig!(10e-1));
+    assert_eq!(a.precision(), 2);
+    assert_eq!(b.precision(), 3);
+    assert_eq!(hash(&a), hash(&b));
+    assert_eq!(hash(&a), hash(&c));
+    assert_eq!(hash(&a), hash(&DBig::ONE));
+    assert_eq!(hash(&a), hash(&DBig::ONE.with_precision(0).value()));
+    assert_ne!(hash(&a), hash(&dbig!(1.01)));
+
+    // results of the operations are normalized
+    assert_eq!(hash(&(dbig!(0.5) + dbig!(0.5))), hash(&a));
+    assert_eq!(hash(&(dbig!(2.50) * dbig!(0.4))), hash(&a));
+    assert_eq!(hash(&(dbig!(1e2) / dbig!(1e2))), hash(&a));
+    assert_eq!(hash(&(fbig!(0x3p-1) - fbig!(0x1p-1))), hash(&FBig::ONE));
+    assert_eq!(hash(&dbig!(0.0)), hash(&DBig::ZERO));
+    assert_eq!(hash(&dbig!(-0e10)), hash(&DBig::ZERO));
+    assert_eq!(hash(&DBig::INFINITY), hash(&-DBig::NEG_INFINITY));
+    assert_ne!(hash(&DBig::INFINITY), hash(&DBig::NEG_INFINITY));
+
+    let mut map = HashMap::new();
+    *map.entry(dbig!(1.0)).or_insert(0) += 1;
+    *map.entry(dbig!(1.000)).or_insert(0) += 1;
+    *map.entry(dbig!(10e-1)).or_insert(0) += 1;
+    *map.entry(dbig!(2)).or_insert(0) += 1;
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&DBig::ONE], 3);
+}
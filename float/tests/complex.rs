@@ -0,0 +1,188 @@
+use core::str::FromStr;
+use dashu_base::Abs;
+use dashu_float::{round::mode, CBig, DBig, FBig};
+
+mod helper_macros;
+
+type CDBig = CBig<mode::HalfAway, 10>;
+
+fn cbig(re: DBig, im: DBig, precision: usize) -> CDBig {
+    CBig::new(re, im).with_precision(precision)
+}
+
+/// Assert that the difference between the number and the expected value is less than 1 ulp
+fn assert_close(actual: &DBig, expected: &str) {
+    let expected = DBig::from_str(expected).unwrap();
+    let diff = (actual - expected.clone().with_precision(0).value())
+        .with_precision(0)
+        .value();
+    assert!(
+        diff.abs() <= actual.ulp().with_precision(0).value(),
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+fn assert_complex_close(actual: &CDBig, re: &str, im: &str) {
+    assert_close(actual.re(), re);
+    assert_close(actual.im(), im);
+}
+
+#[test]
+fn test_basic() {
+    let a = cbig(dbig!(1.5), dbig!(-2), 4);
+    assert_eq!(a.to_string(), "1.5-2i");
+    assert_eq!(a.re(), &dbig!(1.5));
+    assert_eq!(a.im(), &dbig!(-2));
+    assert_eq!(a.precision(), 4);
+    assert_eq!(a.conj(), cbig(dbig!(1.5), dbig!(2), 4));
+    assert_eq!(-&a, cbig(dbig!(-1.5), dbig!(2), 4));
+    assert_eq!(a.norm(), dbig!(6.25));
+    assert_eq!(a.abs(), dbig!(2.5));
+    assert!(!a.is_zero() && CDBig::ZERO.is_zero());
+
+    let b = CDBig::from(dbig!(3));
+    assert_eq!(b.to_string(), "3+0i");
+    assert_eq!(b.into_parts(), (dbig!(3), DBig::ZERO));
+    assert_eq!(CDBig::I.to_string(), "0+1i");
+    assert_eq!(&CDBig::I * &CDBig::I, -CDBig::ONE);
+
+    // the magnitude of a purely real or imaginary number doesn't require limited precision
+    assert_eq!(CDBig::ZERO.abs(), DBig::ZERO);
+    assert_eq!((-&CDBig::I).abs(), DBig::ONE);
+    let c = CDBig::from(dbig!(-123.45).with_precision(0).value());
+    assert_eq!(c.abs(), dbig!(123.45));
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = cbig(dbig!(3), dbig!(4), 3);
+    let b = cbig(dbig!(1), dbig!(-2), 3);
+    assert_eq!(&a + &b, cbig(dbig!(4), dbig!(2), 3));
+    assert_eq!(&a - &b, cbig(dbig!(2), dbig!(6), 3));
+    assert_eq!(&a * &b, cbig(dbig!(11), dbig!(-2), 3));
+    assert_eq!(&a / &b, cbig(dbig!(-1), dbig!(2), 3));
+
+    // each part is correctly rounded
+    let c = cbig(dbig!(1.23), dbig!(4.56), 3);
+    let d = cbig(dbig!(7.89), dbig!(-0.12), 3);
+    // (1.23 + 4.56i)(7.89 - 0.12i) = 10.2519 + 35.8308i
+    assert_eq!(&c * &d, cbig(dbig!(10.3), dbig!(35.8), 3));
+    // (1.23 + 4.56i)/(7.89 - 0.12i) = 0.14707... + 0.58018...i
+    assert_complex_close(&(&c / &d), "0.147069", "0.580183");
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = CDBig::ONE / CDBig::ZERO;
+}
+
+#[test]
+fn test_polar() {
+    let pi = "3.141592653589793238462643383279502884197";
+    assert_close(&cbig(dbig!(-1), dbig!(0), 40).arg(), pi);
+    assert_eq!(CDBig::ZERO.with_precision(10).arg(), DBig::ZERO);
+
+    let a = cbig(dbig!(-1), dbig!(-1), 40);
+    assert_close(&a.arg(), "-2.356194490192344928846982537459627163148");
+    let b = cbig(dbig!(-1e-5), dbig!(3), 40);
+    assert_close(&b.arg(), "1.570799660128229940218976012709710289835");
+
+    let (r, theta) = a.to_polar();
+    let c = CBig::from_polar(r, theta);
+    assert_complex_close(&c, "-1", "-1");
+
+    // large argument reduction
+    let d = CBig::from_polar(dbig!(1).with_precision(40).value(), dbig!(1e10));
+    assert_complex_close(
+        &d,
+        "0.8731196226768560011761913453076951961904",
+        "-0.4875060250875106915277942943481060416764",
+    );
+
+    // argument close to a multiple of π
+    let e = CBig::from_polar(dbig!(1).with_precision(40).value(), dbig!(3.14159265358979323846));
+    assert_complex_close(&e, "-1", "2.643383279502884197169399375105820974945e-21");
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(cbig(dbig!(-3), dbig!(-4), 10).sqrt(), cbig(dbig!(1), dbig!(-2), 10));
+    assert_eq!(cbig(dbig!(-3), dbig!(4), 10).sqrt(), cbig(dbig!(1), dbig!(2), 10));
+    assert_eq!(cbig(dbig!(3), dbig!(-4), 10).sqrt(), cbig(dbig!(2), dbig!(-1), 10));
+    assert_eq!(cbig(dbig!(-4), dbig!(0), 10).sqrt(), cbig(dbig!(0), dbig!(2), 10));
+    assert_eq!(CDBig::ZERO.sqrt(), CDBig::ZERO);
+
+    let a = cbig(dbig!(2), dbig!(0), 40).sqrt();
+    assert_complex_close(&a, "1.414213562373095048801688724209698078570", "0");
+}
+
+#[test]
+fn test_exp_ln() {
+    let a = cbig(dbig!(-2.5), dbig!(100), 40).exp();
+    assert_complex_close(
+        &a,
+        "0.07078344344509649662762242992778385019591",
+        "-0.04156502295368418176140541417750717563036",
+    );
+
+    // the magnitude is very close to 1
+    let b = cbig(dbig!(0.6), dbig!(0.8000000000000000000001), 40).ln();
+    assert_complex_close(
+        &b,
+        "7.99999999999999999999986e-23",
+        "0.9272952180016122324285724629224288040571",
+    );
+
+    let c = cbig(dbig!(1.5), dbig!(-2.5), 40);
+    // ln and exp are faithfully rounded, so the round trip is only exact at a lower precision
+    assert_eq!(c.ln().exp().with_precision(38), c.with_precision(38));
+}
+
+#[test]
+#[should_panic]
+fn test_ln_zero() {
+    let _ = CDBig::ZERO.with_precision(10).ln();
+}
+
+#[test]
+fn test_pow() {
+    let a = cbig(dbig!(1.1), dbig!(0.3), 40);
+    assert_complex_close(
+        &a.powi(25.into()),
+        "24.7362027413250007846793216",
+        "9.6830406085458085783154688",
+    );
+    assert_complex_close(
+        &a.powi((-3).into()),
+        "0.47064178425125170687300864815657715066",
+        "-0.4833864360491579426490669094219390077378",
+    );
+    assert_eq!(a.powi(0.into()), CDBig::ONE);
+
+    let b = cbig(dbig!(1.5), dbig!(-2.5), 40);
+    let w = cbig(dbig!(0.5), dbig!(1.25), 40);
+    assert_complex_close(
+        &b.pow(&w),
+        "4.212509776971472762320846507173704005715",
+        "4.535954564349062355021120342180548549982",
+    );
+    assert_eq!(CDBig::ZERO.pow(&w), CDBig::ZERO);
+    assert_eq!(b.pow(&CDBig::ZERO), CDBig::ONE);
+}
+
+#[test]
+fn test_binary() {
+    let a: CBig = CBig::new(fbig!(0x3), fbig!(0x4)).with_precision(8);
+    assert_eq!(a.abs(), fbig!(0x5));
+    assert_eq!(a.sqrt(), CBig::new(fbig!(0x2), fbig!(0x1)));
+
+    let i = CBig::<mode::HalfEven, 2>::new(FBig::ZERO, FBig::ONE).with_precision(100);
+    // i^2 = -1 via exp(2 ln(i))
+    let two = CBig::from(FBig::from(2u8));
+    let b = i.pow(&two);
+    assert!((b.re() + FBig::ONE).abs() < fbig!(0x1p-95));
+    assert!(b.im().clone().abs() < fbig!(0x1p-95));
+}
@@ -2,10 +2,10 @@ use core::convert::TryFrom;
 use dashu_base::Approximation::*;
 use dashu_float::{
     round::{
-        mode::{HalfAway, Zero},
+        mode::{self, HalfAway, HalfEven, Zero},
         Rounding::*,
     },
-    DBig, FBig,
+    Context, DBig, FBig,
 };
 
 mod helper_macros;
@@ -133,6 +133,64 @@ fn test_fract_inf() {
     let _ = DBig::INFINITY.fract();
 }
 
+#[test]
+fn test_round_quantize() {
+    // round to integer with the rounding mode of the number
+    assert_eq!(dbig!(2.5).round(), Inexact(dbig!(3), AddOne));
+    assert_eq!(dbig!(-2.5).round(), Inexact(dbig!(-3), SubOne));
+    assert_eq!(dbig!(0.4).round(), Inexact(DBig::ZERO, NoOp));
+    assert_eq!(dbig!(-123).round(), Exact(dbig!(-123)));
+    assert_eq!(DBig::ZERO.round(), Exact(DBig::ZERO));
+    assert_eq!(dbig!(1e-100).round(), Inexact(DBig::ZERO, NoOp));
+    assert_eq!(fbig!(0x5p-1).round(), Inexact(fbig!(0x2), NoOp)); // toward zero
+    assert_eq!(fbig!(-0x1p-100).round(), Inexact(FBin::ZERO, NoOp));
+    let a = FBig::<HalfEven>::from_parts(5.into(), -1);
+    assert_eq!(a.round(), Inexact(FBig::from_parts(2.into(), 0), NoOp));
+    let a = FBig::<mode::Up>::from_parts(1.into(), -100);
+    assert_eq!(a.round(), Inexact(FBig::ONE, AddOne));
+
+    // the precision is preserved
+    let a = dbig!(1.23456);
+    assert_eq!(a.round_to(2).value().precision(), 6);
+    assert_eq!(a.round_to(2), Inexact(dbig!(1.23), NoOp));
+    assert_eq!(a.round_to(4), Inexact(dbig!(1.2346), AddOne));
+    assert_eq!(a.round_to(0), Inexact(dbig!(1), NoOp));
+    assert_eq!(a.round_to(-1), Inexact(DBig::ZERO, NoOp));
+    assert_eq!(dbig!(-95).round_to(-1), Inexact(dbig!(-100), SubOne));
+    assert_eq!(dbig!(999.95).round_to(1), Inexact(dbig!(1000), AddOne));
+    assert_eq!(fbig!(0x1bp-4).round_to(2), Inexact(fbig!(0x3p-1), NoOp));
+
+    // quantize
+    assert_eq!(dbig!(123.456).quantize(-2), Inexact(dbig!(123.46), AddOne));
+    assert_eq!(dbig!(123.456).quantize(2), Inexact(dbig!(100), NoOp));
+    assert_eq!(dbig!(123.456).quantize(-3), Exact(dbig!(123.456)));
+    assert_eq!(dbig!(123.4).quantize(-1000), Exact(dbig!(123.4)));
+    assert_eq!(dbig!(123.4).quantize(1000), Inexact(DBig::ZERO, NoOp));
+    assert_eq!(dbig!(5e5).quantize(6), Inexact(dbig!(1e6), AddOne));
+    let price = dbig!(19.99);
+    assert_eq!(dbig!(7.005).quantize(price.repr().exponent()), Inexact(dbig!(7.01), AddOne));
+
+    // significant digits
+    assert_eq!(dbig!(123.456).round_to_significant(1), Inexact(dbig!(100), NoOp));
+    assert_eq!(dbig!(123.456).round_to_significant(4), Inexact(dbig!(123.5), AddOne));
+    assert_eq!(dbig!(123.456).round_to_significant(6), Exact(dbig!(123.456)));
+    assert_eq!(dbig!(-0.0009995).round_to_significant(3), Inexact(dbig!(-0.001), SubOne));
+    assert_eq!(DBig::ZERO.round_to_significant(3), Exact(DBig::ZERO));
+    assert_eq!(fbig!(0x1fp3).round_to_significant(3), Inexact(fbig!(0x7p5), NoOp));
+}
+
+#[test]
+#[should_panic]
+fn test_round_inf() {
+    let _ = DBig::INFINITY.round();
+}
+
+#[test]
+#[should_panic]
+fn test_round_to_zero_digits() {
+    let _ = dbig!(1.5).round_to_significant(0);
+}
+
 #[test]
 #[rustfmt::skip]
 fn test_base_change() {
@@ -207,8 +265,9 @@ fn test_from_f32() {
     assert_eq!(FBin::try_from(12.34f32).unwrap(), fbig!(0x315c29p-18));
     assert_eq!(FBin::try_from(-12.34f32).unwrap(), fbig!(-0x315c29p-18));
     assert_eq!(1e-40_f32.to_bits(), 0x000116c2); // subnormal
-    assert_eq!(FBin::try_from(1e-40_f32).unwrap(), fbig!(0x116c2p-126));
-    assert_eq!(FBin::try_from(-1e-40_f32).unwrap(), fbig!(-0x116c2p-126));
+    assert_eq!(FBin::try_from(1e-40_f32).unwrap(), fbig!(0x116c2p-149));
+    assert_eq!(FBin::try_from(-1e-40_f32).unwrap(), fbig!(-0x116c2p-149));
+    assert_eq!(FBin::try_from(1e-40_f32).unwrap().to_f32(), Exact(1e-40_f32));
     assert_eq!(FBin::try_from(f32::INFINITY).unwrap(), FBin::INFINITY);
     assert_eq!(FBin::try_from(f32::NEG_INFINITY).unwrap(), FBin::NEG_INFINITY);
     assert!(FBin::try_from(f32::NAN).is_err());
@@ -226,8 +285,9 @@ fn test_from_f64() {
     assert_eq!(FBin::try_from(12.34f64).unwrap(), fbig!(0xc570a3d70a3d7p-48));
     assert_eq!(FBin::try_from(-12.34f64).unwrap(), fbig!(-0xc570a3d70a3d7p-48));
     assert_eq!(1e-308_f64.to_bits(), 0x000730d67819e8d2); // subnormal
-    assert_eq!(FBin::try_from(1e-308_f64).unwrap(), fbig!(0x730d67819e8d2p-1022));
-    assert_eq!(FBin::try_from(-1e-308_f64).unwrap(), fbig!(-0x730d67819e8d2p-1022));
+    assert_eq!(FBin::try_from(1e-308_f64).unwrap(), fbig!(0x730d67819e8d2p-1074));
+    assert_eq!(FBin::try_from(-1e-308_f64).unwrap(), fbig!(-0x730d67819e8d2p-1074));
+    assert_eq!(FBin::try_from(-1e-308_f64).unwrap().to_f64(), Exact(-1e-308_f64));
     assert_eq!(FBin::try_from(f64::INFINITY).unwrap(), FBin::INFINITY);
     assert_eq!(FBin::try_from(f64::NEG_INFINITY).unwrap(), FBin::NEG_INFINITY);
     assert!(FBin::try_from(f64::NAN).is_err());
@@ -281,6 +341,17 @@ fn test_to_f32() {
     assert_eq!(FBig::<Zero, 2>::NEG_INFINITY.to_f32(), Inexact(f32::NEG_INFINITY, NoOp));
     assert_eq!((fbig!(0x1) << 200).to_f32(), Inexact(f32::INFINITY, AddOne));
     assert_eq!((fbig!(-0x1) << 200).to_f32(), Inexact(f32::NEG_INFINITY, SubOne));
+    assert_eq!(fbig!(0xffffffp104).to_f32(), Exact(f32::MAX));
+    assert_eq!(fbig!(0x1ffffffp103).to_f32(), Inexact(f32::INFINITY, AddOne));
+
+    // subnormals
+    assert_eq!(fbig!(0x1p-126).to_f32(), Exact(f32::MIN_POSITIVE));
+    assert_eq!(fbig!(0x7fffffp-149).to_f32(), Exact(f32::from_bits(0x7fffff)));
+    assert_eq!(fbig!(0x1p-149).to_f32(), Exact(f32::from_bits(1)));
+    assert_eq!(fbig!(-0x3p-151).to_f32(), Inexact(-f32::from_bits(1), SubOne));
+    assert_eq!(fbig!(0x1p-150).to_f32(), Inexact(0., NoOp));
+    assert_eq!(fbig!(0x123456789p-160).to_f32(), Inexact(f32::from_bits(0x2468ad), AddOne));
+    assert!(fbig!(-0x1p-200).to_f32().value().is_sign_negative());
 }
 
 #[test]
@@ -308,4 +379,76 @@ fn test_to_f64() {
     assert_eq!(FBig::<Zero, 2>::NEG_INFINITY.to_f64(), Inexact(f64::NEG_INFINITY, NoOp));
     assert_eq!((fbig!(0x1) << 2000).to_f64(), Inexact(f64::INFINITY, AddOne));
     assert_eq!((fbig!(-0x1) << 2000).to_f64(), Inexact(f64::NEG_INFINITY, SubOne));
+    assert_eq!(fbig!(0x1fffffffffffffp971).to_f64(), Exact(f64::MAX));
+    assert_eq!(fbig!(0x3fffffffffffffp970).to_f64(), Inexact(f64::INFINITY, AddOne));
+
+    // subnormals
+    assert_eq!(fbig!(0x1p-1022).to_f64(), Exact(f64::MIN_POSITIVE));
+    assert_eq!(fbig!(0xfffffffffffffp-1074).to_f64(), Exact(f64::from_bits(0xfffffffffffff)));
+    assert_eq!(fbig!(0x1p-1074).to_f64(), Exact(f64::from_bits(1)));
+    assert_eq!(fbig!(0x3p-1076).to_f64(), Inexact(f64::from_bits(1), AddOne));
+    assert_eq!(fbig!(-0x1p-1075).to_f64(), Inexact(-0., NoOp));
+    assert_eq!(fbig!(0x1fffffffffffffp-1075).to_f64(), Inexact(f64::MIN_POSITIVE, AddOne));
+    assert!(fbig!(-0x1p-2000).to_f64().value().is_sign_negative());
+}
+
+#[test]
+fn test_to_shortest_decimal() {
+    type FEven = FBig<HalfEven>;
+    let shortest = |f: f64| format!("{:e}", FEven::try_from(f).unwrap().to_shortest_decimal());
+    let shortest32 = |f: f32| format!("{:e}", FEven::try_from(f).unwrap().to_shortest_decimal());
+
+    assert_eq!(shortest(0.1), "1e-1");
+    assert_eq!(shortest(-0.3), "-3e-1");
+    assert_eq!(shortest(1.0), "1e0");
+    assert_eq!(shortest(123456.0), "1.23456e5");
+    assert_eq!(shortest(5e-324), "4.9406564584124654e-324"); // precision is 53 bits
+    assert_eq!(shortest32(0.1), "1e-1");
+    assert_eq!(shortest32(16777215.0), "1.6777215e7");
+    assert_eq!(FEven::ZERO.to_shortest_decimal(), DBig::ZERO.with_rounding::<HalfEven>());
+    assert_eq!(
+        FEven::NEG_INFINITY.to_shortest_decimal(),
+        DBig::NEG_INFINITY.with_rounding::<HalfEven>()
+    );
+
+    // compare with the shortest representation printed by the standard library
+    let mut state = 0x2545f4914f6cdd1du64;
+    for _ in 0..1000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let f = f64::from_bits(state);
+        if f.is_finite() && f.is_normal() {
+            assert_eq!(shortest(f), format!("{:e}", f));
+        }
+        let f = f32::from_bits(state as u32);
+        if f.is_finite() && f.is_normal() {
+            assert_eq!(shortest32(f), format!("{:e}", f));
+        }
+    }
+
+    // with the exponent range of f64, the subnormals are printed as the standard library
+    let context = Context::new(53).with_exponent_range(-1022, 1023);
+    for f in [5e-324, 1.5e-323, -2.2250738585072e-310] {
+        let a = FEven::try_from(f).unwrap();
+        let a = FEven::from_repr(a.repr().clone(), context);
+        assert_eq!(format!("{:e}", a.to_shortest_decimal()), format!("{:e}", f));
+    }
+
+    // the result depends on the rounding mode
+    let a = FBig::<Zero>::try_from(0.1f64).unwrap();
+    assert_eq!(a.to_shortest_decimal().to_string(), "0.10000000000000001");
+    let a = FBig::<Zero>::try_from(0.3f64).unwrap();
+    assert_eq!(a.to_shortest_decimal().to_string(), "0.3");
+
+    // arbitrary precision
+    let a = fbig!(0x1p-1000).with_precision(200).value();
+    let b = a.to_shortest_decimal();
+    assert_eq!(b.clone().with_base_and_precision::<2>(200).value(), a);
+    assert!(b.precision() <= 62);
+    let c = FEven::ONE.with_precision(100).value() / FEven::from(3);
+    assert_eq!(c.to_shortest_decimal().to_string(), "0.3333333333333333333333333333335");
+    // with unlimited precision the exact value is returned
+    let d = FBin::from_parts((-3).into(), -3).with_precision(0).value();
+    assert_eq!(d.to_shortest_decimal(), dbig!(-0.375).with_rounding::<Zero>());
 }
@@ -0,0 +1,173 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    DBig, Decimal,
+};
+use dashu_int::{IBig, UBig};
+
+mod helper_macros;
+
+type D2 = Decimal<2>;
+
+fn d2(s: &str) -> D2 {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_parse_format() {
+    assert_eq!(d2("12.3").raw(), &IBig::from(1230));
+    assert_eq!(d2("-0.005").raw(), &IBig::from(-1)); // rounded half away from zero
+    assert_eq!(d2("1e3").raw(), &IBig::from(100000));
+    assert!("1.2.3".parse::<D2>().is_err());
+
+    assert_eq!(d2("12.3").to_string(), "12.30");
+    assert_eq!(d2("-0.05").to_string(), "-0.05");
+    assert_eq!(D2::ZERO.to_string(), "0.00");
+    assert_eq!(Decimal::<0>::from(-42).to_string(), "-42");
+    assert_eq!(Decimal::<5>::from_raw(IBig::from(-7)).to_string(), "-0.00007");
+
+    let a = d2("-1.25");
+    assert_eq!(format!("{:8}", a), "   -1.25");
+    assert_eq!(format!("{:<8}|", a), "-1.25   |");
+    assert_eq!(format!("{:08}", a), "-0001.25");
+    assert_eq!(format!("{:+}", d2("1.25")), "+1.25");
+    assert_eq!(format!("{:.1}", a), "-1.3");
+    assert_eq!(format!("{:.0}", a), "-1");
+    assert_eq!(format!("{:.4}", a), "-1.2500");
+
+    // no negative zero is printed after rounding
+    assert_eq!(format!("{:.1}", d2("-0.04")), "0.0");
+    assert_eq!(format!("{:.0}", d2("-0.45")), "0");
+    assert_eq!(format!("{:.0}", d2("-0.5")), "-1");
+}
+
+#[test]
+fn test_add_sub() {
+    let a = d2("10.25");
+    let b = d2("0.75");
+    assert_eq!(&a + &b, d2("11"));
+    assert_eq!(&a - &b, d2("9.5"));
+    assert_eq!(&b - &a, d2("-9.5"));
+    assert_eq!(-&a, d2("-10.25"));
+    assert_eq!(a.abs(), (-a).abs());
+
+    let items = [d2("0.1"), d2("0.2"), d2("0.3")];
+    assert_eq!(items.iter().sum::<D2>(), d2("0.6"));
+    assert_eq!(items.into_iter().sum::<D2>(), d2("0.6"));
+}
+
+#[test]
+fn test_mul_div() {
+    let a = d2("2.5");
+    assert_eq!(&a * d2("0.35"), d2("0.88")); // 0.875
+    assert_eq!(&a * d2("-0.35"), d2("-0.88"));
+    assert_eq!(a.mul_rounded(&d2("0.34")), Exact(d2("0.85")));
+    assert_eq!(a.mul_rounded(&d2("0.33")), Inexact(d2("0.83"), AddOne)); // 0.825
+
+    assert_eq!(d2("1") / d2("3"), d2("0.33"));
+    assert_eq!(d2("2") / d2("3"), d2("0.67"));
+    assert_eq!(d2("-2") / d2("3"), d2("-0.67"));
+    assert_eq!(d2("2").div_rounded(&d2("-3")), Inexact(d2("-0.67"), SubOne));
+    assert_eq!(d2("1").div_rounded(&d2("0.08")), Exact(d2("12.5")));
+    assert_eq!(d2("1").checked_div(&D2::ZERO), None);
+
+    // the rounding mode is a type parameter
+    type E2 = Decimal<2, mode::HalfEven>;
+    let b: E2 = "0.5".parse().unwrap();
+    assert_eq!(&b * &"0.25".parse().unwrap(), "0.12".parse().unwrap()); // 0.125
+    assert_eq!(&b * &"0.35".parse().unwrap(), "0.18".parse().unwrap()); // 0.175
+    type Z2 = Decimal<2, mode::Zero>;
+    assert_eq!(Z2::from(2) / Z2::from(3), "0.66".parse().unwrap());
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = d2("1") / D2::ZERO;
+}
+
+#[test]
+fn test_quantize_rescale() {
+    let nickel = d2("0.05");
+    assert_eq!(d2("12.37").quantize(&nickel), Inexact(d2("12.35"), NoOp));
+    assert_eq!(d2("12.375").quantize(&nickel), Inexact(d2("12.40"), AddOne)); // 12.38
+    assert_eq!(d2("-12.38").quantize(&nickel), Inexact(d2("-12.40"), SubOne));
+    assert_eq!(d2("12.35").quantize(&nickel), Exact(d2("12.35")));
+    assert_eq!(d2("2.5").quantize(&D2::from(1)), Inexact(d2("3"), AddOne));
+
+    // the sign of the quantum doesn't affect the rounding direction
+    let up = |s: &str, q: &str| {
+        s.parse::<Decimal<2, mode::Up>>()
+            .unwrap()
+            .quantize(&q.parse().unwrap())
+    };
+    assert_eq!(up("12.38", "0.05"), Inexact("12.40".parse().unwrap(), AddOne));
+    assert_eq!(up("12.38", "-0.05"), Inexact("12.40".parse().unwrap(), AddOne));
+    assert_eq!(up("-12.38", "-0.05"), Inexact("-12.35".parse().unwrap(), NoOp));
+    assert_eq!(d2("12.37").quantize(&-nickel), Inexact(d2("12.35"), NoOp));
+
+    let a: Decimal<4> = "-3.14159".parse().unwrap();
+    assert_eq!(a.raw(), &IBig::from(-31416));
+    assert_eq!(a.rescale::<2>(), Inexact(d2("-3.14"), NoOp));
+    assert_eq!(a.rescale::<0>(), Inexact(Decimal::from(-3), NoOp));
+    assert_eq!(a.rescale::<3>(), Inexact("-3.142".parse().unwrap(), SubOne));
+    assert_eq!(a.rescale::<6>(), Exact(Decimal::from_raw(IBig::from(-3141600))));
+    assert_eq!(a.scale(), 4);
+}
+
+#[test]
+#[should_panic]
+fn test_quantize_zero() {
+    let _ = d2("1").quantize(&D2::ZERO);
+}
+
+#[test]
+fn test_allocate() {
+    let ratios = |r: &[u8]| r.iter().map(|&x| UBig::from(x)).collect::<Vec<_>>();
+
+    let a = d2("100");
+    let parts = a.allocate(&ratios(&[1, 1, 1]));
+    assert_eq!(parts, [d2("33.34"), d2("33.33"), d2("33.33")]);
+
+    // the remaining cents go to the parts with largest remainders
+    let parts = a.allocate(&ratios(&[1, 2, 0, 4]));
+    assert_eq!(parts, [d2("14.29"), d2("28.57"), d2("0"), d2("57.14")]);
+    assert_eq!(parts.iter().sum::<D2>(), a);
+
+    let b = d2("-0.05");
+    let parts = b.allocate(&ratios(&[3, 7]));
+    assert_eq!(parts, [d2("-0.02"), d2("-0.03")]);
+    assert_eq!(D2::ZERO.allocate(&ratios(&[1, 2])), [D2::ZERO, D2::ZERO]);
+
+    assert_eq!(a.split(3), [d2("33.34"), d2("33.33"), d2("33.33")]);
+    assert_eq!(d2("0.02").split(3), [d2("0.01"), d2("0.01"), d2("0")]);
+    assert_eq!(d2("-1").split(1), [d2("-1")]);
+}
+
+#[test]
+#[should_panic]
+fn test_allocate_zero_ratios() {
+    let _ = d2("1").allocate(&[UBig::ZERO, UBig::ZERO]);
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(D2::from_fbig(&dbig!(1.005)), Inexact(d2("1.01"), AddOne));
+    assert_eq!(D2::from_fbig(&dbig!(-1.004)), Inexact(d2("-1"), NoOp));
+    assert_eq!(D2::from_fbig(&dbig!(12e3)), Exact(d2("12000")));
+    assert_eq!(D2::from_fbig(&DBig::ZERO), Exact(D2::ZERO));
+
+    assert_eq!(d2("-12.50").to_fbig(), dbig!(-12.5));
+    assert_eq!(DBig::from(d2("0.01")), dbig!(0.01));
+    assert_eq!(D2::from(IBig::from(-3)), d2("-3"));
+}
+
+#[test]
+fn test_cmp() {
+    assert!(d2("1.01") > d2("1"));
+    assert!(d2("-1.01") < d2("-1"));
+    assert_eq!(d2("1.10").max(d2("1.1")), d2("1.1"));
+    assert_eq!(D2::default(), D2::ZERO);
+    assert!(D2::ZERO.is_zero());
+    assert_eq!(d2("-0.01").sign(), dashu_base::Sign::Negative);
+}
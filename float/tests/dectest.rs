@@ -0,0 +1,298 @@
+//! Runner for the test cases of the General Decimal Arithmetic specification in the `.decTest`
+//! format (see <https://speleotrove.com/decimal/dectest.html>).
+//!
+//! Every `*.decTest` file in `tests/dectest` is executed. The test cases of the operations that
+//! are not supported by the [gda][dashu_float::gda] module, the cases with null (`#`) or
+//! hexadecimal operands or results, and the cases that require the `extended: 0` subset are skipped.
+//!
+//! The files shipped in the repository are the official test files (version 2.59) of the operations
+//! supported by the [gda][dashu_float::gda] module. The other files of the test suite can be copied
+//! into the same directory to be executed as well.
+
+use dashu_float::{
+    gda::{DecContext, DecNumber, DecRounding},
+    status::Flags,
+};
+use std::{
+    cmp::Ordering,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The context directives in effect
+#[derive(Clone)]
+struct Directives {
+    precision: usize,
+    rounding: DecRounding,
+    emax: isize,
+    emin: isize,
+    clamp: bool,
+    extended: bool,
+}
+
+impl Default for Directives {
+    fn default() -> Self {
+        Self {
+            precision: 9,
+            rounding: DecRounding::HalfAway,
+            emax: 999,
+            emin: -999,
+            clamp: false,
+            extended: true,
+        }
+    }
+}
+
+impl Directives {
+    fn context(&self) -> DecContext {
+        DecContext::new(self.precision)
+            .with_rounding(self.rounding)
+            .with_exponent_range(self.emin, self.emax)
+            .with_clamp(self.clamp)
+    }
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    skipped: usize,
+    failures: Vec<String>,
+}
+
+/// Split a line into tokens, handling the quoted strings and removing the comment
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+        let first = match chars.next() {
+            None => break,
+            Some(c) => c,
+        };
+        if first == '-' && chars.peek() == Some(&'-') {
+            break;
+        }
+
+        let mut token = String::new();
+        if first == '\'' || first == '"' {
+            // a doubled quote inside the quoted string is a literal quote
+            while let Some(c) = chars.next() {
+                if c == first {
+                    if chars.peek() == Some(&first) {
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                token.push(c);
+            }
+        } else {
+            token.push(first);
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+        }
+        tokens.push(token);
+    }
+    tokens
+}
+
+fn parse_rounding(s: &str) -> DecRounding {
+    match s {
+        "down" => DecRounding::Zero,
+        "half_up" => DecRounding::HalfAway,
+        "half_even" => DecRounding::HalfEven,
+        "ceiling" => DecRounding::Up,
+        "floor" => DecRounding::Down,
+        "half_down" => DecRounding::HalfDown,
+        "up" => DecRounding::Away,
+        "05up" => DecRounding::ZeroFiveUp,
+        _ => panic!("unknown rounding mode {}", s),
+    }
+}
+
+/// Map the condition names in the test cases to the flags, or [None] if it's not supported
+fn parse_condition(s: &str) -> Option<Flags> {
+    Some(match s.to_ascii_lowercase().as_str() {
+        "clamped" => Flags::CLAMPED,
+        "conversion_syntax"
+        | "division_impossible"
+        | "division_undefined"
+        | "invalid_context"
+        | "invalid_operation" => Flags::INVALID_OPERATION,
+        "division_by_zero" => Flags::DIVISION_BY_ZERO,
+        "inexact" => Flags::INEXACT,
+        "overflow" => Flags::OVERFLOW,
+        "rounded" => Flags::ROUNDED,
+        "subnormal" => Flags::SUBNORMAL,
+        "underflow" => Flags::UNDERFLOW,
+        _ => return None,
+    })
+}
+
+fn ordering_str(ord: Ordering) -> String {
+    match ord {
+        Ordering::Less => "-1",
+        Ordering::Equal => "0",
+        Ordering::Greater => "1",
+    }
+    .to_string()
+}
+
+/// Execute the operation, returns [None] if the operation is not supported
+fn execute(ctxt: &mut DecContext, op: &str, operands: &[String]) -> Option<String> {
+    // the conversions round the operand to the context, the other operations take exact operands
+    match op {
+        "apply" | "tosci" => return Some(ctxt.parse(&operands[0]).to_string()),
+        "toeng" => return Some(ctxt.parse(&operands[0]).to_eng_string()),
+        _ => {}
+    }
+    let args: Vec<DecNumber> = operands
+        .iter()
+        .map(|s| s.parse().ok())
+        .collect::<Option<_>>()?;
+    let (a, b) = (&args[0], args.get(1));
+    let result = match op {
+        "abs" => ctxt.abs(a),
+        "add" => ctxt.add(a, b?),
+        "subtract" => ctxt.subtract(a, b?),
+        "multiply" => ctxt.multiply(a, b?),
+        "divide" => ctxt.divide(a, b?),
+        "divideint" => ctxt.divide_integer(a, b?),
+        "remainder" => ctxt.remainder(a, b?),
+        "compare" => ctxt.compare(a, b?),
+        "comparetotal" => return Some(ordering_str(a.compare_total(b?))),
+        "comparetotmag" => return Some(ordering_str(a.compare_total_mag(b?))),
+        "samequantum" => return Some(if a.same_quantum(b?) { "1" } else { "0" }.to_string()),
+        "max" => ctxt.max(a, b?),
+        "min" => ctxt.min(a, b?),
+        "maxmag" => ctxt.max_mag(a, b?),
+        "minmag" => ctxt.min_mag(a, b?),
+        "minus" => ctxt.minus(a),
+        "plus" => ctxt.plus(a),
+        "quantize" => ctxt.quantize(a, b?),
+        "reduce" => ctxt.reduce(a),
+        "tointegral" => ctxt.to_integral_value(a),
+        "tointegralx" => ctxt.to_integral_exact(a),
+        "copy" => a.clone(),
+        "copyabs" => a.copy_abs(),
+        "copynegate" => a.copy_negate(),
+        "copysign" => a.copy_sign(b?),
+        _ => return None,
+    };
+    Some(result.to_string())
+}
+
+/// Run a test case line, or update the directives
+fn run_line(path: &Path, line: &str, directives: &mut Directives, summary: &mut Summary) {
+    let tokens = tokenize(line);
+    if tokens.is_empty() {
+        return;
+    }
+
+    // directives
+    if tokens[0].ends_with(':') {
+        let keyword = tokens[0].trim_end_matches(':').to_ascii_lowercase();
+        let value = tokens[1].to_ascii_lowercase();
+        match keyword.as_str() {
+            "precision" => directives.precision = value.parse().unwrap(),
+            "rounding" => directives.rounding = parse_rounding(&value),
+            "maxexponent" => directives.emax = value.parse().unwrap(),
+            "minexponent" => directives.emin = value.parse().unwrap(),
+            "clamp" => directives.clamp = value == "1",
+            "extended" => directives.extended = value == "1",
+            "dectest" => {
+                let include = path.with_file_name(format!("{}.decTest", tokens[1]));
+                run_file(&include, summary);
+            }
+            _ => {} // version etc.
+        }
+        return;
+    }
+
+    // test cases: id operation operand1 operand2 ... -> result conditions...
+    let arrow = tokens.iter().position(|t| t == "->").expect("missing ->");
+    let (id, op) = (&tokens[0], tokens[1].to_ascii_lowercase());
+    let operands = &tokens[2..arrow];
+    let expected = &tokens[arrow + 1];
+    let conditions: Option<Vec<Flags>> = tokens[arrow + 2..]
+        .iter()
+        .map(|c| parse_condition(c))
+        .collect();
+
+    let hex_or_null = operands
+        .iter()
+        .chain([expected])
+        .any(|s| s.starts_with('#'));
+    let conditions = match conditions {
+        Some(c) if directives.extended && !hex_or_null => c,
+        _ => {
+            summary.skipped += 1;
+            return;
+        }
+    };
+    let expected_flags = conditions.into_iter().fold(Flags::EMPTY, |a, b| a | b);
+
+    let mut ctxt = directives.context();
+    let result = match execute(&mut ctxt, &op, operands) {
+        Some(r) => r,
+        None => {
+            summary.skipped += 1;
+            return;
+        }
+    };
+    if (expected == "?" || &result == expected) && ctxt.flags() == expected_flags {
+        summary.passed += 1;
+    } else {
+        summary.failures.push(format!(
+            "{}: {} {} -> expected {} {:?}, got {} {:?}",
+            id,
+            op,
+            operands.join(" "),
+            expected,
+            expected_flags,
+            result,
+            ctxt.flags()
+        ));
+    }
+}
+
+fn run_file(path: &Path, summary: &mut Summary) {
+    let content = fs::read_to_string(path).unwrap();
+    let mut directives = Directives::default();
+    for line in content.lines() {
+        run_line(path, line, &mut directives, summary);
+    }
+}
+
+#[test]
+fn test_dectest_files() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/dectest");
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|p| p.extension().map_or(false, |ext| ext == "decTest"))
+        .collect();
+    files.sort();
+    assert!(!files.is_empty());
+
+    let mut summary = Summary::default();
+    for file in &files {
+        run_file(file, &mut summary);
+    }
+    assert!(
+        summary.failures.is_empty(),
+        "{} of {} test cases failed:\n{}",
+        summary.failures.len(),
+        summary.passed + summary.failures.len(),
+        summary.failures.join("\n")
+    );
+    assert!(summary.passed > 0);
+    println!("{} test cases passed, {} skipped", summary.passed, summary.skipped);
+}
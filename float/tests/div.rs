@@ -2,7 +2,7 @@ use core::{
     fmt::Debug,
     ops::{Div, DivAssign},
 };
-use dashu_base::{Approximation::*, DivEuclid, DivRemEuclid, RemEuclid};
+use dashu_base::{Approximation::*, DivEuclid, DivRem, DivRemEuclid, RemEuclid};
 use dashu_float::{round::Rounding::*, Context};
 
 mod helper_macros;
@@ -129,6 +129,62 @@ fn test_div_by_inf() {
     let _ = dashu_float::DBig::ONE / dashu_float::DBig::INFINITY;
 }
 
+#[test]
+fn test_div_long_dividend() {
+    // the dividend is much longer than the precision, it should not be rounded before the division
+    let context = Context::<dashu_float::round::mode::HalfEven>::new(1);
+    assert_eq!(
+        context.div(dbig!(2.5000001).repr(), dbig!(1).repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+    let context = Context::<dashu_float::round::mode::Down>::new(1);
+    assert_eq!(
+        context.div(dbig!(2.01).repr(), dbig!(-1).repr()),
+        Inexact(dbig!(-3).with_rounding(), SubOne)
+    );
+    assert_eq!(
+        context.div(dbig!(2.00).repr(), dbig!(-1e-2).repr()),
+        Exact(dbig!(-2e2).with_rounding())
+    );
+}
+
+#[test]
+fn test_div_rounding_modes() {
+    use dashu_float::round::mode::{HalfDown, HalfOdd, ToOdd};
+
+    let (a, b) = (dbig!(5), dbig!(2));
+    assert_eq!(
+        Context::<HalfDown>::new(1).div(a.repr(), b.repr()),
+        Inexact(dbig!(2).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(1).div(a.repr(), b.repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(2).div(a.repr(), b.repr()),
+        Exact(dbig!(2.5).with_rounding())
+    );
+
+    let (a, b) = (dbig!(2), dbig!(3));
+    assert_eq!(
+        Context::<ToOdd>::new(2).div(a.repr(), b.repr()),
+        Inexact(dbig!(0.67).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(3).div(a.repr(), b.repr()),
+        Inexact(dbig!(0.667).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).div((-a).repr(), b.repr()),
+        Inexact(dbig!(-0.67).with_rounding(), SubOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).div(dbig!(-1).repr(), b.repr()),
+        Inexact(dbig!(-0.33).with_rounding(), NoOp)
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_div_by_0() {
@@ -200,3 +256,96 @@ fn test_div_rem_euclid_decimal() {
         assert_eq!(remainder, *r);
     }
 }
+
+#[test]
+fn test_div_rem_binary() {
+    // test cases: n, d, quotient, remainder
+    let test_cases = [
+        (fbig!(0), fbig!(1), ibig!(0), fbig!(0)),
+        (fbig!(0x1000), fbig!(-0x10), ibig!(-0x100), fbig!(0)),
+        (fbig!(0x43), fbig!(0x21), ibig!(2), fbig!(0x1)),
+        (fbig!(-0x43), fbig!(0x21), ibig!(-2), fbig!(-0x1)),
+        (fbig!(0x654), fbig!(-0x321), ibig!(-2), fbig!(0x12)),
+        (fbig!(-0x98765), fbig!(-0x43210), ibig!(2), fbig!(-0x12345)),
+        (fbig!(0x1), fbig!(0x9p-4), ibig!(1), fbig!(0x7p-4)),
+        (fbig!(-0x1), fbig!(0x9p-8), ibig!(-28), fbig!(-0x4p-8)),
+        // the remainder is exact even if it has more digits than the precision
+        (fbig!(0x1p100), fbig!(0x3), ibig!(0x5555555555555555555555555), fbig!(0x1)),
+        (fbig!(0x1p-100), fbig!(0x3), ibig!(0), fbig!(0x1p-100)),
+    ];
+
+    for (n, d, q, r) in &test_cases {
+        assert_eq!(n % d, *r);
+        let mut n_mut = n.clone();
+        n_mut %= d;
+        assert_eq!(n_mut, *r);
+        let (quotient, remainder) = n.div_rem(d);
+        assert_eq!(quotient, *q);
+        assert_eq!(remainder, *r);
+    }
+}
+
+#[test]
+fn test_div_rem_decimal() {
+    // test cases: n, d, quotient, remainder
+    let test_cases = [
+        (dbig!(0), dbig!(1), ibig!(0), dbig!(0)),
+        (dbig!(1000), dbig!(-10), ibig!(-100), dbig!(0)),
+        (dbig!(43), dbig!(21), ibig!(2), dbig!(1)),
+        (dbig!(-43), dbig!(21), ibig!(-2), dbig!(-1)),
+        (dbig!(13), dbig!(-9), ibig!(-1), dbig!(4)),
+        (dbig!(-13), dbig!(-9), ibig!(1), dbig!(-4)),
+        (dbig!(1), dbig!(9e-2), ibig!(11), dbig!(1e-2)),
+        (dbig!(-55e-1), dbig!(2), ibig!(-2), dbig!(-15e-1)),
+        (dbig!(1e20), dbig!(7), ibig!(14285714285714285714), dbig!(2)),
+    ];
+
+    for (n, d, q, r) in &test_cases {
+        assert_eq!(n % d, *r);
+        let (quotient, remainder) = n.div_rem(d);
+        assert_eq!(quotient, *q);
+        assert_eq!(remainder, *r);
+    }
+    assert_eq!(dbig!(7) % 3, dbig!(1));
+    assert_eq!(7 % dbig!(-3), dbig!(1));
+}
+
+#[test]
+fn test_remainder() {
+    // test cases: n, d, remainder, low bits of the quotient
+    let test_cases = [
+        (dbig!(0), dbig!(1), dbig!(0), 0),
+        (dbig!(10), dbig!(3), dbig!(1), 3),
+        (dbig!(11), dbig!(3), dbig!(-1), 4),
+        (dbig!(-11), dbig!(3), dbig!(1), -4),
+        (dbig!(11), dbig!(-3), dbig!(-1), -4),
+        (dbig!(10), dbig!(4), dbig!(2), 2),
+        (dbig!(14), dbig!(4), dbig!(-2), 4),
+        (dbig!(-14), dbig!(4), dbig!(2), -4),
+        (dbig!(1), dbig!(3e-1), dbig!(1e-1), 3),
+        (dbig!(5e-1), dbig!(1), dbig!(5e-1), 0),
+        (dbig!(15e-1), dbig!(1), dbig!(-5e-1), 2),
+        (dbig!(1e20), dbig!(1), dbig!(0), 1661992960),
+    ];
+
+    for (n, d, r, q) in &test_cases {
+        assert_eq!(n.remainder(d), *r);
+        assert_eq!(n.remquo(d), (r.clone(), *q));
+    }
+
+    assert_eq!(fbig!(0x7p-2).remainder(&fbig!(0x1)), fbig!(-0x1p-2));
+    assert_eq!(fbig!(0x5p-1).remquo(&fbig!(-0x1)), (fbig!(0x1p-1), -2));
+    assert_eq!(fbig!(0x1p40).remquo(&fbig!(0x1)), (fbig!(0), 0));
+}
+
+#[test]
+#[should_panic]
+fn test_rem_by_0() {
+    let _ = dbig!(1) % dbig!(0);
+}
+
+#[test]
+#[should_panic]
+fn test_remainder_by_inf() {
+    let _ = dashu_float::DBig::ONE.remainder(&dashu_float::DBig::INFINITY);
+}
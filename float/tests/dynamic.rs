@@ -0,0 +1,136 @@
+use core::convert::TryFrom;
+use dashu_base::{Approximation::*, Sign};
+use dashu_float::{
+    dynamic::{DynFBig, RoundingMode},
+    round::{mode, Rounding::*},
+    DBig, FBig,
+};
+use dashu_int::error::ParseError;
+
+mod helper_macros;
+
+#[test]
+fn test_conversion() {
+    let a = DynFBig::from(dbig!(-12.34));
+    assert_eq!(a.base(), 10);
+    assert_eq!(a.rounding(), RoundingMode::HalfAway);
+    assert_eq!(a.precision(), 4);
+    assert_eq!((a.significand().clone(), a.exponent()), ((-1234).into(), -2));
+    assert_eq!(a.sign(), Sign::Negative);
+    assert_eq!(DBig::try_from(a.clone()), Ok(dbig!(-12.34)));
+    assert!(FBig::<mode::Zero, 2>::try_from(a.clone()).is_err());
+
+    // the rounding mode must match, or be changed explicitly
+    assert!(FBig::<mode::HalfEven, 10>::try_from(a.clone()).is_err());
+    let b = FBig::<mode::HalfEven, 10>::try_from(a.with_rounding(RoundingMode::HalfEven)).unwrap();
+    assert_eq!(b, dbig!(-12.34));
+
+    let c = DynFBig::from(fbig!(0x1p-3));
+    assert_eq!((c.base(), c.rounding()), (2, RoundingMode::Zero));
+    assert_eq!(FBig::try_from(c), Ok(fbig!(0x1p-3)));
+
+    let inf = DynFBig::from(DBig::NEG_INFINITY);
+    assert!(inf.is_infinite());
+    assert_eq!(inf.sign(), Sign::Negative);
+    assert_eq!(DBig::try_from(-inf), Ok(DBig::INFINITY));
+    assert!(DynFBig::from(DBig::ZERO).is_zero());
+}
+
+#[test]
+fn test_parse() {
+    let a = DynFBig::parse("1.5", 10, RoundingMode::HalfEven).unwrap();
+    assert_eq!(a, DynFBig::from(dbig!(1.5)));
+    assert_eq!(a.rounding(), RoundingMode::HalfEven);
+    let b = DynFBig::parse("-a.8", 16, RoundingMode::Down).unwrap();
+    assert_eq!(b, DynFBig::from_parts((-0xa8).into(), -1, 16, RoundingMode::Down));
+    assert_eq!(b.to_string(), "-a.8");
+
+    assert_eq!(DynFBig::parse("1.5", 7, RoundingMode::Zero), Err(ParseError::UnsupportedRadix));
+    assert_eq!(DynFBig::parse("1.2", 2, RoundingMode::Zero), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_rounding_modes() {
+    let cases = [
+        (RoundingMode::Zero, "2.5", "-2.5"),
+        (RoundingMode::Away, "2.6", "-2.6"),
+        (RoundingMode::Up, "2.6", "-2.5"),
+        (RoundingMode::Down, "2.5", "-2.6"),
+        (RoundingMode::HalfEven, "2.6", "-2.6"),
+        (RoundingMode::HalfAway, "2.6", "-2.6"),
+        (RoundingMode::HalfDown, "2.5", "-2.5"),
+        (RoundingMode::HalfOdd, "2.5", "-2.5"),
+        (RoundingMode::ToOdd, "2.5", "-2.5"),
+    ];
+    for (mode, pos, neg) in cases {
+        let x = DynFBig::parse("2.55", 10, mode).unwrap();
+        assert_eq!(x.clone().with_precision(2).value().to_string(), pos);
+        assert_eq!((-x).with_precision(2).value().to_string(), neg);
+    }
+
+    let x = DynFBig::parse("2.55", 10, RoundingMode::HalfEven).unwrap();
+    assert_eq!(x.clone().with_precision(2), Inexact(DynFBig::from(dbig!(2.6)), AddOne));
+    assert_eq!(x.clone().with_precision(3), Exact(x.clone()));
+    assert_eq!(x.with_rounding(RoundingMode::HalfDown).rounding(), RoundingMode::HalfDown);
+}
+
+#[test]
+fn test_arithmetic() {
+    let mode = RoundingMode::HalfEven;
+    let a = DynFBig::parse("1.0", 10, mode).unwrap();
+    let b = DynFBig::parse("3", 10, mode).unwrap();
+    assert_eq!(&a + &b, DynFBig::from(dbig!(4)));
+    assert_eq!(&a - &b, DynFBig::from(dbig!(-2)));
+    assert_eq!(&a * b.clone(), DynFBig::from(dbig!(3)));
+    assert_eq!(a.clone() / &b, DynFBig::from(dbig!(0.33)));
+    assert_eq!((a.clone() / b.clone()).rounding(), mode);
+
+    let two = DynFBig::parse("2.0000", 10, mode).unwrap();
+    assert_eq!(two.sqrt().to_string(), "1.4142");
+    assert_eq!(two.exp().to_string(), "7.3891");
+    assert_eq!(two.ln().to_string(), "0.69315");
+    assert_eq!(two.powi(10.into()).to_string(), "1024");
+
+    let x = DynFBig::parse("1.1", 2, RoundingMode::Zero).unwrap();
+    assert_eq!(x.clone() * x, DynFBig::from(fbig!(0x2)));
+}
+
+#[test]
+fn test_base_conversion() {
+    let a = DynFBig::parse("1.25", 10, RoundingMode::HalfEven).unwrap();
+    let b = a.clone().with_base(2);
+    assert_eq!(b, Exact(DynFBig::from(fbig!(0x5p-2))));
+    let b = b.value();
+    assert_eq!((b.base(), b.rounding()), (2, RoundingMode::HalfEven));
+    assert_eq!(
+        b.with_base(16),
+        Exact(DynFBig::parse("1.4", 16, RoundingMode::HalfEven).unwrap())
+    );
+    assert_eq!(a.clone().with_base(10), Exact(a));
+
+    // numbers can't be created in unsupported bases
+    assert_eq!(DynFBig::parse("1", 3, RoundingMode::Zero), Err(ParseError::UnsupportedRadix));
+}
+
+#[test]
+#[should_panic]
+fn test_different_bases() {
+    let a = DynFBig::parse("1", 10, RoundingMode::HalfEven).unwrap();
+    let b = DynFBig::parse("1", 2, RoundingMode::HalfEven).unwrap();
+    let _ = a + b;
+}
+
+#[test]
+#[should_panic]
+fn test_different_rounding() {
+    let a = DynFBig::parse("1", 10, RoundingMode::HalfEven).unwrap();
+    let b = DynFBig::parse("1", 10, RoundingMode::HalfAway).unwrap();
+    let _ = a * b;
+}
+
+#[test]
+#[should_panic]
+fn test_unsupported_base() {
+    let a = DynFBig::parse("1", 10, RoundingMode::HalfEven).unwrap();
+    let _ = a.with_base(3);
+}
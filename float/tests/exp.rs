@@ -1,5 +1,8 @@
 use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, DBig};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
 
 mod helper_macros;
 
@@ -216,6 +219,17 @@ fn test_exp_unlimited_precision() {
     let _ = dbig!(2).with_precision(0).value().exp();
 }
 
+#[test]
+fn test_exp_directed_rounding() {
+    let x = dbig!(1.2345);
+    let up = Context::<mode::Up>::new(5);
+    let down = Context::<mode::Down>::new(5);
+    assert_eq!(up.exp(x.repr()), Inexact(dbig!(3.4367).with_rounding(), AddOne));
+    assert_eq!(down.exp(x.repr()), Inexact(dbig!(3.4366).with_rounding(), NoOp));
+    assert_eq!(up.ln(x.repr()), Inexact(dbig!(0.21067).with_rounding(), AddOne));
+    assert_eq!(down.ln(x.repr()), Inexact(dbig!(0.21066).with_rounding(), NoOp));
+}
+
 #[test]
 #[should_panic]
 fn test_exp_inf() {
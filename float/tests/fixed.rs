@@ -0,0 +1,141 @@
+use core::str::FromStr;
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    DBig, FBig, FixedI,
+};
+use dashu_int::IBig;
+
+mod helper_macros;
+
+type Q8 = FixedI<8>;
+
+fn q8(s: &str) -> Q8 {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_parse_format() {
+    assert_eq!(q8("1.5").raw(), &IBig::from(384));
+    assert_eq!(q8("-0.1").raw(), &IBig::from(-25)); // -25.6 rounded toward zero
+    assert_eq!(FixedI::<8, mode::HalfEven>::from_str("-0.1").unwrap().raw(), &IBig::from(-26));
+    assert_eq!(q8("1e2").raw(), &IBig::from(25600));
+    assert!("1.2.3".parse::<Q8>().is_err());
+
+    // the decimal expansion is exact
+    assert_eq!(Q8::from_raw(IBig::from(1)).to_string(), "0.00390625");
+    assert_eq!(q8("-3.75").to_string(), "-3.75");
+    assert_eq!(Q8::ZERO.to_string(), "0");
+    assert_eq!(FixedI::<0>::from(-42).to_string(), "-42");
+    let tiny = FixedI::<64>::from_raw(IBig::ONE);
+    assert_eq!(
+        tiny.to_string(),
+        "0.0000000000000000000542101086242752217003726400434970855712890625"
+    );
+
+    assert_eq!(format!("{:.2}", q8("-3.75")), "-3.75");
+    assert_eq!(format!("{:.1}", q8("-3.75")), "-3.7");
+    assert_eq!(format!("{:.4}", q8("0.5")), "0.5000");
+    assert_eq!(format!("{:>6}", q8("0.5")), "   0.5");
+    assert_eq!(format!("{:+08.2}", q8("1.5")), "+0001.50");
+
+    // no negative zero is printed after rounding
+    assert_eq!(format!("{:.1}", q8("-0.03125")), "0.0");
+    assert_eq!(format!("{:.0}", q8("-0.5")), "0");
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = q8("1.5");
+    let b = q8("-0.25");
+    assert_eq!(&a + &b, q8("1.25"));
+    assert_eq!(&a - &b, q8("1.75"));
+    assert_eq!(-&a, q8("-1.5"));
+    assert_eq!(b.abs(), q8("0.25"));
+    assert_eq!(&a * &b, q8("-0.375"));
+    assert_eq!(&a / &b, q8("-6"));
+    assert_eq!([a.clone(), b.clone()].iter().sum::<Q8>(), q8("1.25"));
+
+    // the product 3/256 * 3/256 is below the resolution
+    let c = Q8::from_raw(IBig::from(3));
+    assert_eq!(c.mul_rounded(&c), Inexact(Q8::ZERO, NoOp));
+    let c = FixedI::<8, mode::Up>::from_raw(IBig::from(3));
+    assert_eq!(c.mul_rounded(&c), Inexact(FixedI::from_raw(IBig::ONE), AddOne));
+    let c = FixedI::<8, mode::Down>::from_raw(IBig::from(-3));
+    assert_eq!(c.mul_rounded(&-&c), Inexact(FixedI::from_raw(IBig::NEG_ONE), SubOne));
+
+    assert_eq!(q8("1").div_rounded(&q8("3")), Inexact(Q8::from_raw(IBig::from(85)), NoOp));
+    let one = FixedI::<8, mode::HalfAway>::from(1);
+    assert_eq!(
+        one.div_rounded(&FixedI::from(-3)),
+        Inexact(FixedI::from_raw(IBig::from(-85)), NoOp)
+    );
+    assert_eq!(one.div_rounded(&FixedI::from(3)).value().raw(), &IBig::from(85));
+    assert_eq!(q8("1").checked_div(&Q8::ZERO), None);
+
+    assert_eq!(a.clone() * 3, q8("4.5"));
+    assert_eq!(a / 7, Q8::from_raw(IBig::from(54))); // 384 / 7 = 54.86
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = q8("1") / Q8::ZERO;
+}
+
+#[test]
+#[should_panic]
+fn test_div_int_by_zero() {
+    let _ = q8("1") / 0;
+}
+
+#[test]
+fn test_shift_rescale() {
+    let a = q8("-1.25");
+    assert_eq!(&a << 2, q8("-5"));
+    assert_eq!(&a >> 2, q8("-0.3125"));
+    assert_eq!(&a >> 8, Q8::from_raw(IBig::from(-1))); // -1.25 / 256 rounded toward zero
+    assert_eq!(a.clone() >> 9, Q8::ZERO);
+    let b = FixedI::<8, mode::HalfEven>::from_raw(IBig::from(6));
+    assert_eq!(b.clone() >> 2, FixedI::from_raw(IBig::from(2))); // 1.5 -> 2
+    assert_eq!(b >> 3, FixedI::from_raw(IBig::from(1))); // 0.75 -> 1
+
+    assert_eq!(a.rescale::<4>(), Exact("-1.25".parse().unwrap()));
+    assert_eq!(a.rescale::<16>(), Exact("-1.25".parse().unwrap()));
+    assert_eq!(a.rescale::<1>(), Inexact("-1".parse().unwrap(), NoOp));
+    assert_eq!(a.frac_bits(), 8);
+}
+
+#[test]
+fn test_convert() {
+    let a = q8("-2.5");
+    let b: FBig = FBig::from_parts(IBig::from(-5), -1);
+    assert_eq!(a.to_fbig(), b);
+    assert_eq!(FBig::from(a.clone()), b);
+    assert_eq!(a.to_f64(), Exact(-2.5));
+    assert_eq!(Q8::from(IBig::from(3)), q8("3"));
+
+    assert_eq!(Q8::from_fbig(&fbig!(-0x3p2)), Exact(q8("-12")));
+    assert_eq!(Q8::from_fbig(&fbig!(0x1p-9)), Inexact(Q8::ZERO, NoOp));
+    assert_eq!(Q8::from_fbig(&dbig!(0.375)), Exact(q8("0.375")));
+    assert_eq!(Q8::from_fbig(&dbig!(-1e3)), Exact(q8("-1000")));
+    assert_eq!(Q8::from_fbig(&DBig::ZERO), Exact(Q8::ZERO));
+
+    assert_eq!(Q8::try_from(0.75f64), Ok(q8("0.75")));
+    assert_eq!(Q8::try_from(-0.1f64), Ok(q8("-0.1")));
+    assert!(Q8::try_from(f64::NAN).is_err());
+    assert!(Q8::try_from(f64::INFINITY).is_err());
+
+    // round trip through f64 for a fixed-point number with 52 fractional bits
+    let c = FixedI::<52>::try_from(core::f64::consts::PI).unwrap();
+    assert_eq!(c.to_f64(), Exact(core::f64::consts::PI));
+}
+
+#[test]
+fn test_cmp() {
+    assert!(q8("1.01") > q8("1"));
+    assert!(q8("-1.5") < q8("-1"));
+    assert_eq!(Q8::default(), Q8::ZERO);
+    assert!(Q8::ZERO.is_zero());
+    assert_eq!(q8("-0.5").sign(), dashu_base::Sign::Negative);
+}
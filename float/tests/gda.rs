@@ -0,0 +1,125 @@
+use core::convert::TryFrom;
+use dashu_base::Sign;
+use dashu_float::{
+    dynamic::RoundingMode,
+    gda::{DecContext, DecNumber, DecRounding},
+    status::Flags,
+    DBig, DecimalFormat,
+};
+use dashu_int::{error::ParseError, UBig};
+
+mod helper_macros;
+
+#[test]
+fn test_parse_exact() {
+    let n: DecNumber = "-0012.3400E-2".parse().unwrap();
+    assert_eq!(n.sign(), Sign::Negative);
+    assert_eq!(n.coefficient(), Some(&UBig::from(123400u32)));
+    assert_eq!(n.exponent(), Some(-6));
+    assert_eq!(n.to_string(), "-0.123400");
+
+    assert_eq!("1.".parse::<DecNumber>().unwrap().to_string(), "1");
+    assert_eq!(".5".parse::<DecNumber>().unwrap().to_string(), "0.5");
+    assert_eq!("-Inf".parse::<DecNumber>().unwrap(), DecNumber::NEG_INFINITY);
+    assert_eq!("nan".parse::<DecNumber>().unwrap(), DecNumber::NAN);
+    assert_eq!("sNaN".parse::<DecNumber>().unwrap(), DecNumber::SNAN);
+    assert_eq!("NaN007".parse::<DecNumber>().unwrap().payload(), Some(&UBig::from(7u8)));
+
+    // exponents are saturated instead of overflowing
+    let huge: DecNumber = "1E+99999999999999999999999".parse().unwrap();
+    let mut ctxt = DecContext::new(9);
+    assert_eq!(ctxt.plus(&huge), DecNumber::INFINITY);
+    assert_eq!(ctxt.flags(), Flags::OVERFLOW | Flags::INEXACT | Flags::ROUNDED);
+
+    assert_eq!("".parse::<DecNumber>(), Err(ParseError::NoDigits));
+    assert_eq!("-".parse::<DecNumber>(), Err(ParseError::NoDigits));
+    assert_eq!(".".parse::<DecNumber>(), Err(ParseError::NoDigits));
+    assert_eq!("1E".parse::<DecNumber>(), Err(ParseError::NoDigits));
+    assert_eq!("1,0".parse::<DecNumber>(), Err(ParseError::InvalidDigit));
+    assert_eq!("1e+-2".parse::<DecNumber>(), Err(ParseError::InvalidDigit));
+    assert_eq!("NaNx".parse::<DecNumber>(), Err(ParseError::InvalidDigit));
+    assert_eq!(" 1".parse::<DecNumber>(), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_representation_equality() {
+    let a: DecNumber = "1.20".parse().unwrap();
+    let b: DecNumber = "1.2".parse().unwrap();
+    assert_ne!(a, b);
+    assert_ne!(DecNumber::ZERO, DecNumber::NEG_ZERO);
+
+    let mut ctxt = DecContext::new(9);
+    assert_eq!(ctxt.compare(&a, &b), DecNumber::ZERO);
+    assert_eq!(ctxt.compare(&DecNumber::ZERO, &DecNumber::NEG_ZERO), DecNumber::ZERO);
+    assert_eq!(ctxt.reduce(&a), b);
+    assert!(ctxt.flags().is_empty());
+}
+
+#[test]
+fn test_convert_fbig() {
+    let x = dbig!(-12.340);
+    let n = DecNumber::from(x.clone());
+    assert_eq!(n.to_string(), "-12.34");
+    assert_eq!(DBig::try_from(n).unwrap(), x);
+
+    let n: DecNumber = "1.200E+3".parse().unwrap();
+    let x = DBig::try_from(n).unwrap();
+    assert_eq!(x, dbig!(1200));
+    assert_eq!(x.precision(), 4);
+
+    assert_eq!(DecNumber::from(DBig::INFINITY), DecNumber::INFINITY);
+    assert_eq!(DBig::try_from(DecNumber::NEG_INFINITY).unwrap(), DBig::NEG_INFINITY);
+    assert_eq!(DBig::try_from(DecNumber::NEG_ZERO).unwrap(), DBig::ZERO);
+    assert!(DBig::try_from(DecNumber::NAN).is_err());
+    assert!(DBig::try_from(DecNumber::SNAN).is_err());
+}
+
+#[test]
+fn test_context() {
+    let ctxt = DecContext::new(7)
+        .with_rounding(DecRounding::Down)
+        .with_exponent_range(-95, 96)
+        .with_clamp(true);
+    assert_eq!(ctxt.precision(), 7);
+    assert_eq!(ctxt.rounding(), DecRounding::Down);
+    assert_eq!((ctxt.emin(), ctxt.emax()), (-95, 96));
+    assert!(ctxt.clamp());
+
+    let ctxt = DecContext::from(DecimalFormat::DECIMAL64);
+    assert_eq!(ctxt.precision(), 16);
+    assert_eq!(ctxt.rounding(), DecRounding::HalfEven);
+    assert_eq!((ctxt.emin(), ctxt.emax()), (-383, 384));
+    assert!(ctxt.clamp());
+
+    // the flags are sticky
+    let mut ctxt = DecContext::new(3);
+    let third = ctxt.divide(&"1".parse().unwrap(), &"3".parse().unwrap());
+    assert_eq!(third.to_string(), "0.333");
+    let sum = ctxt.add(&"1".parse().unwrap(), &"2".parse().unwrap());
+    assert_eq!(sum.to_string(), "3");
+    assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+    ctxt.clear_flags();
+    assert!(ctxt.flags().is_empty());
+}
+
+#[test]
+fn test_rounding() {
+    // the modes round towards the infinities like the other rounding modes of the crate
+    let mut ctxt = DecContext::new(2).with_rounding(DecRounding::Up);
+    let x: DecNumber = "-1.25".parse().unwrap();
+    assert_eq!(ctxt.plus(&x).to_string(), "-1.2");
+    let mut ctxt = ctxt.with_rounding(DecRounding::Down);
+    assert_eq!(ctxt.plus(&x).to_string(), "-1.3");
+    assert_eq!(DecRounding::Up.to_string(), "ceiling");
+    assert_eq!(DecRounding::Away.to_string(), "up");
+
+    assert_eq!(DecRounding::try_from(RoundingMode::Up), Ok(DecRounding::Up));
+    assert_eq!(DecRounding::try_from(RoundingMode::HalfAway), Ok(DecRounding::HalfAway));
+    assert_eq!(DecRounding::try_from(RoundingMode::ToOdd), Err(RoundingMode::ToOdd));
+}
+
+#[test]
+#[should_panic]
+fn test_zero_precision() {
+    let _ = DecContext::new(0);
+}
@@ -0,0 +1,405 @@
+use dashu_base::{Abs, Approximation::*};
+use dashu_float::{
+    round::{
+        mode::{HalfEven, Up, Zero},
+        Rounding::*,
+    },
+    BinaryFormat, DBig,
+    DecimalEncoding::{self, *},
+    DecimalFormat, FBig,
+};
+use dashu_int::{IBig, UBig};
+
+mod helper_macros;
+
+type HBig = FBig<HalfEven>;
+
+fn encode(x: &HBig, format: BinaryFormat) -> u128 {
+    x.to_bits(format).value().try_into().unwrap()
+}
+
+fn decode(bits: u128, format: BinaryFormat) -> HBig {
+    HBig::from_bits(&UBig::from(bits), format).unwrap()
+}
+
+/// Generate pseudo-random bit patterns using xorshift
+fn random_bits(count: usize) -> impl Iterator<Item = u64> {
+    let mut state = 0x2545f4914f6cdd1du64;
+    (0..count).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+#[test]
+fn test_format_parameters() {
+    let formats = [
+        (BinaryFormat::BINARY16, 16, 11, 15),
+        (BinaryFormat::BFLOAT16, 16, 8, 127),
+        (BinaryFormat::BINARY32, 32, 24, 127),
+        (BinaryFormat::BINARY64, 64, 53, 1023),
+        (BinaryFormat::BINARY128, 128, 113, 16383),
+        (BinaryFormat::BINARY256, 256, 237, 262143),
+    ];
+    for (format, bits, precision, emax) in formats {
+        assert_eq!(format.total_bits(), bits);
+        assert_eq!(format.precision(), precision);
+        assert_eq!(format.emax(), emax);
+        assert_eq!(format.emin(), 1 - emax);
+    }
+
+    let context = BinaryFormat::BINARY64.context::<HalfEven>();
+    assert_eq!(context.precision(), 53);
+    assert_eq!((context.emin(), context.emax()), (Some(-1022), Some(1023)));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_format() {
+    let _ = BinaryFormat::new(1, 10);
+}
+
+#[test]
+fn test_binary16() {
+    let half = BinaryFormat::BINARY16;
+    assert_eq!(encode(&HBig::ONE, half), 0x3c00);
+    assert_eq!(encode(&HBig::from(-2), half), 0xc000);
+    assert_eq!(encode(&HBig::from(65504), half), 0x7bff); // max finite
+    assert_eq!(encode(&HBig::from_parts(IBig::ONE, -14), half), 0x0400); // min normal
+    assert_eq!(encode(&HBig::from_parts(IBig::ONE, -24), half), 0x0001); // min subnormal
+    assert_eq!(encode(&HBig::from_parts(IBig::from(1023), -24), half), 0x03ff); // max subnormal
+    assert_eq!(encode(&HBig::ZERO, half), 0x0000);
+    assert_eq!(encode(&HBig::INFINITY, half), 0x7c00);
+    assert_eq!(encode(&HBig::NEG_INFINITY, half), 0xfc00);
+    assert_eq!(HBig::INFINITY.to_bits(half), Exact(UBig::from(0x7c00u16)));
+
+    // rounding
+    let third = HBig::ONE.with_precision(100).value() / HBig::from(3);
+    assert_eq!(third.to_bits(half), Inexact(UBig::from(0x3555u16), NoOp));
+    assert_eq!(HBig::from(2049).to_bits(half), Inexact(UBig::from(0x6800u16), NoOp)); // tie to even
+    assert_eq!(HBig::from(2051).to_bits(half), Inexact(UBig::from(0x6802u16), AddOne)); // tie to even
+    assert_eq!(HBig::from(65519).to_bits(half), Inexact(UBig::from(0x7bffu16), NoOp));
+    assert_eq!(HBig::from(65520).to_bits(half), Inexact(UBig::from(0x7c00u16), AddOne));
+    assert_eq!(HBig::from(-65520).to_bits(half), Inexact(UBig::from(0xfc00u16), SubOne));
+    let x = HBig::from_parts(IBig::from(3), -26); // 0.75 * 2^-24
+    assert_eq!(x.to_bits(half), Inexact(UBig::from(0x0001u16), AddOne));
+    let x = HBig::from_parts(IBig::ONE, -25); // 0.5 * 2^-24
+    assert_eq!(x.to_bits(half), Inexact(UBig::ZERO, NoOp));
+    let x = HBig::from_parts(IBig::NEG_ONE, -26); // -0.25 * 2^-24
+    assert_eq!(x.to_bits(half), Inexact(UBig::from(0x8000u16), NoOp));
+    let x = HBig::from_parts(IBig::from(2047), -25); // rounded up to the min normal
+    assert_eq!(x.to_bits(half), Inexact(UBig::from(0x0400u16), AddOne));
+
+    // the rounding mode of the number is used
+    let x = FBig::<Zero>::from(65520);
+    assert_eq!(x.to_bits(half), Inexact(UBig::from(0x7bffu16), NoOp));
+    let x = FBig::<Up>::from_parts(IBig::ONE, -100);
+    assert_eq!(x.to_bits(half), Inexact(UBig::from(0x0001u16), AddOne));
+
+    // decoding
+    assert_eq!(decode(0x3c00, half), HBig::ONE);
+    assert_eq!(decode(0x3555, half), HBig::from_parts(IBig::from(0x555), -12));
+    assert_eq!(decode(0x8001, half), HBig::from_parts(IBig::NEG_ONE, -24));
+    assert_eq!(decode(0x8000, half), HBig::ZERO);
+    assert_eq!(decode(0x7bff, half), HBig::from(65504));
+    assert_eq!(decode(0xfc00, half), HBig::NEG_INFINITY);
+    assert_eq!(decode(0x3c00, half).precision(), 11);
+    for bits in 0..=0xffffu128 {
+        if half.is_nan(&UBig::from(bits)) {
+            assert!(HBig::from_bits(&UBig::from(bits), half).is_err());
+        } else if bits != 0x8000 {
+            assert_eq!(encode(&decode(bits, half), half), bits);
+        }
+    }
+}
+
+#[test]
+fn test_bfloat16() {
+    let bf16 = BinaryFormat::BFLOAT16;
+    assert_eq!(encode(&HBig::ONE, bf16), 0x3f80);
+    assert_eq!(encode(&HBig::try_from(core::f32::consts::PI).unwrap(), bf16), 0x4049);
+    assert_eq!(encode(&HBig::INFINITY, bf16), 0x7f80);
+    assert!(bf16.is_nan(&UBig::from(0xffc1u16)));
+    assert!(!bf16.is_nan(&UBig::from(0xff80u16)));
+
+    // bfloat16 is f32 with the lower 16 bits rounded off
+    for bits in random_bits(1000) {
+        let f = f32::from_bits(bits as u32);
+        if f.is_nan() {
+            continue;
+        }
+        let bits = f.to_bits();
+        let expected = if f.is_infinite() {
+            bits >> 16
+        } else {
+            (bits + 0x7fff + ((bits >> 16) & 1)) >> 16
+        };
+        let x = HBig::try_from(f).unwrap();
+        assert_eq!(encode(&x, bf16), expected as u128);
+        assert_eq!(decode(bits as u128 >> 16, bf16), f32::from_bits(bits & 0xffff0000));
+    }
+}
+
+#[test]
+fn test_binary32_binary64() {
+    for bits in random_bits(1000) {
+        let f = f64::from_bits(bits);
+        if f.is_nan() {
+            assert!(BinaryFormat::BINARY64.is_nan(&UBig::from(bits)));
+            assert!(HBig::from_bits(&UBig::from(bits), BinaryFormat::BINARY64).is_err());
+            continue;
+        }
+        let x = HBig::try_from(f).unwrap();
+        if f != 0. || f.is_sign_positive() {
+            assert_eq!(x.to_bits(BinaryFormat::BINARY64), Exact(UBig::from(bits)));
+        }
+        assert_eq!(decode(bits as u128, BinaryFormat::BINARY64), x);
+
+        // the rounding is the same as the conversion from f64 to f32
+        assert_eq!(encode(&x, BinaryFormat::BINARY32), (f as f32).to_bits() as u128);
+        // the result is truncated with the rounding mode Zero
+        let x = x.with_rounding::<Zero>();
+        let truncated = x.to_bits(BinaryFormat::BINARY32).value();
+        let truncated = FBig::<Zero>::from_bits(&truncated, BinaryFormat::BINARY32).unwrap();
+        assert!(truncated.repr().is_finite());
+        assert!(truncated.abs() <= x.abs());
+    }
+}
+
+#[test]
+fn test_binary128() {
+    let quad = BinaryFormat::BINARY128;
+    assert_eq!(encode(&HBig::ONE, quad), 0x3fff << 112);
+    assert_eq!(encode(&HBig::from(-2), quad), 0xc000 << 112);
+    assert_eq!(encode(&HBig::from_parts(IBig::ONE, -16494), quad), 1); // min subnormal
+    assert_eq!(encode(&HBig::from_parts(IBig::ONE, -16382), quad), 1 << 112); // min normal
+    assert_eq!(encode(&HBig::INFINITY, quad), 0x7fff << 112);
+    let max = HBig::from_parts((IBig::ONE << 113) - 1, 16383 - 112);
+    assert_eq!(encode(&max, quad), (0x7fff << 112) - 1);
+    assert_eq!(
+        encode(&HBig::try_from(core::f64::consts::PI).unwrap(), quad),
+        0x4000921fb54442d18000000000000000
+    );
+    let third = HBig::ONE.with_precision(200).value() / HBig::from(3);
+    assert_eq!(encode(&third, quad), 0x3ffd5555555555555555555555555555);
+    assert_eq!(decode(0x3ffd5555555555555555555555555555, quad).precision(), 113);
+
+    // f64 values are exactly representable in binary128
+    for bits in random_bits(1000) {
+        let f = f64::from_bits(bits);
+        if f.is_nan() || f == 0. {
+            continue;
+        }
+        let x = HBig::try_from(f).unwrap();
+        let encoded = x.to_bits(quad);
+        assert!(matches!(encoded, Exact(_)));
+        assert_eq!(HBig::from_bits(&encoded.value(), quad).unwrap(), x);
+    }
+}
+
+#[test]
+fn test_binary256() {
+    let octuple = BinaryFormat::BINARY256;
+    let one = HBig::ONE.to_bits(octuple).value();
+    assert_eq!(one, UBig::from(0x3ffffu32) << 236);
+    assert_eq!(HBig::from_bits(&one, octuple), Ok(HBig::ONE));
+    let min = HBig::from_parts(IBig::NEG_ONE, -262378);
+    assert_eq!(min.to_bits(octuple), Exact((UBig::ONE << 255) | UBig::ONE));
+    assert_eq!(HBig::NEG_INFINITY.to_bits(octuple).value(), UBig::from(0xfffffu32) << 236);
+
+    let x = HBig::from_str_native(
+        "0x1.23456789abcdef0123456789abcdef0123456789abcdef0123456789ap-1000",
+    )
+    .unwrap();
+    let bits = x.to_bits(octuple).value();
+    assert_eq!(HBig::from_bits(&bits, octuple), Ok(x));
+    assert!(HBig::from_bits(&(UBig::ONE << 256), octuple).is_err());
+}
+
+#[test]
+fn test_custom_format() {
+    // 8-bit format with 4 exponent bits and 3 mantissa bits
+    let format = BinaryFormat::new(4, 3);
+    assert_eq!(format.total_bits(), 8);
+    assert_eq!(encode(&HBig::from(240), format), 0x77); // max finite
+    assert_eq!(encode(&HBig::from(248), format), 0x78); // overflow
+    assert_eq!(encode(&HBig::from_parts(IBig::ONE, -9), format), 0x01);
+    assert_eq!(encode(&HBig::from_parts(IBig::from(-3), -1), format), 0xbc);
+    for bits in 0..=0xffu128 {
+        match HBig::from_bits(&UBig::from(bits), format) {
+            Ok(x) if bits != 0x80 => assert_eq!(encode(&x, format), bits),
+            Ok(x) => assert!(x.repr().is_zero()),
+            Err(_) => assert!(format.is_nan(&UBig::from(bits))),
+        }
+    }
+    assert!(HBig::from_bits(&UBig::from(0x100u16), format).is_err());
+}
+
+fn encode_dec(x: &DBig, format: DecimalFormat, encoding: DecimalEncoding) -> u128 {
+    x.to_decimal_bits(format, encoding)
+        .value()
+        .try_into()
+        .unwrap()
+}
+
+fn decode_dec(bits: u128, format: DecimalFormat, encoding: DecimalEncoding) -> DBig {
+    DBig::from_decimal_bits(&UBig::from(bits), format, encoding).unwrap()
+}
+
+#[test]
+fn test_decimal_format_parameters() {
+    let formats = [
+        (DecimalFormat::DECIMAL32, 32, 7, 96),
+        (DecimalFormat::DECIMAL64, 64, 16, 384),
+        (DecimalFormat::DECIMAL128, 128, 34, 6144),
+    ];
+    for (format, bits, precision, emax) in formats {
+        assert_eq!(format.total_bits(), bits);
+        assert_eq!(format.precision(), precision);
+        assert_eq!(format.emax(), emax);
+        assert_eq!(format.emin(), 1 - emax);
+        assert_eq!(format.context::<HalfEven>().emax(), Some(emax));
+    }
+}
+
+#[test]
+fn test_decimal_known_values() {
+    use DecimalFormat as F;
+    let cases = [
+        // (value, format, bid, dpd)
+        (dbig!(1), F::DECIMAL32, 0x32800001, 0x22500001),
+        (dbig!(-7.5), F::DECIMAL32, 0xb200004b, 0xa2400075),
+        (dbig!(9.999999e96), F::DECIMAL32, 0x77f8967f, 0x77f3fcff),
+        (dbig!(1e-101), F::DECIMAL32, 0x00000001, 0x00000001),
+        (dbig!(1), F::DECIMAL64, 0x31c0000000000001, 0x2238000000000001),
+        (
+            dbig!(9.999999999999999e384),
+            F::DECIMAL64,
+            0x77fb86f26fc0ffff,
+            0x77fcff3fcff3fcff,
+        ),
+        (dbig!(-1e-398), F::DECIMAL64, 0x8000000000000001, 0x8000000000000001),
+        (
+            dbig!(1),
+            F::DECIMAL128,
+            0x30400000000000000000000000000001,
+            0x22080000000000000000000000000001,
+        ),
+    ];
+    for (value, format, bid, dpd) in cases {
+        assert_eq!(value.to_decimal_bits(format, Bid), Exact(UBig::from(bid)));
+        assert_eq!(value.to_decimal_bits(format, Dpd), Exact(UBig::from(dpd)));
+        assert_eq!(decode_dec(bid, format, Bid), value);
+        assert_eq!(decode_dec(dpd, format, Dpd), value);
+        assert_eq!(decode_dec(bid, format, Bid).precision(), format.precision());
+    }
+
+    for format in [F::DECIMAL32, F::DECIMAL64, F::DECIMAL128] {
+        let sign = 1u128 << (format.total_bits() - 1);
+        let inf = 0b11110u128 << (format.total_bits() - 6);
+        for encoding in [Bid, Dpd] {
+            assert_eq!(encode_dec(&DBig::INFINITY, format, encoding), inf);
+            assert_eq!(DBig::INFINITY.to_decimal_bits(format, encoding), Exact(UBig::from(inf)));
+            assert_eq!(encode_dec(&DBig::NEG_INFINITY, format, encoding), sign | inf);
+            assert_eq!(decode_dec(sign | inf, format, encoding), DBig::NEG_INFINITY);
+            assert_eq!(decode_dec(sign, format, encoding), DBig::ZERO);
+
+            let nan = UBig::from(0b11111u128 << (format.total_bits() - 6));
+            assert!(format.is_nan(&nan));
+            assert!(DBig::from_decimal_bits(&nan, format, encoding).is_err());
+            let wide = UBig::ONE << format.total_bits();
+            assert!(DBig::from_decimal_bits(&wide, format, encoding).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_decimal_rounding() {
+    let d64 = DecimalFormat::DECIMAL64;
+
+    // rounded to 16 digits
+    let x = dbig!(1.23456789012345678);
+    assert_eq!(x.to_decimal_bits(d64, Bid), Inexact(UBig::from(0x2fe462d53c8abac1u64), AddOne));
+    assert_eq!(decode_dec(0x2fe462d53c8abac1, d64, Bid), dbig!(1.234567890123457));
+
+    // the exponent is clamped when the coefficient has trailing zeros
+    let bits = encode_dec(&dbig!(1e384), d64, Bid);
+    assert_eq!(bits, (767u128 << 53) | 1_000_000_000_000_000);
+    assert_eq!(decode_dec(bits, d64, Bid), dbig!(1e384));
+    let bits = encode_dec(&dbig!(1e384), d64, Dpd);
+    assert_eq!(decode_dec(bits, d64, Dpd), dbig!(1e384));
+
+    // overflow
+    assert_eq!(
+        dbig!(1e385).to_decimal_bits(d64, Bid),
+        Inexact(UBig::from(0x7800000000000000u64), AddOne)
+    );
+    let x = FBig::<Zero, 10>::from_parts(IBig::from(-1), 385);
+    assert_eq!(x.to_decimal_bits(d64, Dpd), Inexact(UBig::from(0xf7fcff3fcff3fcffu64), NoOp));
+
+    // subnormal numbers and underflow
+    assert_eq!(dbig!(1.5e-398).to_decimal_bits(d64, Bid), Inexact(UBig::from(2u8), AddOne));
+    assert_eq!(dbig!(1.23e-396).to_decimal_bits(d64, Dpd), Exact(UBig::from(0xa3u8)));
+    assert_eq!(
+        dbig!(-1.234e-396).to_decimal_bits(d64, Bid),
+        Inexact(UBig::from(0x800000000000007bu64), NoOp)
+    );
+    assert_eq!(
+        dbig!(-1e-400).to_decimal_bits(d64, Bid),
+        Inexact(UBig::from(0xb1c0000000000000u64), NoOp)
+    );
+}
+
+#[test]
+fn test_decimal_noncanonical() {
+    let d64 = DecimalFormat::DECIMAL64;
+    // BID coefficient larger than 10^16 - 1 is decoded as zero
+    let bits = (0b11u128 << 61) | (398 << 51) | ((1 << 51) - 1);
+    assert_eq!(decode_dec(bits, d64, Bid), DBig::ZERO);
+    // DPD declets with redundant bits are decoded as 9s
+    assert_eq!(decode_dec(0x22380000000003ff, d64, Dpd), dbig!(999));
+    assert_eq!(decode_dec(0x223800000000016e, d64, Dpd), dbig!(888));
+}
+
+#[test]
+fn test_decimal_roundtrip() {
+    // all the declets
+    for n in 0..1000 {
+        let x = DBig::from(n);
+        assert_eq!(
+            decode_dec(
+                encode_dec(&x, DecimalFormat::DECIMAL32, Dpd),
+                DecimalFormat::DECIMAL32,
+                Dpd
+            ),
+            x
+        );
+    }
+
+    let mut random = random_bits(3000);
+    for format in [
+        DecimalFormat::DECIMAL32,
+        DecimalFormat::DECIMAL64,
+        DecimalFormat::DECIMAL128,
+    ] {
+        for _ in 0..500 {
+            let bits = ((random.next().unwrap() as u128) << 64 | random.next().unwrap() as u128)
+                >> (128 - format.total_bits());
+            for encoding in [Bid, Dpd] {
+                if format.is_nan(&UBig::from(bits)) {
+                    continue;
+                }
+                // the decoded value can be encoded exactly in both encodings
+                let x = decode_dec(bits, format, encoding);
+                for encoding in [Bid, Dpd] {
+                    let encoded = x.to_decimal_bits(format, encoding);
+                    assert!(matches!(encoded, Exact(_)));
+                    let encoded = encoded.value().try_into().unwrap();
+                    assert_eq!(decode_dec(encoded, format, encoding), x);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use dashu_float::{round::mode, DBig, FBig, Interval};
+
+mod helper_macros;
+
+type FBigDown = FBig<mode::Down, 10>;
+
+fn interval(lo: DBig, hi: DBig) -> Interval<10> {
+    Interval::new(lo, hi)
+}
+
+fn assert_interval(x: &Interval<10>, lo: DBig, hi: DBig) {
+    assert_eq!(x.lo(), &lo, "lower endpoint of {}", x);
+    assert_eq!(x.hi(), &hi, "upper endpoint of {}", x);
+}
+
+#[test]
+fn test_construct() {
+    let a = interval(dbig!(-1), dbig!(2));
+    assert_eq!(a.to_string(), "[-1, 2]");
+    assert!(a.contains_zero() && a.is_bounded() && !a.is_point());
+    assert!(a.contains(&dbig!(0.5)));
+    assert!(!a.contains(&dbig!(2.01)));
+    assert!(!interval(dbig!(1), dbig!(2)).contains_zero());
+    assert!(!interval(dbig!(-2), dbig!(-1)).contains_zero());
+
+    let p = Interval::point(dbig!(1.5));
+    assert!(p.is_point());
+    assert!(p.is_subset(&a));
+    assert!(!a.is_subset(&p));
+    assert_eq!(p.width(), FBig::<mode::Up, 10>::ZERO);
+    assert_eq!(a.width(), FBig::<mode::Up, 10>::from_parts(3.into(), 0));
+
+    let e = Interval::<10>::entire();
+    assert!(!e.is_bounded());
+    assert!(a.is_subset(&e));
+    assert_eq!(e.to_string(), "[-inf, inf]");
+    let half = interval(dbig!(1), DBig::INFINITY);
+    assert!(half.contains(&dbig!(1e100)));
+    assert_eq!(half.width(), FBig::<mode::Up, 10>::INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_interval() {
+    let _ = interval(dbig!(2), dbig!(1));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_infinite_interval() {
+    let _ = interval(DBig::INFINITY, DBig::INFINITY);
+}
+
+#[test]
+fn test_set_operations() {
+    let a = interval(dbig!(1), dbig!(3));
+    let b = interval(dbig!(2), dbig!(4));
+    let c = interval(dbig!(5), dbig!(6));
+    assert_interval(&a.hull(&b), dbig!(1), dbig!(4));
+    assert_interval(&a.hull(&c), dbig!(1), dbig!(6));
+    assert_interval(&a.intersection(&b).unwrap(), dbig!(2), dbig!(3));
+    assert_eq!(a.intersection(&c), None);
+    assert_eq!(a.intersection(&interval(dbig!(3), dbig!(5))), Some(Interval::point(dbig!(3))));
+    assert_eq!(a.intersection(&Interval::entire()), Some(a.clone()));
+}
+
+#[test]
+fn test_add_sub() {
+    let a = interval(dbig!(1.1), dbig!(1.2));
+    let b = interval(dbig!(1e-2), dbig!(2e-2));
+    assert_interval(&(&a + &b), dbig!(1.1), dbig!(1.3));
+    // subtraction keeps an extra digit to prevent cancellation
+    assert_interval(&(&a - &b), dbig!(1.08), dbig!(1.19));
+    assert_interval(&(-&a), dbig!(-1.2), dbig!(-1.1));
+
+    let a = interval(dbig!(1.10), dbig!(1.20));
+    assert_interval(&(&a + &b), dbig!(1.11), dbig!(1.22));
+    assert_interval(&(&a - &b), dbig!(1.08), dbig!(1.19));
+
+    // unbounded intervals
+    let c = interval(DBig::NEG_INFINITY, dbig!(1));
+    assert_interval(&(&a + &c), DBig::NEG_INFINITY, dbig!(2.20));
+    assert_interval(&(&a - &c), dbig!(0.10), DBig::INFINITY);
+    assert_eq!(&c - &c, Interval::entire());
+}
+
+#[test]
+fn test_mul() {
+    let cases = [
+        // lhs, rhs, result
+        ((dbig!(1), dbig!(2)), (dbig!(3), dbig!(4)), (dbig!(3), dbig!(8))),
+        ((dbig!(-1), dbig!(2)), (dbig!(3), dbig!(4)), (dbig!(-4), dbig!(8))),
+        ((dbig!(-1), dbig!(2)), (dbig!(-3), dbig!(4)), (dbig!(-6), dbig!(8))),
+        ((dbig!(-2), dbig!(-1)), (dbig!(-3), dbig!(4)), (dbig!(-8), dbig!(6))),
+        ((dbig!(-2), dbig!(-1)), (dbig!(-4), dbig!(-3)), (dbig!(3), dbig!(8))),
+        ((dbig!(0), dbig!(1)), (dbig!(1), DBig::INFINITY), (dbig!(0), DBig::INFINITY)),
+        (
+            (dbig!(-1), dbig!(1)),
+            (dbig!(1), DBig::INFINITY),
+            (DBig::NEG_INFINITY, DBig::INFINITY),
+        ),
+        ((dbig!(0), dbig!(0)), (DBig::NEG_INFINITY, DBig::INFINITY), (dbig!(0), dbig!(0))),
+    ];
+    for ((a0, a1), (b0, b1), (c0, c1)) in cases {
+        let (a, b) = (interval(a0, a1), interval(b0, b1));
+        assert_interval(&(&a * &b), c0.clone(), c1.clone());
+        assert_interval(&(&b * &a), c0, c1);
+    }
+
+    // rounding is outward
+    let a = interval(dbig!(1.1), dbig!(1.3));
+    assert_interval(&(&a * &a), dbig!(1.2), dbig!(1.7));
+    assert_interval(&(&a * -&a), dbig!(-1.7), dbig!(-1.2));
+}
+
+#[test]
+fn test_div() {
+    let one = Interval::point(dbig!(1.0));
+    let three = Interval::point(dbig!(3));
+    assert_interval(&(&one / &three), dbig!(0.33), dbig!(0.34));
+    assert_interval(&(-&one / &three), dbig!(-0.34), dbig!(-0.33));
+
+    let a = interval(dbig!(1), dbig!(2));
+    let b = interval(dbig!(-4), dbig!(-2));
+    assert_interval(&(&a / &b), dbig!(-1), dbig!(-0.2));
+    assert_interval(&(&b / &a), dbig!(-4), dbig!(-1));
+    assert_interval(&(&a / interval(dbig!(1), DBig::INFINITY)), dbig!(0), dbig!(2));
+
+    // divisor containing zero
+    let pos = interval(dbig!(1), dbig!(2));
+    let neg = interval(dbig!(-2), dbig!(-1));
+    let cases = [
+        // lhs, rhs, lower part, upper part
+        (&pos, (dbig!(0), dbig!(4)), (dbig!(0.2), DBig::INFINITY), None),
+        (&pos, (dbig!(-4), dbig!(0)), (DBig::NEG_INFINITY, dbig!(-0.2)), None),
+        (&neg, (dbig!(0), dbig!(4)), (DBig::NEG_INFINITY, dbig!(-0.2)), None),
+        (&neg, (dbig!(-4), dbig!(0)), (dbig!(0.2), DBig::INFINITY), None),
+        (&pos, (dbig!(0), DBig::INFINITY), (dbig!(0), DBig::INFINITY), None),
+        (
+            &pos,
+            (dbig!(-4), dbig!(2)),
+            (DBig::NEG_INFINITY, dbig!(-0.2)),
+            Some((dbig!(0.5), DBig::INFINITY)),
+        ),
+        (
+            &neg,
+            (dbig!(-4), dbig!(2)),
+            (DBig::NEG_INFINITY, dbig!(-0.5)),
+            Some((dbig!(0.2), DBig::INFINITY)),
+        ),
+    ];
+    for (lhs, (b0, b1), (lo0, lo1), hi) in cases {
+        let rhs = interval(b0, b1);
+        let (lo, hi_part) = lhs.div_extended(&rhs);
+        assert_interval(&lo, lo0.clone(), lo1.clone());
+        match hi {
+            None => {
+                assert_eq!(hi_part, None);
+                assert_interval(&(lhs / &rhs), lo0, lo1);
+            }
+            Some((hi0, hi1)) => {
+                assert_interval(&hi_part.unwrap(), hi0, hi1);
+                assert_eq!(lhs / &rhs, Interval::entire());
+            }
+        }
+    }
+
+    // both operands containing zero
+    let z = interval(dbig!(-1), dbig!(1));
+    assert_eq!(&z / &z, Interval::entire());
+    assert_eq!(z.div_extended(&pos), (interval(dbig!(-1), dbig!(1)), None));
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _ = Interval::point(dbig!(1)) / Interval::point(dbig!(0));
+}
+
+#[test]
+fn test_functions() {
+    // compare with the results calculated with much higher precision
+    let precise =
+        |x: &Interval<10>| -> DBig { x.lo().clone().with_precision(50).value().with_rounding() };
+
+    let a = interval(dbig!(1.2345), dbig!(1.2346));
+    let sqrt = a.sqrt();
+    assert!(sqrt.contains(&precise(&a).sqrt()));
+    assert_interval(&sqrt, dbig!(1.1110), dbig!(1.1112));
+    assert_interval(&interval(dbig!(-1), dbig!(4.0)).sqrt(), dbig!(0), dbig!(2.0));
+    assert_interval(&interval(dbig!(4), DBig::INFINITY).sqrt(), dbig!(2), DBig::INFINITY);
+
+    let exp = a.exp();
+    assert!(exp.contains(&precise(&a).exp()));
+    assert!(exp.width() <= dbig!(3e-3));
+    assert_interval(&interval(DBig::NEG_INFINITY, dbig!(0)).exp(), dbig!(0), dbig!(1));
+
+    let ln = a.ln();
+    assert!(ln.contains(&precise(&a).ln()));
+    assert_interval(&interval(dbig!(0), dbig!(1)).ln(), DBig::NEG_INFINITY, dbig!(0));
+    assert_interval(&interval(dbig!(1), DBig::INFINITY).ln(), dbig!(0), DBig::INFINITY);
+
+    let x = interval(dbig!(-1.5), dbig!(1.2));
+    assert_interval(&x.powi(0.into()), dbig!(1), dbig!(1));
+    // inexact powers are widened by one ulp
+    assert_interval(&x.powi(2.into()), dbig!(0), dbig!(2.4));
+    assert_interval(&x.powi(3.into()), dbig!(-3.5), dbig!(1.9));
+    assert_interval(&(-&x).powi(3.into()), dbig!(-1.9), dbig!(3.5));
+    assert_interval(&interval(dbig!(-1.5), dbig!(-1.2)).powi(2.into()), dbig!(1.3), dbig!(2.4));
+    assert_interval(&x.powi((-1).into()), DBig::NEG_INFINITY, DBig::INFINITY);
+    assert_interval(&interval(dbig!(2.0), dbig!(4.0)).powi((-1).into()), dbig!(0.25), dbig!(0.50));
+    let b = interval(dbig!(1.11), dbig!(1.12));
+    let pow = b.powi(7.into());
+    assert!(pow.contains(&precise(&b).powi(7.into())));
+    assert!(pow.lo() < &FBigDown::from_parts(2076.into(), -3));
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = interval(dbig!(-2), dbig!(-1)).sqrt();
+}
+
+#[test]
+#[should_panic]
+fn test_ln_non_positive() {
+    let _ = interval(dbig!(-2), dbig!(0)).ln();
+}
+
+#[test]
+fn test_binary() {
+    let a = Interval::point(fbig!(0x1p-1));
+    let b = Interval::new(fbig!(0x3), fbig!(0x5));
+    let c = &a / &b;
+    assert!(c.contains(&fbig!(0x1p-3)));
+    assert!(c.lo() < &fbig!(0x1p-3));
+    assert!(c.hi() > &fbig!(0x1p-3));
+    assert!(c.contains(&(fbig!(0x1).with_precision(40).value() / fbig!(0x6))));
+}
@@ -1,7 +1,11 @@
 use core::str::FromStr;
+use dashu_base::Approximation::*;
 use dashu_base::Sign;
-use dashu_float::{round::mode, DBig, FBig};
-use dashu_int::{error::ParseError, DoubleWord, IBig, Word};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig, FBig,
+};
+use dashu_int::{error::ParseError, DoubleWord, IBig, UBig, Word};
 
 mod helper_macros;
 type FBin = FBig;
@@ -133,6 +137,11 @@ fn test_from_str_binary() {
     assert_eq!(FBin::from_str("1p8"), Err(ParseError::InvalidDigit));
     assert_eq!(FBin::from_str(".1p8"), Err(ParseError::InvalidDigit));
     assert_eq!(FBin::from_str("1.0p8"), Err(ParseError::InvalidDigit));
+
+    // digits are required after the prefix
+    assert_eq!(FBin::from_str("0x.p1"), Err(ParseError::NoDigits));
+    assert_eq!(FBin::from_str("-0x."), Err(ParseError::NoDigits));
+    assert_eq!(FBin::from_str("0xp1"), Err(ParseError::NoDigits));
 }
 
 #[test]
@@ -207,6 +216,115 @@ fn test_from_str_other_bases() {
     );
 }
 
+#[test]
+fn test_from_str_radix() {
+    let a = FBin::from_str_radix("0.1", 10).unwrap();
+    assert_eq!(a, fbig!(0x66p-10));
+    assert_eq!(a.precision(), 7);
+    assert_eq!(FBin::from_str_radix("-12.5e1", 10).unwrap(), fbig!(-0x7dp0));
+    assert_eq!(FBin::from_str_radix("0x1.8p1", 2).unwrap(), fbig!(0x3));
+    assert_eq!(FBin::from_str_radix("z@-1", 36).unwrap(), fbig!(0x1fp-5)); // 35/36 rounded to 6 bits
+    assert_eq!(DBig::from_str_radix("-0.01", 2).unwrap(), dbig!(-0.3)); // 0.25 rounded to 1 digit
+    assert_eq!(FHex::from_str_radix("255", 10).unwrap(), FHex::from_str("ff").unwrap());
+    assert_eq!(DBig::from_str_radix("1.234e5", 10).unwrap(), dbig!(1.234e5));
+    assert_eq!(FBin::from_str_radix("1.2.3", 10).unwrap_err(), ParseError::InvalidDigit);
+    assert_eq!(FBin::from_str_radix("e5", 10).unwrap_err(), ParseError::NoDigits);
+    assert_eq!(FBin::from_str_radix("12", 2).unwrap_err(), ParseError::InvalidDigit);
+}
+
+#[test]
+fn test_context_parse() {
+    let context = Context::<mode::HalfEven>::new(53).with_exponent_range(-1022, 1023);
+    let parse = |s: &str| -> FBig<mode::HalfEven> { context.parse(s).unwrap().value() };
+    let to_fbig = |f: f64| FBig::<mode::HalfEven>::try_from(f).unwrap();
+
+    // compare with the correctly rounded parsing of f64 in the standard library
+    let mut state = 0x2545f4914f6cdd1du64;
+    for _ in 0..500 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let ndigits = (state % 25) as usize + 1;
+        let digits: String = (0..ndigits)
+            .map(|i| char::from(b'0' + ((state >> (i * 2 % 60)) % 10) as u8))
+            .collect();
+        let exp = (state >> 40) as i64 % 620 - 320;
+        let text = format!("{}.{}e{}", &digits[..1], &digits[1..], exp);
+        assert_eq!(parse(&text), to_fbig(text.parse::<f64>().unwrap()), "{}", text);
+    }
+    assert_eq!(parse("0.1"), to_fbig(0.1));
+    assert_eq!(parse("-2.5e-320"), to_fbig(-2.5e-320));
+    assert_eq!(parse("1e400"), FBig::<mode::HalfEven>::INFINITY);
+
+    // halfway cases
+    assert_eq!(parse("9007199254740993"), to_fbig(9007199254740992.0));
+    let long = format!("9007199254740993.{}1", "0".repeat(2000));
+    assert_eq!(parse(&long), to_fbig(9007199254740994.0));
+    let long = format!("9007199254740992.{}", "9".repeat(2000));
+    assert_eq!(parse(&long), to_fbig(9007199254740992.0));
+
+    // rounding status and other bases
+    let context = Context::<mode::Zero>::new(4);
+    assert_eq!(context.parse::<2>("0.75"), Ok(Exact(fbig!(0x3p-2))));
+    assert_eq!(context.parse::<2>("-0.1"), Ok(Inexact(fbig!(-0xcp-7), NoOp)));
+    assert_eq!(
+        context.parse::<16>("4096.5"),
+        Ok(Inexact(FHex::from_str("1000").unwrap(), NoOp))
+    );
+    assert_eq!(
+        context.parse::<10>("-1234567"),
+        Ok(Inexact(dbig!(-1234e3).with_rounding(), NoOp))
+    );
+    assert_eq!(context.parse_radix::<10>("-1.1b-1", 2), Ok(Exact(dbig!(-0.75).with_rounding())));
+    assert_eq!(context.parse::<2>("0x1p1"), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_context_parse_large_exponent() {
+    // compare with the exact conversions
+    let context = Context::<mode::HalfEven>::new(53);
+    let exact = |s: i64, e: usize| -> FBig<mode::HalfEven> {
+        let pow = FBig::<mode::HalfEven>::from(UBig::from(10u8).pow(e));
+        context
+            .mul(FBig::<mode::HalfEven>::from(s).repr(), pow.repr())
+            .value()
+    };
+    for (s, e) in [(1, 100000), (-7, 12345)] {
+        let text = format!("{}e{}", s, e);
+        assert_eq!(context.parse::<2>(&text).unwrap().value(), exact(s, e), "{}", text);
+    }
+    assert_eq!(
+        context.parse::<2>("123e-100000"),
+        Ok(Inexact(FBig::from_parts(ibig!(2469303804647341), -332237), NoOp))
+    );
+    assert_eq!(
+        context.parse::<2>("-99e-54321"),
+        Ok(Inexact(FBig::from_parts(ibig!(-5078466132406211), -180496), SubOne))
+    );
+
+    // the time doesn't depend on the exponent
+    let top = |f: FBig<mode::HalfEven>| f.repr().exponent() + f.repr().digits() as isize - 1;
+    assert_eq!(top(context.parse::<2>("1e999999999").unwrap().value()), 3321928091);
+    assert_eq!(top(context.parse::<2>("-1e-999999999").unwrap().value()), -3321928092);
+    assert_eq!(context.parse::<2>("1e9223372036854775807"), Ok(Inexact(FBig::INFINITY, AddOne)));
+    assert_eq!(context.parse::<2>("-1e-9223372036854775807"), Ok(Inexact(FBig::ZERO, NoOp)));
+
+    // overflow and underflow with an exponent range
+    let context = context.with_exponent_range(-1022, 1023);
+    assert_eq!(context.parse::<2>("1e999999999"), Ok(Inexact(FBig::INFINITY, AddOne)));
+    assert_eq!(context.parse::<2>("-1e999999999"), Ok(Inexact(FBig::NEG_INFINITY, SubOne)));
+    assert_eq!(context.parse::<2>("1e-999999999"), Ok(Inexact(FBig::ZERO, NoOp)));
+    let context = Context::<mode::Up>::new(53).with_exponent_range(-1022, 1023);
+    assert_eq!(
+        context.parse::<2>("1e-999999999"),
+        Ok(Inexact(FBin::try_from(f64::from_bits(1)).unwrap().with_rounding(), AddOne))
+    );
+    let max = FBin::try_from(f64::MAX)
+        .unwrap()
+        .with_rounding::<mode::Up>();
+    assert_eq!(context.parse::<2>("-1e999999999"), Ok(Inexact(-max, NoOp)));
+}
+
 #[test]
 fn test_from_parts() {
     assert_eq!(FBin::from_parts(ibig!(0), 2), FBin::ZERO);
@@ -368,6 +486,82 @@ fn test_format_binary() {
     assert_eq!(format!("{:=>+16.8}", fbig!(0x123p-4)), "=+10010.00110000");
 }
 
+#[test]
+fn test_format_hex() {
+    assert_eq!(format!("{:x}", fbig!(0x0)), "0x0p+0");
+    assert_eq!(format!("{:x}", fbig!(0x1)), "0x1p+0");
+    assert_eq!(format!("{:x}", fbig!(-0x1)), "-0x1p+0");
+    assert_eq!(format!("{:x}", fbig!(0x1p4)), "0x1p+4");
+    assert_eq!(format!("{:x}", fbig!(0x1p-4)), "0x1p-4");
+    assert_eq!(format!("{:x}", fbig!(0x3p-1)), "0x1.8p+0");
+    assert_eq!(format!("{:x}", fbig!(0x123p-4)), "0x1.23p+4");
+    assert_eq!(format!("{:x}", fbig!(-0xabcdefp-100)), "-0x1.579bdep-77");
+    assert_eq!(format!("{:X}", fbig!(-0xabcdefp-100)), "-0X1.579BDEP-77");
+    assert_eq!(format!("{:x}", FBin::INFINITY), "inf");
+    assert_eq!(format!("{:X}", FBin::NEG_INFINITY), "-INF");
+
+    // consistent with the %a format of C for f64
+    assert_eq!(format!("{:x}", FBin::try_from(0.1f64).unwrap()), "0x1.999999999999ap-4");
+    assert_eq!(format!("{:x}", FBin::try_from(f64::MAX).unwrap()), "0x1.fffffffffffffp+1023");
+    assert_eq!(format!("{:x}", FBin::try_from(f64::MIN_POSITIVE).unwrap()), "0x1p-1022");
+    assert_eq!(format!("{:x}", FBin::try_from(-5e-324f64).unwrap()), "-0x1p-1074");
+
+    // rounding with precision
+    assert_eq!(format!("{:.0x}", fbig!(0x3p-1)), "0x1p+0");
+    assert_eq!(format!("{:.2x}", fbig!(0x3p-1)), "0x1.80p+0");
+    assert_eq!(format!("{:.1x}", fbig!(0x123p-4)), "0x1.2p+4");
+    assert_eq!(format!("{:.1x}", fbig!(0x1ffp-4)), "0x1.fp+4"); // rounding toward zero
+    let a = FBig::<mode::HalfEven>::from_parts(IBig::from(0x1ff), -4);
+    assert_eq!(format!("{:.1x}", a), "0x1.0p+5");
+    assert_eq!(format!("{:.0x}", -a), "-0x1p+5");
+    let b = FBig::<mode::HalfEven>::from_parts(IBig::from(0x118), 0);
+    assert_eq!(format!("{:.1x}", b), "0x1.2p+8");
+    let b = FBig::<mode::HalfEven>::from_parts(IBig::from(0x128), 0);
+    assert_eq!(format!("{:.1x}", b), "0x1.2p+8");
+
+    // padding
+    assert_eq!(format!("{:12x}", fbig!(-0x3p-1)), "   -0x1.8p+0");
+    assert_eq!(format!("{:<12x}|", fbig!(0x3p-1)), "0x1.8p+0    |");
+    assert_eq!(format!("{:=^+12x}", fbig!(0x3p-1)), "=+0x1.8p+0==");
+    assert_eq!(format!("{:012x}", fbig!(-0x3p-1)), "-0x0001.8p+0");
+
+    // round trip
+    for x in [0.1f64, -1.5, 1e300, -5e-324, 123456.789] {
+        let a = FBin::try_from(x).unwrap();
+        assert_eq!(FBin::from_str(&format!("{:x}", a)).unwrap(), a);
+        assert_eq!(FBin::from_str(&format!("{:X}", a)).unwrap(), a);
+        assert_eq!(FBin::from_str(&format!("{:b}", a)).unwrap(), a);
+    }
+}
+
+#[test]
+fn test_format_native_sci() {
+    assert_eq!(format!("{:b}", fbig!(0x0)), "0b0");
+    assert_eq!(format!("{:b}", fbig!(0x1)), "1b0");
+    assert_eq!(format!("{:b}", fbig!(-0x3p-1)), "-1.1b0");
+    assert_eq!(format!("{:b}", fbig!(0x123p-4)), "1.00100011b4");
+    assert_eq!(format!("{:b}", fbig!(0x1p-100)), "1b-100");
+    assert_eq!(format!("{:b}", FBin::NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:.3b}", fbig!(0x123p-4)), "1.001b4");
+    assert_eq!(format!("{:.12b}", fbig!(0x123p-4)), "1.001000110000b4");
+    assert_eq!(format!("{:.1b}", fbig!(0x0)), "0.0b0");
+    assert_eq!(format!("{:>10b}", fbig!(-0x3p-1)), "    -1.1b0");
+
+    assert_eq!(format!("{:o}", FOct::ZERO), "0o0");
+    assert_eq!(format!("{:o}", FOct::from_str("-14.4").unwrap()), "-1.44o1");
+    assert_eq!(format!("{:o}", FOct::from_str("0.0007").unwrap()), "7o-4");
+    assert_eq!(format!("{:o}", FOct::from_str("7.77").unwrap()), "7.77o0");
+    assert_eq!(format!("{:.1o}", FOct::from_str("7.77").unwrap()), "7.7o0"); // rounding toward zero
+    let a = FBig::<mode::HalfAway, 8>::from_str("7.77").unwrap();
+    assert_eq!(format!("{:.1o}", a), "1.0o1");
+    assert_eq!(format!("{:+.3o}", a), "+7.770o0");
+
+    for text in ["-1.44o1", "7o-4", "1.234567o100"] {
+        let a = FOct::from_str(text).unwrap();
+        assert_eq!(FOct::from_str(&format!("{:o}", a)).unwrap(), a);
+    }
+}
+
 #[test]
 fn test_format_decimal() {
     assert_eq!(format!("{}", dbig!(0)), "0");
@@ -498,6 +692,58 @@ fn test_format_decimal() {
     assert_eq!(format!("{:=<+8.4}", dbig!(123e-2)), "+1.2300=");
     assert_eq!(format!("{:=^+8.4}", dbig!(123e-2)), "+1.2300=");
     assert_eq!(format!("{:=>+8.4}", dbig!(123e-2)), "=+1.2300");
+
+    // zero padding goes after the sign, and infinities respect the width
+    assert_eq!(format!("{:08.2}", dbig!(-15e-1)), "-0001.50");
+    assert_eq!(format!("{:+08}", dbig!(15e-1)), "+00001.5");
+    assert_eq!(format!("{:>6}", DBig::INFINITY), "   inf");
+    assert_eq!(format!("{:<6}|", DBig::NEG_INFINITY), "-inf  |");
+}
+
+#[test]
+fn test_format_exp() {
+    assert_eq!(format!("{:e}", DBig::ZERO), "0e0");
+    assert_eq!(format!("{:e}", dbig!(1234.5)), "1.2345e3");
+    assert_eq!(format!("{:e}", dbig!(-1e-7)), "-1e-7");
+    assert_eq!(format!("{:E}", dbig!(1234.5)), "1.2345E3");
+    assert_eq!(format!("{:.2e}", dbig!(1234.5)), "1.23e3");
+    assert_eq!(format!("{:.2e}", dbig!(9999)), "1.00e4");
+    assert_eq!(format!("{:.0e}", dbig!(-25)), "-3e1");
+    assert_eq!(format!("{:.3e}", dbig!(5)), "5.000e0");
+    assert_eq!(format!("{:+10.1e}", dbig!(1234.5)), "    +1.2e3");
+    assert_eq!(format!("{:010.1e}", dbig!(-1234.5)), "-00001.2e3");
+    assert_eq!(format!("{:e}", DBig::NEG_INFINITY), "-inf");
+    assert_eq!(format!("{:E}", DBig::INFINITY), "inf");
+
+    assert_eq!(format!("{:e}", fbig!(-0x3p-1)), "-1.1@0");
+    assert_eq!(format!("{:e}", FHex::from_str("ff.8").unwrap()), "f.f8@1");
+    assert_eq!(format!("{:E}", FHex::from_str("ff.8").unwrap()), "F.F8@1");
+
+    for text in ["1.2345e3", "-1e-7", "9.87654321e100"] {
+        let a = DBig::from_str(text).unwrap();
+        assert_eq!(DBig::from_str(&format!("{:e}", a)).unwrap(), a);
+    }
+    let a = FHex::from_str("-ab.cd").unwrap();
+    assert_eq!(FHex::from_str(&format!("{:e}", a)).unwrap(), a);
+}
+
+#[test]
+fn test_format_engineering() {
+    assert_eq!(DBig::ZERO.engineering().to_string(), "0e0");
+    assert_eq!(dbig!(1).engineering().to_string(), "1e0");
+    assert_eq!(dbig!(12345.6).engineering().to_string(), "12.3456e3");
+    assert_eq!(dbig!(-123456).engineering().to_string(), "-123.456e3");
+    assert_eq!(dbig!(1e5).engineering().to_string(), "100e3");
+    assert_eq!(dbig!(42e-5).engineering().to_string(), "420e-6");
+    assert_eq!(dbig!(1e-7).engineering().to_string(), "100e-9");
+    assert_eq!(format!("{:E}", dbig!(1.5e-3).engineering()), "1.5E-3");
+    assert_eq!(format!("{:.1}", dbig!(12345.6).engineering()), "12.3e3");
+    assert_eq!(format!("{:.2}", dbig!(1e5).engineering()), "100.00e3");
+    assert_eq!(format!("{:.1}", dbig!(999.96).engineering()), "1.0e3");
+    assert_eq!(format!("{:.1}", dbig!(99.96).engineering()), "100.0e0");
+    assert_eq!(format!("{:>10}", dbig!(-42e-5).engineering()), "   -420e-6");
+    assert_eq!(DBig::INFINITY.engineering().to_string(), "inf");
+    assert_eq!(format!("{:e}", fbig!(0x3p4).engineering()), "110@3");
 }
 
 #[test]
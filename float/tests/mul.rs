@@ -3,7 +3,10 @@ use core::{
     ops::{Mul, MulAssign},
 };
 use dashu_base::Approximation::*;
-use dashu_float::{round::Rounding::*, Context};
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context,
+};
 
 mod helper_macros;
 
@@ -141,3 +144,164 @@ fn test_mul_zero_precision() {
     test_mul(&a, &a, &dbig!(9801));
     test_mul(&a, &b, &dbig!(-98e2));
 }
+
+#[test]
+fn test_mul_long_operands() {
+    // the operands are much longer than the precision, and the exact products are
+    // very close to the rounding boundaries
+    let near_half = format!("2.5{}1", "0".repeat(200));
+    let near_one = format!("0.{}1", "9".repeat(200));
+    let a = dashu_float::DBig::from_str_native(&near_half).unwrap();
+    let b = dashu_float::DBig::from_str_native(&near_one).unwrap();
+
+    let context = Context::<mode::HalfEven>::new(1);
+    assert_eq!(context.mul(a.repr(), b.repr()), Inexact(dbig!(2).with_rounding(), NoOp));
+    assert_eq!(context.square(b.repr()), Inexact(dbig!(1).with_rounding(), AddOne));
+    let context = Context::<mode::HalfEven>::new(2);
+    assert_eq!(context.mul(a.repr(), a.repr()), Inexact(dbig!(6.3).with_rounding(), AddOne));
+    let context = Context::<mode::Down>::new(3);
+    assert_eq!(context.mul(a.repr(), b.repr()), Inexact(dbig!(2.49).with_rounding(), NoOp));
+    assert_eq!(
+        context.mul(a.repr(), dbig!(-2).repr()),
+        Inexact(dbig!(-5.01).with_rounding(), SubOne)
+    );
+
+    // the results are the same as rounding the exact products
+    for precision in [1, 5, 20, 100, 250] {
+        let context = Context::<mode::HalfAway>::new(precision);
+        let exact = Context::<mode::HalfAway>::new(0);
+        for (x, y) in [(&a, &b), (&b, &b), (&a, &a)] {
+            let product = exact.mul(x.repr(), y.repr()).value();
+            let expected = context.add(product.repr(), dashu_float::DBig::ZERO.repr());
+            assert_eq!(context.mul(x.repr(), y.repr()), expected);
+        }
+    }
+}
+
+#[test]
+fn test_mul_rounding_modes() {
+    use dashu_float::round::mode::{HalfDown, HalfOdd, ToOdd};
+
+    let (a, b) = (dbig!(1.5), dbig!(-1.5));
+    assert_eq!(
+        Context::<HalfDown>::new(2).mul(a.repr(), a.repr()),
+        Inexact(dbig!(2.2).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(2).mul(a.repr(), a.repr()),
+        Inexact(dbig!(2.3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(2).mul(a.repr(), b.repr()),
+        Inexact(dbig!(-2.3).with_rounding(), SubOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).mul(a.repr(), a.repr()),
+        Inexact(dbig!(2.3).with_rounding(), AddOne)
+    );
+
+    let (a, b) = (dbig!(1.3), dbig!(1.1));
+    assert_eq!(
+        Context::<HalfDown>::new(2).mul(a.repr(), a.repr()),
+        Inexact(dbig!(1.7).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).mul(a.repr(), a.repr()),
+        Inexact(dbig!(1.7).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).mul(a.repr(), b.repr()),
+        Inexact(dbig!(1.5).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).mul((-a).repr(), b.repr()),
+        Inexact(dbig!(-1.5).with_rounding(), SubOne)
+    );
+    let c = dbig!(1.4);
+    assert_eq!(
+        Context::<ToOdd>::new(2).mul(b.repr(), c.repr()),
+        Inexact(dbig!(1.5).with_rounding(), NoOp)
+    );
+}
+
+#[test]
+fn test_fma() {
+    let a = dbig!(111e-2);
+    let b = dbig!(-123e-2);
+    assert_eq!(&a * &a + &b, dbig!(0));
+    assert_eq!(a.mul_add(&a, &b), dbig!(21e-4));
+    assert_eq!(fbig!(0x3).mul_add(&fbig!(0x5), &fbig!(-0xf)), fbig!(0x0));
+    assert_eq!(fbig!(0x3).mul_add(&fbig!(0x0), &fbig!(-0xf)), fbig!(-0xf));
+
+    let context = Context::<mode::HalfAway>::new(2);
+    let (a, b) = (dbig!(1234e-3), dbig!(-15e-1));
+    assert_eq!(context.fma(a.repr(), a.repr(), b.repr()), Inexact(dbig!(23e-3), AddOne));
+
+    // the addend is much smaller than the product, but still affects the rounding
+    let (one, tiny) = (dbig!(1), dbig!(1e-1000));
+    assert_eq!(
+        Context::<mode::Up>::new(3).fma(one.repr(), one.repr(), tiny.repr()),
+        Inexact(dbig!(101e-2).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<mode::Down>::new(3).fma(one.repr(), one.repr(), (-&tiny).repr()),
+        Inexact(dbig!(999e-3).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<mode::HalfAway>::new(3).fma(tiny.repr(), tiny.repr(), one.repr()),
+        Inexact(dbig!(1), NoOp)
+    );
+}
+
+#[test]
+fn test_dot() {
+    let context = Context::<mode::HalfAway>::new(2);
+    let a = [dbig!(111e-2), dbig!(123e-2)];
+    let b = [dbig!(111e-2), dbig!(-1)];
+    assert_eq!(
+        context.dot(a.iter().map(|v| v.repr()), b.iter().map(|v| v.repr())),
+        Exact(dbig!(21e-4))
+    );
+
+    let a = [dbig!(1e10), dbig!(1234e-3), dbig!(1e10)];
+    let b = [dbig!(1), dbig!(1), dbig!(-1)];
+    assert_eq!(
+        context.dot(a.iter().map(|v| v.repr()), b.iter().map(|v| v.repr())),
+        Inexact(dbig!(12e-1), NoOp)
+    );
+
+    let context = Context::<mode::Zero>::new(4);
+    let a = [fbig!(0x1p-200), fbig!(0x3)];
+    let b = [fbig!(-0x1p-200), fbig!(0x5)];
+    assert_eq!(
+        context.dot(a.iter().map(|v| v.repr()), b.iter().map(|v| v.repr())),
+        Inexact(fbig!(0x7p1), NoOp)
+    );
+    let empty: [dashu_float::FBig; 0] = [];
+    assert_eq!(
+        context.dot(empty.iter().map(|v| v.repr()), empty.iter().map(|v| v.repr())),
+        Exact(dashu_float::FBig::<mode::Zero>::ZERO)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_dot_different_lengths() {
+    let context = Context::<mode::HalfAway>::new(2);
+    let a = [dbig!(1), dbig!(2)];
+    let b = [dbig!(1)];
+    let _ = context.dot(a.iter().map(|v| v.repr()), b.iter().map(|v| v.repr()));
+}
+
+#[test]
+fn test_product() {
+    let empty: [dashu_float::DBig; 0] = [];
+    assert_eq!(empty.iter().product::<dashu_float::DBig>(), dbig!(1));
+
+    let factors = [dbig!(2), dbig!(-15e-1), dbig!(4)];
+    assert_eq!(factors.iter().product::<dashu_float::DBig>(), dbig!(-12));
+    assert_eq!(factors.into_iter().product::<dashu_float::DBig>(), dbig!(-12));
+
+    let factors = [fbig!(0x3), fbig!(0x1p-4), fbig!(0x5)];
+    assert_eq!(factors.iter().product::<dashu_float::FBig>(), fbig!(0xfp-4));
+}
@@ -0,0 +1,81 @@
+use dashu_float::{round::mode, Context, DBig, FBig};
+
+mod helper_macros;
+
+type FEven = FBig<mode::HalfEven>;
+
+#[test]
+fn test_next_up_down() {
+    assert_eq!(dbig!(1.23).next_up(), dbig!(1.24));
+    assert_eq!(dbig!(1.23).next_down(), dbig!(1.22));
+    assert_eq!(dbig!(-1.23).next_up(), dbig!(-1.22));
+    assert_eq!(dbig!(-1.23).next_down(), dbig!(-1.24));
+    assert_eq!(dbig!(9.99).next_up(), dbig!(10.0));
+    assert_eq!(dbig!(-9.99).next_down(), dbig!(-10.0));
+    assert_eq!(dbig!(1.00).next_down(), dbig!(0.999));
+    assert_eq!(dbig!(-1.00).next_up(), dbig!(-0.999));
+    assert_eq!(dbig!(1.00).next_up(), dbig!(1.01));
+    assert_eq!(dbig!(1e10).with_precision(3).value().next_up(), dbig!(1.01e10));
+    assert_eq!(DBig::INFINITY.next_up(), DBig::INFINITY);
+    assert_eq!(DBig::NEG_INFINITY.next_down(), DBig::NEG_INFINITY);
+
+    assert_eq!(fbig!(0x1).with_precision(4).value().next_up(), fbig!(0x9p-3));
+    assert_eq!(fbig!(0x1).with_precision(4).value().next_down(), fbig!(0xfp-4));
+    assert_eq!(fbig!(-0xf).next_down(), fbig!(-0x10));
+
+    // the precision is preserved
+    let a = dbig!(1.23).next_up();
+    assert_eq!(a.precision(), 3);
+    assert_eq!(a.next_down(), dbig!(1.23));
+}
+
+#[test]
+fn test_next_with_range() {
+    let context = Context::<mode::HalfEven>::new(53).with_exponent_range(-1022, 1023);
+    let with_range = |f: f64| FEven::from_repr(FEven::try_from(f).unwrap().repr().clone(), context);
+    let to_fbig = |f: f64| FEven::try_from(f).unwrap();
+
+    let cases = [
+        1.0,
+        -1.0,
+        0.1,
+        -3.5e-300,
+        f64::MIN_POSITIVE,
+        -f64::MIN_POSITIVE,
+        5e-324,
+        1e-310,
+        f64::MAX / 2.0,
+    ];
+    for f in cases {
+        assert_eq!(with_range(f).next_up(), to_fbig(f.next_up()), "{}", f);
+        assert_eq!(with_range(f).next_down(), to_fbig(f.next_down()), "{}", f);
+    }
+    assert_eq!(with_range(0.0).next_up(), to_fbig(5e-324));
+    assert_eq!(with_range(0.0).next_down(), to_fbig(-5e-324));
+    assert_eq!(with_range(5e-324).next_down(), FEven::ZERO);
+    assert_eq!(with_range(f64::MAX).next_up(), FEven::INFINITY);
+    assert_eq!(with_range(f64::MIN).next_down(), FEven::NEG_INFINITY);
+    assert_eq!(with_range(f64::MAX).next_down(), to_fbig(f64::MAX.next_down()));
+}
+
+#[test]
+fn test_next_toward() {
+    let a = dbig!(1.5);
+    assert_eq!(a.next_toward(&dbig!(2)), dbig!(1.6));
+    assert_eq!(a.next_toward(&dbig!(1.51)), dbig!(1.6));
+    assert_eq!(a.next_toward(&DBig::NEG_INFINITY), dbig!(1.4));
+    assert_eq!(a.next_toward(&dbig!(1.50)), a);
+    assert_eq!(DBig::INFINITY.next_toward(&a), DBig::INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn test_next_of_zero() {
+    let _ = DBig::ZERO.with_precision(3).value().next_up();
+}
+
+#[test]
+#[should_panic]
+fn test_next_unlimited() {
+    let _ = dbig!(1).with_precision(0).value().next_up();
+}
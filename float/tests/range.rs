@@ -0,0 +1,267 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig, FBig, RangeStatus,
+};
+
+mod helper_macros;
+
+#[test]
+fn test_exponent_range() {
+    let context = Context::<mode::HalfEven>::new(4);
+    assert_eq!(context.emin(), None);
+    assert_eq!(context.emax(), None);
+
+    let context = context.with_exponent_range(-10, 20);
+    assert_eq!(context.emin(), Some(-10));
+    assert_eq!(context.emax(), Some(20));
+
+    let wider = Context::<mode::HalfEven>::new(2).with_exponent_range(-20, 10);
+    let ctxt = Context::max(context, wider);
+    assert_eq!((ctxt.precision(), ctxt.emin(), ctxt.emax()), (4, Some(-20), Some(20)));
+    let ctxt = Context::max(context, Context::new(2));
+    assert_eq!((ctxt.emin(), ctxt.emax()), (None, None));
+
+    // the unbounded range is encoded in the bounds rather than an extra discriminant
+    assert_eq!(
+        core::mem::size_of::<Context<mode::HalfEven>>(),
+        3 * core::mem::size_of::<usize>()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_exponent_range_with_unlimited_precision() {
+    let _ = Context::<mode::HalfEven>::new(0).with_exponent_range(-10, 10);
+}
+
+#[test]
+#[should_panic]
+fn test_exponent_range_with_reserved_emin() {
+    let _ = Context::<mode::HalfEven>::new(4).with_exponent_range(isize::MIN, 10);
+}
+
+#[test]
+fn test_subnormal_binary() {
+    // emin = 0, the smallest subnormal is 2^-3
+    let context = Context::<mode::HalfEven>::new(4).with_exponent_range(0, 4);
+
+    // exact subnormals
+    assert_eq!(
+        context.add(fbig!(0x3p-3).repr(), fbig!(0x1p-3).repr()),
+        Exact(fbig!(0x1p-1).with_rounding())
+    );
+    assert_eq!(
+        context.mul(fbig!(0x3p-2).repr(), fbig!(0x1p-1).repr()),
+        Exact(fbig!(0x3p-3).with_rounding())
+    );
+
+    // inexact subnormals
+    assert_eq!(
+        context.mul(fbig!(0x3p-2).repr(), fbig!(0x1p-2).repr()),
+        Inexact(fbig!(0x1p-2).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.mul(fbig!(0x5p-3).repr(), fbig!(0x1p-2).repr()),
+        Inexact(fbig!(0x1p-3).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        context.mul(fbig!(0x1p-3).repr(), fbig!(0x1p-1).repr()),
+        Inexact(fbig!(0x0).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        context.mul(fbig!(-0x3p-3).repr(), fbig!(0x1p-1).repr()),
+        Inexact(fbig!(-0x1p-2).with_rounding(), SubOne)
+    );
+
+    // double rounding is avoided: 0x3dp-6 / 3 = 0x5.1555p-4 is rounded to 0x5p-4 with 4 digits,
+    // but it should be rounded to 0x3p-3 in the subnormal range
+    assert_eq!(
+        context.div(fbig!(0x3d).repr(), fbig!(0x3p6).repr()),
+        Inexact(fbig!(0x3p-3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.div(fbig!(0x3b).repr(), fbig!(0x3p6).repr()),
+        Inexact(fbig!(0x1p-2).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        context.div(fbig!(0x15).repr(), fbig!(0x1p6).repr()),
+        Inexact(fbig!(0x3p-3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        context.sqrt(fbig!(0x1p-5).repr()),
+        Inexact(fbig!(0x1p-3).with_rounding(), NoOp)
+    );
+
+    // the results rounded up to the normal range
+    assert_eq!(
+        context.mul(fbig!(0x7p-3).repr(), fbig!(0x1).repr()),
+        Exact(fbig!(0x7p-3).with_rounding())
+    );
+    assert_eq!(
+        context.mul(fbig!(0xfp-4).repr(), fbig!(0x1).repr()),
+        Inexact(fbig!(0x1).with_rounding(), AddOne)
+    );
+}
+
+#[test]
+fn test_subnormal_decimal() {
+    // the smallest subnormal is 1e-7
+    let context = Context::<mode::HalfAway>::new(3).with_exponent_range(-5, 5);
+    assert_eq!(
+        context.mul(dbig!(123e-8).repr(), dbig!(5e-1).repr()),
+        Inexact(dbig!(6e-7), NoOp)
+    );
+    assert_eq!(
+        context.mul(dbig!(123e-8).repr(), dbig!(1e-1).repr()),
+        Inexact(dbig!(1e-7), NoOp)
+    );
+    assert_eq!(context.mul(dbig!(-5e-8).repr(), dbig!(1).repr()), Inexact(dbig!(-1e-7), SubOne));
+    assert_eq!(context.sub(dbig!(123e-7).repr(), dbig!(1e-5).repr()), Exact(dbig!(23e-7)));
+    assert_eq!(context.div(dbig!(1e-5).repr(), dbig!(3).repr()), Inexact(dbig!(33e-7), NoOp));
+    assert_eq!(
+        context.sum([dbig!(1e-8).repr(), dbig!(4e-8).repr()]),
+        Inexact(dbig!(1e-7), AddOne)
+    );
+}
+
+#[test]
+fn test_subnormal_odd_base() {
+    // the smallest subnormal is 3^-2, and 1/2 = 4.5 * 3^-2 is a tie in the subnormal range,
+    // which should be rounded to 4 * 3^-2 instead of 14 * 3^-3 with 3 digits then 5 * 3^-2
+    type Ternary = FBig<mode::HalfEven, 3>;
+    let context = Context::<mode::HalfEven>::new(3).with_exponent_range(0, 4);
+    let half = Ternary::from_parts(4.into(), -2);
+    assert_eq!(
+        context.div(Ternary::ONE.repr(), Ternary::from(2).repr()),
+        Inexact(half.clone(), NoOp)
+    );
+    assert_eq!(
+        context.with_range_status(|ctx| ctx.div(Ternary::ONE.repr(), Ternary::from(2).repr())),
+        (Inexact(half, NoOp), RangeStatus::Underflow)
+    );
+}
+
+#[test]
+fn test_overflow() {
+    let max = fbig!(0xfp1);
+    let big = fbig!(0x1p5);
+    let cases = [
+        // value, result of Zero, Away, Up, Down, HalfEven
+        (
+            big.clone(),
+            [
+                max.clone(),
+                FBig::INFINITY,
+                FBig::INFINITY,
+                max.clone(),
+                FBig::INFINITY,
+            ],
+        ),
+        (
+            -big.clone(),
+            [
+                -max.clone(),
+                FBig::NEG_INFINITY,
+                -max.clone(),
+                FBig::NEG_INFINITY,
+                FBig::NEG_INFINITY,
+            ],
+        ),
+    ];
+    for (v, [zero, away, up, down, half_even]) in cases {
+        let x = v.repr();
+        let one = fbig!(0x1);
+        assert_eq!(
+            Context::<mode::Zero>::new(4)
+                .with_exponent_range(-4, 4)
+                .mul(x, one.repr()),
+            Inexact(zero.with_rounding(), NoOp)
+        );
+        assert_eq!(
+            *Context::<mode::Away>::new(4)
+                .with_exponent_range(-4, 4)
+                .mul(x, one.repr())
+                .value()
+                .repr(),
+            *away.repr()
+        );
+        assert_eq!(
+            *Context::<mode::Up>::new(4)
+                .with_exponent_range(-4, 4)
+                .mul(x, one.repr())
+                .value()
+                .repr(),
+            *up.repr()
+        );
+        assert_eq!(
+            *Context::<mode::Down>::new(4)
+                .with_exponent_range(-4, 4)
+                .mul(x, one.repr())
+                .value()
+                .repr(),
+            *down.repr()
+        );
+        assert_eq!(
+            *Context::<mode::HalfEven>::new(4)
+                .with_exponent_range(-4, 4)
+                .mul(x, one.repr())
+                .value()
+                .repr(),
+            *half_even.repr()
+        );
+    }
+
+    // the largest finite number doesn't overflow
+    let context = Context::<mode::HalfEven>::new(4).with_exponent_range(-4, 4);
+    assert_eq!(
+        context.add(fbig!(0xfp1).repr(), fbig!(0x1p-4).repr()),
+        Inexact(fbig!(0xfp1).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        context.add(fbig!(0xfp1).repr(), fbig!(0x1p0).repr()),
+        Inexact(FBig::INFINITY, AddOne)
+    );
+
+    let context = Context::<mode::HalfAway>::new(10).with_exponent_range(-99, 99);
+    assert_eq!(context.exp(dbig!(1000).repr()), Inexact(DBig::INFINITY, AddOne));
+    assert_eq!(context.exp(dbig!(-1000).repr()), Inexact(DBig::ZERO, NoOp));
+    assert_eq!(context.powi(dbig!(10).repr(), 100.into()), Inexact(DBig::INFINITY, AddOne));
+    assert_eq!(context.powi(dbig!(10).repr(), 99.into()), Exact(dbig!(1e99)));
+}
+
+#[test]
+fn test_fbig_with_range() {
+    let context = Context::<mode::HalfAway>::new(3).with_exponent_range(-5, 5);
+    let a = DBig::from_repr(dbig!(5e5).repr().clone(), context);
+    assert_eq!(&a * &a, DBig::INFINITY);
+    assert_eq!(&a + &a, DBig::INFINITY);
+    assert_eq!(&a + dbig!(5e5), dbig!(1e6)); // the exponent is unbounded if any operand is unbounded
+
+    let b = DBig::from_repr(dbig!(3e-4).repr().clone(), context);
+    assert_eq!((&b * &b).context().emin(), Some(-5));
+    assert_eq!(&b * &b, dbig!(1e-7));
+    assert_eq!(b.with_rounding::<mode::Zero>().context().emax(), Some(5));
+}
+
+#[test]
+fn test_range_status() {
+    let context = Context::<mode::HalfEven>::new(4).with_exponent_range(0, 4);
+    let status = |a: FBig, b: FBig| {
+        context
+            .with_range_status(|ctx| ctx.mul(a.repr(), b.repr()))
+            .1
+    };
+    assert_eq!(status(fbig!(0x3), fbig!(0x5)), RangeStatus::Normal);
+    assert_eq!(status(fbig!(0x0), fbig!(0x5)), RangeStatus::Normal);
+    assert_eq!(status(fbig!(0x3p-2), fbig!(0x1p-1)), RangeStatus::Subnormal);
+    assert_eq!(status(fbig!(0x3p-2), fbig!(0x1p-2)), RangeStatus::Underflow);
+    assert_eq!(status(fbig!(0x1p-3), fbig!(0x1p-3)), RangeStatus::Underflow);
+    assert_eq!(status(fbig!(0x1p4), fbig!(0x2)), RangeStatus::Overflow);
+    assert_eq!(status(fbig!(0x1p4), fbig!(0x1)), RangeStatus::Normal);
+
+    let result =
+        context.with_range_status(|ctx| ctx.mul(fbig!(0x1p-3).repr(), fbig!(0x1p-3).repr()));
+    assert_eq!(result.0, Inexact(FBig::ZERO, NoOp));
+    assert_eq!(result.0.value().context().emin(), Some(0));
+}
@@ -0,0 +1,194 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    Context, DBig,
+};
+
+mod helper_macros;
+
+#[test]
+fn test_sqrt_binary() {
+    let exact_cases = [
+        (fbig!(0x0), fbig!(0x0)),
+        (fbig!(0x1), fbig!(0x1)),
+        (fbig!(0x4), fbig!(0x2)),
+        (fbig!(0x9p-4), fbig!(0x3p-2)),
+        (fbig!(0x1p-1000), fbig!(0x1p-500)),
+    ];
+    for (x, root) in &exact_cases {
+        assert_eq!(x.sqrt(), *root);
+        if let Exact(v) = x.context().sqrt(x.repr()) {
+            assert_eq!(v, *root);
+        } else {
+            panic!("the result should be exact!")
+        }
+    }
+
+    let x = fbig!(0x2).with_precision(53).value();
+    assert_eq!(x.sqrt(), fbig!(0x16a09e667f3bccp-52));
+    assert_eq!(
+        Context::<mode::HalfEven>::new(53).sqrt(x.repr()),
+        Inexact(fbig!(0x16a09e667f3bcdp-52).with_rounding(), AddOne)
+    );
+}
+
+#[test]
+fn test_sqrt_decimal() {
+    let exact_cases = [
+        (dbig!(0), dbig!(0)),
+        (dbig!(1), dbig!(1)),
+        (dbig!(25e-2), dbig!(5e-1)),
+        (dbig!(1e-1000), dbig!(1e-500)),
+        (dbig!(15241578750190521), dbig!(123456789)),
+    ];
+    for (x, root) in &exact_cases {
+        assert_eq!(x.sqrt(), *root);
+        if let Exact(v) = x.context().sqrt(x.repr()) {
+            assert_eq!(v, *root);
+        } else {
+            panic!("the result should be exact!")
+        }
+    }
+
+    let inexact_cases = [
+        (dbig!(2), 1, dbig!(1), NoOp),
+        (dbig!(2), 10, dbig!(1414213562e-9), NoOp),
+        (dbig!(99), 2, dbig!(99e-1), NoOp),
+        (dbig!(10), 20, dbig!(31622776601683793320e-19), AddOne),
+        (dbig!(15241578750190522), 9, dbig!(123456789), NoOp),
+        (dbig!(15241578750190522), 17, dbig!(12345678900000000e-8), NoOp),
+    ];
+    for (x, prec, root, rnd) in &inexact_cases {
+        let context = Context::<mode::HalfAway>::new(*prec);
+        if let Inexact(v, e) = context.sqrt(x.repr()) {
+            assert_eq!(v, *root);
+            assert_eq!(e, *rnd);
+        } else {
+            panic!("the result should be inexact!")
+        }
+    }
+
+    // directed rounding near an exact root
+    let x = dbig!(15241578750190522);
+    assert_eq!(
+        Context::<mode::Up>::new(9).sqrt(x.repr()),
+        Inexact(dbig!(123456790).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<mode::Down>::new(9).sqrt(x.repr()),
+        Inexact(dbig!(123456789).with_rounding(), NoOp)
+    );
+}
+
+#[test]
+fn test_sqrt_rounding_modes() {
+    use dashu_float::round::mode::{HalfDown, HalfOdd, ToOdd};
+
+    // ties are only possible when the root is exact
+    let x = dbig!(6.25);
+    assert_eq!(
+        Context::<HalfDown>::new(1).sqrt(x.repr()),
+        Inexact(dbig!(2).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(1).sqrt(x.repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(1).sqrt(x.repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+
+    // the roots are just above or below a half
+    let (a, b) = (dbig!(6.26), dbig!(6.24));
+    assert_eq!(
+        Context::<HalfDown>::new(1).sqrt(a.repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<HalfOdd>::new(1).sqrt(b.repr()),
+        Inexact(dbig!(2).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(1).sqrt(b.repr()),
+        Inexact(dbig!(3).with_rounding(), AddOne)
+    );
+
+    let x = dbig!(2);
+    assert_eq!(
+        Context::<HalfDown>::new(2).sqrt(x.repr()),
+        Inexact(dbig!(1.4).with_rounding(), NoOp)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(2).sqrt(x.repr()),
+        Inexact(dbig!(1.5).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<ToOdd>::new(3).sqrt(x.repr()),
+        Inexact(dbig!(1.41).with_rounding(), NoOp)
+    );
+}
+
+#[test]
+#[cfg(all(feature = "rand", feature = "std"))]
+fn test_sqrt_stochastic() {
+    // sqrt(2) = 1.414.. is rounded to 1.5 with probability 0.1421..
+    let context = Context::<mode::Stochastic>::new(2);
+    let x = dbig!(2);
+    let count = (0..4000)
+        .filter(|_| match context.sqrt(x.repr()) {
+            Inexact(v, NoOp) => {
+                assert_eq!(v.repr(), dbig!(1.4).repr());
+                false
+            }
+            Inexact(v, AddOne) => {
+                assert_eq!(v.repr(), dbig!(1.5).repr());
+                true
+            }
+            _ => panic!("the result should be inexact!"),
+        })
+        .count();
+    assert!((400..750).contains(&count));
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_negative() {
+    let _ = dbig!(-1).sqrt();
+}
+
+#[test]
+#[should_panic]
+fn test_sqrt_unlimited_precision() {
+    let _ = dbig!(2).with_precision(0).value().sqrt();
+}
+
+#[test]
+fn test_hypot() {
+    assert_eq!(fbig!(0x3).hypot(&fbig!(-0x4)), fbig!(0x5));
+    assert_eq!(dbig!(0).hypot(&dbig!(0)), dbig!(0));
+    assert_eq!(dbig!(3).hypot(&dbig!(4)), dbig!(5));
+    assert_eq!(dbig!(3e100000).hypot(&dbig!(4e100000)), dbig!(5e100000));
+    assert_eq!(dbig!(-3e-100000).hypot(&dbig!(4e-100000)), dbig!(5e-100000));
+
+    let context = Context::<mode::HalfAway>::new(10);
+    assert_eq!(
+        context.hypot(DBig::ONE.repr(), DBig::ONE.repr()),
+        Inexact(dbig!(1414213562e-9), NoOp)
+    );
+
+    // the tiny operand still affects the rounding
+    let (a, b) = (dbig!(1), dbig!(1e-1000));
+    assert_eq!(
+        Context::<mode::HalfAway>::new(3).hypot(a.repr(), b.repr()),
+        Inexact(dbig!(100e-2), NoOp)
+    );
+    assert_eq!(
+        Context::<mode::Up>::new(3).hypot(a.repr(), b.repr()),
+        Inexact(dbig!(101e-2).with_rounding(), AddOne)
+    );
+    assert_eq!(
+        Context::<mode::Up>::new(3).hypot(b.repr(), a.repr()),
+        Inexact(dbig!(101e-2).with_rounding(), AddOne)
+    );
+}
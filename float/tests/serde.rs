@@ -0,0 +1,116 @@
+use dashu_float::{round::mode::HalfAway, DBig, FBig, Repr};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token,
+};
+
+mod helper_macros;
+
+type FBin = FBig<HalfAway, 2>;
+
+#[test]
+fn test_fbig_serde_readable() {
+    assert_tokens(&DBig::ZERO.readable(), &[Token::Str("0e0")]);
+    assert_tokens(&DBig::from_str_native("1.500e0").unwrap().readable(), &[Token::Str("1.500e0")]);
+    assert_tokens(
+        &DBig::from_str_native("-12.34e5").unwrap().readable(),
+        &[Token::Str("-1.234e6")],
+    );
+    assert_tokens(&DBig::INFINITY.readable(), &[Token::Str("inf")]);
+    assert_tokens(&DBig::NEG_INFINITY.readable(), &[Token::Str("-inf")]);
+    assert_tokens(
+        &FBin::from_str_native("-1.01@-4").unwrap().readable(),
+        &[Token::Str("-1.01@-4")],
+    );
+
+    // the precision is restored from the number of digits
+    let a = DBig::from_str_native("1.500e0").unwrap();
+    let b: DBig = serde_json::from_str(&serde_json::to_string(&a).unwrap()).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(b.precision(), 4);
+
+    let a = FBin::from_str_native("0.00011")
+        .unwrap()
+        .with_precision(20)
+        .value();
+    let b: FBin = serde_json::from_str(&serde_json::to_string(&a).unwrap()).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(b.precision(), 20);
+
+    assert_de_tokens(&DBig::INFINITY.readable(), &[Token::Str("+inf")]);
+    assert_de_tokens_error::<Readable<DBig>>(&[Token::Str("1.2.3")], "invalid digit");
+}
+
+#[test]
+fn test_fbig_serde_compact() {
+    let a = DBig::from_str_native("-1.50e2").unwrap();
+    assert_eq!(a.precision(), 3);
+    assert_tokens(
+        &a.compact(),
+        &[
+            Token::Tuple { len: 3 },
+            Token::Tuple { len: 2 },
+            Token::Bool(true),
+            Token::Seq { len: Some(1) },
+            Token::U64(15),
+            Token::SeqEnd,
+            Token::TupleEnd,
+            Token::I64(1),
+            Token::U64(3),
+            Token::TupleEnd,
+        ],
+    );
+
+    assert_tokens(
+        &FBin::INFINITY.compact(),
+        &[
+            Token::Tuple { len: 3 },
+            Token::Tuple { len: 2 },
+            Token::Bool(false),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::TupleEnd,
+            Token::I64(1),
+            Token::U64(0),
+            Token::TupleEnd,
+        ],
+    );
+
+    // the precision must be enough to hold the significand
+    assert_de_tokens_error::<Compact<DBig>>(
+        &[
+            Token::Tuple { len: 3 },
+            Token::Tuple { len: 2 },
+            Token::Bool(false),
+            Token::Seq { len: Some(1) },
+            Token::U64(123),
+            Token::SeqEnd,
+            Token::TupleEnd,
+            Token::I64(0),
+            Token::U64(2),
+            Token::TupleEnd,
+        ],
+        "invalid value: integer `2`, expected a precision not less than the number of digits in the significand",
+    );
+}
+
+#[test]
+fn test_repr_serde() {
+    let repr = Repr::<10>::new(ibig!(-1234), -2);
+    assert_tokens(&repr.clone().readable(), &[Token::Str("-1.234e1")]);
+    assert_tokens(
+        &repr.compact(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::Tuple { len: 2 },
+            Token::Bool(true),
+            Token::Seq { len: Some(1) },
+            Token::U64(1234),
+            Token::SeqEnd,
+            Token::TupleEnd,
+            Token::I64(-2),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(&Repr::<2>::neg_infinity().readable(), &[Token::Str("-inf")]);
+    assert_tokens(&Repr::<2>::zero().readable(), &[Token::Str("0@0")]);
+}
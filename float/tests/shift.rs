@@ -1,4 +1,4 @@
-use dashu_float::DBig;
+use dashu_float::{round::mode, Context, DBig, FBig};
 
 mod helper_macros;
 
@@ -24,3 +24,50 @@ fn test_shift() {
 fn test_shift_inf() {
     let _ = DBig::INFINITY >> 1;
 }
+
+#[test]
+fn test_shift_assign() {
+    let mut a = dbig!(1.5);
+    a >>= 2;
+    assert_eq!(a, dbig!(0.015));
+    a <<= 3;
+    assert_eq!(a, dbig!(15));
+    let mut b = dbig!(0);
+    b >>= 2;
+    assert_eq!(b, DBig::ZERO);
+}
+
+#[test]
+fn test_ldexp_frexp() {
+    assert_eq!(dbig!(1.234).ldexp(3), dbig!(1234));
+    assert_eq!(dbig!(-1.234).ldexp(-3), dbig!(-0.001234));
+    assert_eq!(DBig::ZERO.ldexp(3), DBig::ZERO);
+    assert_eq!(DBig::NEG_INFINITY.ldexp(3), DBig::NEG_INFINITY);
+    assert_eq!(fbig!(0x3).ldexp(-4), fbig!(0x3p-4));
+
+    // rounded when the exponent range is limited
+    let context = Context::<mode::HalfEven>::new(4).with_exponent_range(-4, 4);
+    let a = FBig::<mode::HalfEven, 10>::from_repr(dbig!(1.235).repr().clone(), context);
+    assert_eq!(a.ldexp(-5), dbig!(1.24e-5)); // subnormal
+    assert_eq!(a.ldexp(-8), DBig::ZERO);
+    assert_eq!(a.ldexp(5), DBig::INFINITY);
+    assert_eq!(a.ldexp(4), dbig!(12350));
+
+    let (signif, exp) = dbig!(-12.34).frexp();
+    assert_eq!((signif.clone(), exp), (dbig!(-0.1234), 2));
+    assert_eq!(signif.precision(), 4);
+    assert_eq!(dbig!(0.00056).frexp(), (dbig!(0.56), -3));
+    assert_eq!(dbig!(1).frexp(), (dbig!(0.1), 1));
+    assert_eq!(DBig::ZERO.frexp(), (DBig::ZERO, 0));
+    assert_eq!(DBig::INFINITY.frexp(), (DBig::INFINITY, 0));
+    assert_eq!(fbig!(0x1).frexp(), (fbig!(0x1p-1), 1));
+    assert_eq!(fbig!(-0x5p-7).frexp(), (fbig!(-0x5p-3), -4));
+
+    for x in [dbig!(1.5), dbig!(-0.0025), dbig!(123e45)] {
+        let (signif, exp) = x.frexp();
+        let signif = signif.copysign(&DBig::ONE);
+        assert!(signif >= dbig!(0.1) && signif < dbig!(1));
+        let signif = signif.copysign(&x);
+        assert_eq!(signif.ldexp(exp), x);
+    }
+}
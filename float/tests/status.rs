@@ -0,0 +1,146 @@
+use dashu_base::Approximation::*;
+use dashu_float::{
+    round::{mode, Rounding::*},
+    status::{Exception, Flags, StatusContext},
+    Context, DBig, FBig,
+};
+
+mod helper_macros;
+
+type FBigE = FBig<mode::HalfEven>;
+
+#[test]
+fn test_flags() {
+    let flags = Flags::INEXACT | Flags::OVERFLOW;
+    assert!(flags.contains(Flags::INEXACT));
+    assert!(flags.contains(Exception::Overflow.into()));
+    assert!(!flags.contains(Flags::INEXACT | Flags::UNDERFLOW));
+    assert!(!flags.is_empty());
+    assert!(Flags::EMPTY.is_empty());
+    assert_eq!(flags & Flags::OVERFLOW, Flags::OVERFLOW);
+    assert_eq!(flags.difference(Flags::OVERFLOW), Flags::INEXACT);
+    assert!(Flags::ALL.contains(flags));
+    assert_eq!(Flags::ALL.iter().count(), 8);
+    assert_eq!(flags.iter().collect::<Vec<_>>(), [Exception::Overflow, Exception::Inexact]);
+    assert_eq!(format!("{:?}", flags), "{Overflow, Inexact}");
+}
+
+#[test]
+fn test_sticky_flags() {
+    let mut ctxt = StatusContext::new(Context::<mode::HalfAway>::new(3));
+    assert_eq!(ctxt.add(dbig!(1).repr(), dbig!(2).repr()), Ok(Exact(dbig!(3))));
+    assert!(ctxt.flags().is_empty());
+
+    assert_eq!(ctxt.div(dbig!(2).repr(), dbig!(3).repr()), Ok(Inexact(dbig!(0.667), AddOne)));
+    assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+
+    // the flags are sticky
+    assert_eq!(ctxt.mul(dbig!(2).repr(), dbig!(3).repr()), Ok(Exact(dbig!(6))));
+    assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+
+    ctxt.clear_flags();
+    assert!(ctxt.flags().is_empty());
+    assert_eq!(ctxt.sqrt(dbig!(4).repr()), Ok(Exact(dbig!(2))));
+    assert_eq!(ctxt.hypot(dbig!(3).repr(), dbig!(4).repr()), Ok(Exact(dbig!(5))));
+    assert_eq!(ctxt.square(dbig!(12).repr()), Ok(Exact(dbig!(144))));
+    assert_eq!(
+        ctxt.fma(dbig!(2).repr(), dbig!(3).repr(), dbig!(4).repr()),
+        Ok(Exact(dbig!(10)))
+    );
+    assert!(ctxt.flags().is_empty());
+    assert_eq!(ctxt.exp(dbig!(1).repr()), Ok(Inexact(dbig!(2.72), AddOne)));
+    assert_eq!(ctxt.flags(), Flags::INEXACT | Flags::ROUNDED);
+}
+
+#[test]
+fn test_division_by_zero() {
+    let mut ctxt = StatusContext::new(Context::<mode::HalfAway>::new(3));
+    assert_eq!(ctxt.div(dbig!(1).repr(), dbig!(0).repr()), Err(Exception::DivisionByZero));
+    assert_eq!(ctxt.ln(dbig!(0).repr()), Err(Exception::DivisionByZero));
+    assert_eq!(ctxt.ln_1p(dbig!(-1).repr()), Err(Exception::DivisionByZero));
+    assert_eq!(ctxt.powi(dbig!(0).repr(), (-1).into()), Err(Exception::DivisionByZero));
+    assert_eq!(ctxt.flags(), Flags::DIVISION_BY_ZERO);
+
+    // untrapped division by zero results in infinities
+    let mut ctxt = ctxt.with_traps(Flags::INVALID_OPERATION);
+    assert_eq!(ctxt.div(dbig!(1).repr(), dbig!(0).repr()), Ok(Exact(DBig::INFINITY)));
+    assert_eq!(ctxt.div(dbig!(-1).repr(), dbig!(0).repr()), Ok(Exact(DBig::NEG_INFINITY)));
+    assert_eq!(ctxt.ln(dbig!(0).repr()), Ok(Exact(DBig::NEG_INFINITY)));
+    assert_eq!(ctxt.ln_1p(dbig!(-1).repr()), Ok(Exact(DBig::NEG_INFINITY)));
+    assert_eq!(ctxt.powi(dbig!(0).repr(), (-2).into()), Ok(Exact(DBig::INFINITY)));
+}
+
+#[test]
+fn test_invalid_operation() {
+    // invalid operations are always trapped
+    let mut ctxt = StatusContext::new(Context::<mode::HalfAway>::new(3)).with_traps(Flags::EMPTY);
+    assert_eq!(ctxt.traps(), Flags::INVALID_OPERATION);
+    let inf = DBig::INFINITY;
+    assert_eq!(ctxt.add(inf.repr(), dbig!(1).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.sub(dbig!(1).repr(), inf.repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.mul(inf.repr(), dbig!(1).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.div(dbig!(0).repr(), dbig!(0).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.sqrt(dbig!(-1).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.ln(dbig!(-1).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.ln_1p(dbig!(-2).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.flags(), Flags::INVALID_OPERATION);
+
+    // operations requiring limited precision
+    let mut ctxt = StatusContext::new(Context::<mode::HalfAway>::new(0));
+    assert_eq!(ctxt.add(dbig!(1).repr(), dbig!(2e-10).repr()), Ok(Exact(dbig!(1.0000000002))));
+    assert_eq!(ctxt.div(dbig!(1).repr(), dbig!(3).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.sqrt(dbig!(2).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.exp(dbig!(1).repr()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.powi(dbig!(2).repr(), (-1).into()), Err(Exception::InvalidOperation));
+    assert_eq!(ctxt.powi(dbig!(2).repr(), 3.into()), Ok(Exact(dbig!(8))));
+}
+
+#[test]
+fn test_range_exceptions() {
+    let context = Context::<mode::HalfEven>::new(4).with_exponent_range(0, 4);
+    let mut ctxt = StatusContext::new(context);
+
+    // overflow is trapped by default
+    assert_eq!(ctxt.mul(fbig!(0x1p4).repr(), fbig!(0x2).repr()), Err(Exception::Overflow));
+    assert_eq!(ctxt.flags(), Flags::OVERFLOW | Flags::INEXACT | Flags::ROUNDED);
+
+    let mut ctxt = StatusContext::new(context).with_traps(Flags::EMPTY);
+    assert_eq!(
+        ctxt.mul(fbig!(0x1p4).repr(), fbig!(0x2).repr()),
+        Ok(Inexact(FBigE::INFINITY, AddOne))
+    );
+    assert_eq!(ctxt.flags(), Flags::OVERFLOW | Flags::INEXACT | Flags::ROUNDED);
+
+    // exact subnormals don't raise underflow
+    ctxt.clear_flags();
+    assert_eq!(
+        ctxt.mul(fbig!(0x3p-2).repr(), fbig!(0x1p-1).repr()),
+        Ok(Exact(fbig!(0x3p-3).with_rounding()))
+    );
+    assert_eq!(ctxt.flags(), Flags::SUBNORMAL);
+    ctxt.clear_flags();
+    assert_eq!(
+        ctxt.mul(fbig!(0x3p-2).repr(), fbig!(0x1p-2).repr()),
+        Ok(Inexact(fbig!(0x1p-2).with_rounding(), AddOne))
+    );
+    assert_eq!(
+        ctxt.flags(),
+        Flags::UNDERFLOW | Flags::SUBNORMAL | Flags::INEXACT | Flags::ROUNDED
+    );
+
+    // normal results don't raise subnormal
+    ctxt.clear_flags();
+    assert_eq!(
+        ctxt.mul(fbig!(0x3).repr(), fbig!(0x1p-1).repr()),
+        Ok(Exact(fbig!(0x3p-1).with_rounding()))
+    );
+    assert!(ctxt.flags().is_empty());
+
+    // the most severe trapped exception is reported
+    let mut ctxt = StatusContext::new(context).with_traps(Flags::INEXACT | Flags::UNDERFLOW);
+    assert_eq!(
+        ctxt.mul(fbig!(0x1p-3).repr(), fbig!(0x1p-3).repr()),
+        Err(Exception::Underflow)
+    );
+    assert_eq!(ctxt.div(fbig!(0x4).repr(), fbig!(0x3).repr()), Err(Exception::Inexact));
+}
@@ -1,7 +1,10 @@
 //! Addition and subtraction operators.
 
 use crate::{helper_macros, ibig::IBig, ubig::UBig, Sign::*};
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::{
+    iter::Sum,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
 
 helper_macros::forward_ubig_binop_to_repr!(impl Add, add);
 helper_macros::forward_ubig_binop_to_repr!(impl Sub, sub);
@@ -95,6 +98,27 @@ macro_rules! impl_add_sub_primitive_with_ibig {
 }
 impl_add_sub_primitive_with_ibig!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
 
+// Ops with iterators
+
+macro_rules! impl_sum_for_big {
+    ($($t:ty)*) => {$(
+        impl Sum for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold(<$t>::ZERO, |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> Sum<&'a $t> for $t {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.fold(<$t>::ZERO, |acc, x| acc + x)
+            }
+        }
+    )*};
+}
+impl_sum_for_big!(UBig IBig);
+
 pub mod repr {
     use super::*;
     use crate::{
@@ -446,8 +446,11 @@ mod repr {
             Repr::from_buffer(buffer)
         } else {
             buffer.truncate(n_words);
-            if let Some(last) = buffer.last_mut() {
-                *last &= ones_word((n % WORD_BITS_USIZE) as u32);
+            let n_top = n % WORD_BITS_USIZE;
+            if n_top != 0 {
+                if let Some(last) = buffer.last_mut() {
+                    *last &= ones_word(n_top as u32);
+                }
             }
             Repr::from_buffer(buffer)
         }
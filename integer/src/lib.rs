@@ -63,7 +63,8 @@
 //! * `std` (*default*): for `std::error::Error` and some internal usages of `std` functions.
 //! * `num-traits` (*default*): support integral traits from crate `num-traits`.
 //! * `rand` (*default*): support random number generation based on crate `rand`.
-//! * `serde`: support serialization and deserialization based on crate `serde`.
+//! * `serde`: support serialization and deserialization based on crate `serde`, see the [serde][crate::serde] module
+//!   for the representations.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -127,4 +128,4 @@ pub mod rand;
 mod num_traits;
 
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
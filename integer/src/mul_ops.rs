@@ -1,7 +1,10 @@
 //! Multiplication and squaring operators.
 
 use crate::{helper_macros, ibig::IBig, ubig::UBig};
-use core::ops::{Mul, MulAssign};
+use core::{
+    iter::Product,
+    ops::{Mul, MulAssign},
+};
 
 helper_macros::forward_ubig_binop_to_repr!(impl Mul, mul);
 helper_macros::impl_binop_assign_by_taking!(impl MulAssign<UBig> for UBig, mul_assign, mul);
@@ -47,6 +50,27 @@ macro_rules! impl_div_primitive_with_ibig {
 }
 impl_div_primitive_with_ibig!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
 
+// Ops with iterators
+
+macro_rules! impl_product_for_big {
+    ($($t:ty)*) => {$(
+        impl Product for $t {
+            #[inline]
+            fn product<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold(<$t>::ONE, |acc, x| acc * x)
+            }
+        }
+
+        impl<'a> Product<&'a $t> for $t {
+            #[inline]
+            fn product<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.fold(<$t>::ONE, |acc, x| acc * x)
+            }
+        }
+    )*};
+}
+impl_product_for_big!(UBig IBig);
+
 impl UBig {
     /// Calculate the square of the number (`x * x`).
     ///
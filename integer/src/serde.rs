@@ -1,6 +1,49 @@
+//! Serialization and deserialization based on crate `serde`.
+//!
+//! For human-readable formats (e.g. JSON, YAML), [UBig] and [IBig] are serialized as decimal strings,
+//! and they can be deserialized from strings (decimal, or with a radix prefix such as `0x`), from
+//! integer literals, or from the compact representation. For compact formats (e.g. bincode), the
+//! integers are serialized as sequences of 64-bit words (with a sign flag for [IBig]).
+//!
+//! Large integer literals are accepted only if the format passes them losslessly. For example,
+//! `serde_json` parses a literal beyond 64 bits as a floating point number (which is rejected),
+//! unless its feature `arbitrary_precision` is enabled. Serialize the integers as strings to
+//! support such formats.
+//!
+//! The modules [as_decimal_str], [as_hex_str] and [as_be_bytes] can be used with the
+//! `#[serde(with = "...")]` attribute to choose the representation of a field regardless of the
+//! format.
+//!
+//! # Examples
+//!
+//! ```
+//! # use dashu_int::{IBig, UBig};
+//! use serde::{Deserialize, Serialize};
+//! use serde_test::{assert_tokens, Token};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Key {
+//!     #[serde(with = "dashu_int::serde::as_hex_str")]
+//!     modulus: UBig,
+//!     #[serde(with = "dashu_int::serde::as_be_bytes")]
+//!     exponent: IBig,
+//! }
+//!
+//! let key = Key { modulus: UBig::from(0xc0ffeeu32), exponent: IBig::from(-2) };
+//! assert_tokens(&key, &[
+//!     Token::Struct { name: "Key", len: 2 },
+//!     Token::Str("modulus"),
+//!     Token::Str("0xc0ffee"),
+//!     Token::Str("exponent"),
+//!     Token::Bytes(&[0xfe]),
+//!     Token::StructEnd,
+//! ]);
+//! ```
+
 use crate::{
     arch::word::Word,
     buffer::Buffer,
+    error::ParseError,
     ibig::IBig,
     primitive::{split_dword, WORD_BITS_USIZE},
     repr::{Repr, TypedReprRef},
@@ -8,9 +51,14 @@ use crate::{
     Sign,
 };
 use alloc::vec::Vec;
-use core::fmt::{self, Formatter};
+use core::{
+    fmt::{self, Display, Formatter, LowerHex},
+    marker::PhantomData,
+};
 use serde::{
-    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    de::{
+        self, Deserialize, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Unexpected, Visitor,
+    },
     ser::{Serialize, SerializeSeq, SerializeTuple, Serializer},
 };
 use static_assertions::const_assert;
@@ -66,13 +114,21 @@ impl<'a> Serialize for TypedReprRef<'a> {
 impl Serialize for UBig {
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.repr().serialize(serializer)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.repr().serialize(serializer)
+        }
     }
 }
 
 impl<'de> Deserialize<'de> for UBig {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(UBigVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ReadableVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_seq(UBigVisitor)
+        }
     }
 }
 
@@ -137,6 +193,10 @@ fn len_64_to_max_len(len_64: usize) -> usize {
 
 impl Serialize for IBig {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+
         let (sign, repr) = self.as_sign_repr();
         let mut tup = serializer.serialize_tuple(2)?;
         tup.serialize_element(&(sign == Sign::Negative))?;
@@ -147,8 +207,323 @@ impl Serialize for IBig {
 
 impl<'de> Deserialize<'de> for IBig {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let (sign, magnitude): (bool, UBig) = Deserialize::deserialize(deserializer)?;
-        let sign = if sign { Sign::Negative } else { Sign::Positive };
-        Ok(IBig(magnitude.0.with_sign(sign)))
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ReadableVisitor(PhantomData))
+        } else {
+            let (sign, magnitude): (bool, UBig) = Deserialize::deserialize(deserializer)?;
+            Ok(ibig_from_parts(sign, magnitude))
+        }
+    }
+}
+
+fn ibig_from_parts(negative: bool, magnitude: UBig) -> IBig {
+    let sign = if negative {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    IBig(magnitude.0.with_sign(sign))
+}
+
+mod private {
+    use super::*;
+
+    /// Conversions needed by the visitors and the adapters
+    pub trait Sealed: Sized + Display + LowerHex {
+        fn from_str_with_prefix(src: &str) -> Result<Self, ParseError>;
+        fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError>;
+        fn from_u128(value: u128) -> Self;
+        fn from_i128(value: i128) -> Option<Self>;
+        /// Deserialize from the sequence in the compact representation
+        fn from_seq<'de, A: SeqAccess<'de>>(seq: A) -> Result<Self, A::Error>;
+        fn to_be_bytes(&self) -> Vec<u8>;
+        fn from_be_bytes(bytes: &[u8]) -> Self;
+    }
+
+    impl Sealed for UBig {
+        #[inline]
+        fn from_str_with_prefix(src: &str) -> Result<Self, ParseError> {
+            UBig::from_str_with_radix_prefix(src).map(|(n, _)| n)
+        }
+        #[inline]
+        fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+            UBig::from_str_radix(src, radix)
+        }
+        #[inline]
+        fn from_u128(value: u128) -> Self {
+            UBig::from(value)
+        }
+        #[inline]
+        fn from_i128(value: i128) -> Option<Self> {
+            UBig::try_from(value).ok()
+        }
+        #[inline]
+        fn from_seq<'de, A: SeqAccess<'de>>(seq: A) -> Result<Self, A::Error> {
+            UBigVisitor.visit_seq(seq)
+        }
+        #[inline]
+        fn to_be_bytes(&self) -> Vec<u8> {
+            UBig::to_be_bytes(self)
+        }
+        #[inline]
+        fn from_be_bytes(bytes: &[u8]) -> Self {
+            UBig::from_be_bytes(bytes)
+        }
+    }
+
+    impl Sealed for IBig {
+        #[inline]
+        fn from_str_with_prefix(src: &str) -> Result<Self, ParseError> {
+            IBig::from_str_with_radix_prefix(src).map(|(n, _)| n)
+        }
+        #[inline]
+        fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+            IBig::from_str_radix(src, radix)
+        }
+        #[inline]
+        fn from_u128(value: u128) -> Self {
+            IBig::from(value)
+        }
+        #[inline]
+        fn from_i128(value: i128) -> Option<Self> {
+            Some(IBig::from(value))
+        }
+        fn from_seq<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Self, A::Error> {
+            let expected = &"a tuple of the sign and the magnitude";
+            let negative: bool = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, expected))?;
+            let magnitude: UBig = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(1, expected))?;
+            Ok(ibig_from_parts(negative, magnitude))
+        }
+
+        /// Big-endian bytes in two's complement with the minimal length
+        fn to_be_bytes(&self) -> Vec<u8> {
+            let (sign, magnitude) = self.clone().into_parts();
+            if magnitude.is_zero() {
+                return Vec::new();
+            }
+            match sign {
+                Sign::Positive => {
+                    let mut bytes = magnitude.to_be_bytes();
+                    if bytes[0] & 0x80 != 0 {
+                        bytes.insert(0, 0);
+                    }
+                    bytes
+                }
+                Sign::Negative => {
+                    // -2^(8n-1) <= self requires magnitude - 1 < 2^(8n-1)
+                    let n = (&magnitude - UBig::ONE).bit_len() / 8 + 1;
+                    ((UBig::ONE << (8 * n)) - magnitude).to_be_bytes()
+                }
+            }
+        }
+
+        fn from_be_bytes(bytes: &[u8]) -> Self {
+            let value = IBig::from(UBig::from_be_bytes(bytes));
+            match bytes.first() {
+                Some(b) if b & 0x80 != 0 => value - (IBig::ONE << (8 * bytes.len())),
+                _ => value,
+            }
+        }
+    }
+}
+
+/// The integer types supported by the adapters [as_decimal_str], [as_hex_str] and [as_be_bytes],
+/// that is [UBig] and [IBig].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: private::Sealed {}
+
+impl Integer for UBig {}
+impl Integer for IBig {}
+
+/// The key of the map that represents a number in `serde_json` with the feature `arbitrary_precision`
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// Visitor for the human-readable formats, which accepts strings, integer literals and
+/// the compact representation.
+struct ReadableVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Integer> Visitor<'de> for ReadableVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an integer or a string of an integer")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_str_with_prefix(v).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_u128(v.into())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        T::from_i128(v.into()).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        Ok(T::from_u128(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        T::from_i128(v)
+            .ok_or_else(|| E::invalid_value(Unexpected::Other("negative integer"), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<T, A::Error> {
+        T::from_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        // serde_json with the feature `arbitrary_precision` represents a number literal
+        // as a map with a single private key, whose value is the literal string
+        match map.next_key_seed(JsonNumberKey)? {
+            Some(true) => map.next_value_seed(self),
+            _ => Err(de::Error::invalid_type(Unexpected::Map, &self)),
+        }
+    }
+}
+
+impl<'de, T: Integer> DeserializeSeed<'de> for ReadableVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// Seed of the map key, which checks whether the key is [JSON_NUMBER_TOKEN] without allocation
+struct JsonNumberKey;
+
+impl<'de> DeserializeSeed<'de> for JsonNumberKey {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for JsonNumberKey {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a string key")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<bool, E> {
+        Ok(v == JSON_NUMBER_TOKEN)
+    }
+}
+
+/// Visitor of the strings in a specific radix, the prefix `0x` is allowed for radix 16
+struct RadixStrVisitor<T, const RADIX: u32>(PhantomData<T>);
+
+impl<'de, T: Integer, const RADIX: u32> Visitor<'de> for RadixStrVisitor<T, RADIX> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a string of an integer in radix {}", RADIX)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        let unsigned = v.strip_prefix('-').unwrap_or(v);
+        let result = if RADIX == 16 && unsigned.starts_with("0x") {
+            T::from_str_with_prefix(v)
+        } else {
+            T::from_str_radix(v, RADIX)
+        };
+        result.map_err(E::custom)
+    }
+}
+
+/// Visitor of the big-endian bytes
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Integer> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("big-endian bytes of an integer")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        Ok(T::from_be_bytes(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        // some formats (e.g. JSON) represent the bytes as a sequence of integers
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+/// Serialize and deserialize the integer as a decimal string (e.g. `"-1234"`) for all formats.
+///
+/// Use with `#[serde(with = "dashu_int::serde::as_decimal_str")]`.
+pub mod as_decimal_str {
+    use super::*;
+
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_str(RadixStrVisitor::<T, 10>(PhantomData))
+    }
+}
+
+/// Serialize and deserialize the integer as a hexadecimal string with the `0x` prefix
+/// (e.g. `"-0x4d2"`) for all formats. The prefix is optional for deserialization.
+///
+/// Use with `#[serde(with = "dashu_int::serde::as_hex_str")]`.
+pub mod as_hex_str {
+    use super::*;
+
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#x}", value))
+    }
+
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_str(RadixStrVisitor::<T, 16>(PhantomData))
+    }
+}
+
+/// Serialize and deserialize the integer as big-endian bytes for all formats. [UBig] is encoded
+/// as its magnitude, and [IBig] is encoded in two's complement, both with the minimal length
+/// (zero is encoded as empty bytes).
+///
+/// Use with `#[serde(with = "dashu_int::serde::as_be_bytes")]`.
+pub mod as_be_bytes {
+    use super::*;
+
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.to_be_bytes())
+    }
+
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
     }
 }
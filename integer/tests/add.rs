@@ -259,3 +259,25 @@ fn test_add_sub_ubig_ibig() {
         assert_eq!(x, *b);
     }
 }
+
+#[test]
+fn test_sum() {
+    let empty: [dashu_int::UBig; 0] = [];
+    assert_eq!(empty.iter().sum::<dashu_int::UBig>(), ubig!(0));
+
+    let values = [
+        ubig!(1),
+        ubig!(0xffffffffffffffff),
+        ubig!(0x10000000000000000),
+    ];
+    assert_eq!(values.iter().sum::<dashu_int::UBig>(), ubig!(0x20000000000000000));
+    assert_eq!(values.into_iter().sum::<dashu_int::UBig>(), ubig!(0x20000000000000000));
+
+    let values = [
+        ibig!(-1),
+        ibig!(0xffffffffffffffff),
+        ibig!(-0x10000000000000000),
+    ];
+    assert_eq!(values.iter().sum::<dashu_int::IBig>(), ibig!(-2));
+    assert_eq!(values.into_iter().sum::<dashu_int::IBig>(), ibig!(-2));
+}
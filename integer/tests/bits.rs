@@ -68,6 +68,13 @@ fn test_clear_high_bits() {
     assert_eq!(a, ubig!(0xa));
     a.clear_high_bits(0);
     assert_eq!(a, ubig!(0));
+
+    // clear at the word boundaries
+    let mut a = ubig!(0xf0000000000000001ff0000000000000001ff0000000000000001ff);
+    a.clear_high_bits(192);
+    assert_eq!(a, ubig!(0x1ff0000000000000001ff0000000000000001ff));
+    a.clear_high_bits(128);
+    assert_eq!(a, ubig!(0x1ff0000000000000001ff));
 }
 
 #[test]
@@ -76,6 +83,10 @@ fn test_split_bits() {
     assert_eq!(a, ubig!(0));
     assert_eq!(b, ubig!(0));
 
+    let (a, b) = ubig!(0x123456789098765432101234567890987654321).split_bits(128);
+    assert_eq!(a, ubig!(0x89098765432101234567890987654321));
+    assert_eq!(b, ubig!(0x1234567));
+
     let a = ubig!(0x123456789098765432101234567890987654321);
     let (a, b) = a.split_bits(0);
     assert!(a.is_zero());
@@ -212,3 +212,17 @@ fn test_sqr() {
         assert_eq!(&(-a).square(), b);
     }
 }
+
+#[test]
+fn test_product() {
+    let empty: [dashu_int::IBig; 0] = [];
+    assert_eq!(empty.iter().product::<dashu_int::IBig>(), ibig!(1));
+
+    let values = [ubig!(3), ubig!(0x100000000), ubig!(0x100000000)];
+    assert_eq!(values.iter().product::<dashu_int::UBig>(), ubig!(0x30000000000000000));
+    assert_eq!(values.into_iter().product::<dashu_int::UBig>(), ubig!(0x30000000000000000));
+
+    let values = [ibig!(-3), ibig!(0x100000000), ibig!(-0x100000000)];
+    assert_eq!(values.iter().product::<dashu_int::IBig>(), ibig!(0x30000000000000000));
+    assert_eq!(values.into_iter().product::<dashu_int::IBig>(), ibig!(0x30000000000000000));
+}
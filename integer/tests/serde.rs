@@ -1,14 +1,24 @@
 mod helper_macros;
-use serde_test::{assert_de_tokens, assert_tokens, Token};
+use dashu_int::{IBig, UBig};
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure, Token,
+};
 
 #[test]
-fn test_ubig_serde() {
-    assert_tokens(&ubig!(0), &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
-    assert_de_tokens(&ubig!(0), &[Token::Seq { len: None }, Token::SeqEnd]);
-    assert_tokens(&ubig!(17), &[Token::Seq { len: Some(1) }, Token::U64(17), Token::SeqEnd]);
-    assert_de_tokens(&ubig!(17), &[Token::Seq { len: None }, Token::U8(17), Token::SeqEnd]);
+fn test_ubig_serde_compact() {
+    assert_tokens(&ubig!(0).compact(), &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
+    assert_de_tokens(&ubig!(0).compact(), &[Token::Seq { len: None }, Token::SeqEnd]);
     assert_tokens(
-        &ubig!(0x123451234567890abcdef),
+        &ubig!(17).compact(),
+        &[Token::Seq { len: Some(1) }, Token::U64(17), Token::SeqEnd],
+    );
+    assert_de_tokens(
+        &ubig!(17).compact(),
+        &[Token::Seq { len: None }, Token::U8(17), Token::SeqEnd],
+    );
+    assert_tokens(
+        &ubig!(0x123451234567890abcdef).compact(),
         &[
             Token::Seq { len: Some(2) },
             Token::U64(0x1234567890abcdef),
@@ -17,7 +27,7 @@ fn test_ubig_serde() {
         ],
     );
     assert_de_tokens(
-        &ubig!(0x123451234567890abcdef),
+        &ubig!(0x123451234567890abcdef).compact(),
         &[
             Token::Seq { len: None },
             Token::U64(0x1234567890abcdef),
@@ -28,9 +38,9 @@ fn test_ubig_serde() {
 }
 
 #[test]
-fn test_ibig_serde() {
+fn test_ibig_serde_compact() {
     assert_tokens(
-        &ibig!(0),
+        &ibig!(0).compact(),
         &[
             Token::Tuple { len: 2 },
             Token::Bool(false),
@@ -40,7 +50,7 @@ fn test_ibig_serde() {
         ],
     );
     assert_de_tokens(
-        &ibig!(0),
+        &ibig!(0).compact(),
         &[
             Token::Seq { len: None },
             Token::Bool(true),
@@ -50,7 +60,7 @@ fn test_ibig_serde() {
         ],
     );
     assert_tokens(
-        &ibig!(17),
+        &ibig!(17).compact(),
         &[
             Token::Tuple { len: 2 },
             Token::Bool(false),
@@ -61,7 +71,7 @@ fn test_ibig_serde() {
         ],
     );
     assert_tokens(
-        &ibig!(-17),
+        &ibig!(-17).compact(),
         &[
             Token::Tuple { len: 2 },
             Token::Bool(true),
@@ -72,3 +82,148 @@ fn test_ibig_serde() {
         ],
     );
 }
+
+#[test]
+fn test_ubig_serde_readable() {
+    assert_tokens(&ubig!(0).readable(), &[Token::Str("0")]);
+    assert_tokens(
+        &ubig!(0x123451234567890abcdef).readable(),
+        &[Token::Str("1375482783624620011146735")],
+    );
+    assert_de_tokens(&ubig!(17).readable(), &[Token::U64(17)]);
+    assert_de_tokens(&ubig!(17).readable(), &[Token::I8(17)]);
+    assert_de_tokens(&ubig!(0xff).readable(), &[Token::Str("0xff")]);
+    // the compact representation is also accepted
+    assert_de_tokens(
+        &ubig!(17).readable(),
+        &[Token::Seq { len: Some(1) }, Token::U64(17), Token::SeqEnd],
+    );
+    assert_de_tokens_error::<serde_test::Readable<UBig>>(
+        &[Token::I64(-1)],
+        "invalid value: integer `-1`, expected an integer or a string of an integer",
+    );
+
+    let json = serde_json::to_string(&ubig!(0x123451234567890abcdef)).unwrap();
+    assert_eq!(json, "\"1375482783624620011146735\"");
+    assert_eq!(serde_json::from_str::<UBig>(&json).unwrap(), ubig!(0x123451234567890abcdef));
+    assert_eq!(serde_json::from_str::<UBig>("12345").unwrap(), ubig!(12345));
+
+    // the number literal of serde_json with the feature `arbitrary_precision`
+    assert_de_tokens(
+        &ubig!(0x123451234567890abcdef).readable(),
+        &[
+            Token::Map { len: Some(1) },
+            Token::Str("$serde_json::private::Number"),
+            Token::Str("1375482783624620011146735"),
+            Token::MapEnd,
+        ],
+    );
+    assert_de_tokens_error::<serde_test::Readable<UBig>>(
+        &[Token::Map { len: Some(1) }, Token::Str("value")],
+        "invalid type: map, expected an integer or a string of an integer",
+    );
+}
+
+#[test]
+fn test_ibig_serde_readable() {
+    assert_tokens(&ibig!(0).readable(), &[Token::Str("0")]);
+    assert_tokens(&ibig!(-17).readable(), &[Token::Str("-17")]);
+    assert_de_tokens(&ibig!(-17).readable(), &[Token::I64(-17)]);
+    assert_de_tokens(&ibig!(-0xff).readable(), &[Token::Str("-0xff")]);
+    assert_de_tokens(
+        &ibig!(-17).readable(),
+        &[
+            Token::Tuple { len: 2 },
+            Token::Bool(true),
+            Token::Seq { len: Some(1) },
+            Token::U64(17),
+            Token::SeqEnd,
+            Token::TupleEnd,
+        ],
+    );
+
+    assert_eq!(serde_json::to_string(&ibig!(-12345)).unwrap(), "\"-12345\"");
+    assert_eq!(serde_json::from_str::<IBig>("-12345").unwrap(), ibig!(-12345));
+    assert_eq!(serde_json::from_str::<IBig>("\"-12345\"").unwrap(), ibig!(-12345));
+    assert_de_tokens(
+        &ibig!(-0x123451234567890abcdef).readable(),
+        &[
+            Token::Map { len: Some(1) },
+            Token::Str("$serde_json::private::Number"),
+            Token::Str("-1375482783624620011146735"),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Adapted {
+    #[serde(with = "dashu_int::serde::as_decimal_str")]
+    dec: UBig,
+    #[serde(with = "dashu_int::serde::as_hex_str")]
+    hex: IBig,
+    #[serde(with = "dashu_int::serde::as_be_bytes")]
+    ubytes: UBig,
+    #[serde(with = "dashu_int::serde::as_be_bytes")]
+    ibytes: IBig,
+}
+
+#[test]
+fn test_serde_adapters() {
+    let value = Adapted {
+        dec: ubig!(12345),
+        hex: ibig!(-0x4d2),
+        ubytes: ubig!(0x8000),
+        ibytes: ibig!(-0x81),
+    };
+    let tokens = [
+        Token::Struct {
+            name: "Adapted",
+            len: 4,
+        },
+        Token::Str("dec"),
+        Token::Str("12345"),
+        Token::Str("hex"),
+        Token::Str("-0x4d2"),
+        Token::Str("ubytes"),
+        Token::Bytes(&[0x80, 0x00]),
+        Token::Str("ibytes"),
+        Token::Bytes(&[0xff, 0x7f]),
+        Token::StructEnd,
+    ];
+    // the adapters don't depend on whether the format is human-readable
+    assert_tokens(&value, &tokens);
+    assert_ser_tokens(&(&value).compact(), &tokens);
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"dec":"12345","hex":"-0x4d2","ubytes":[128,0],"ibytes":[255,127]}"#);
+    assert_eq!(serde_json::from_str::<Adapted>(&json).unwrap(), value);
+    let json = r#"{"dec":"12345","hex":"-4d2","ubytes":[128,0],"ibytes":[255,127]}"#;
+    assert_eq!(serde_json::from_str::<Adapted>(json).unwrap(), value);
+    let json = r#"{"dec":"0x1","hex":"-4d2","ubytes":[],"ibytes":[]}"#;
+    assert!(serde_json::from_str::<Adapted>(json).is_err());
+}
+
+#[test]
+fn test_ibig_be_bytes() {
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Bytes(#[serde(with = "dashu_int::serde::as_be_bytes")] IBig);
+
+    let cases: [(IBig, &[u8]); 9] = [
+        (ibig!(0), &[]),
+        (ibig!(1), &[1]),
+        (ibig!(127), &[0x7f]),
+        (ibig!(128), &[0, 0x80]),
+        (ibig!(-1), &[0xff]),
+        (ibig!(-128), &[0x80]),
+        (ibig!(-129), &[0xff, 0x7f]),
+        (ibig!(-0x8000), &[0x80, 0]),
+        (
+            ibig!(-0x123456789abcdef01),
+            &[0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10, 0xff],
+        ),
+    ];
+    for (n, bytes) in cases {
+        assert_tokens(&Bytes(n), &[Token::NewtypeStruct { name: "Bytes" }, Token::Bytes(bytes)]);
+    }
+}